@@ -0,0 +1,57 @@
+//! Benchmarks of the per-frame cost of the aligners and containers.
+//!
+//! Run with `cargo bench -p egui_alignments`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use egui_alignments::egui::{Align, CentralPanel, Context, RawInput};
+use egui_alignments::{center_horizontal, column, row};
+
+// run a few passes first, so that the sizes are memorized and no sizing pass is left
+fn warmed_up(run_ui: &mut impl FnMut(&Context)) -> Context {
+    let ctx = Context::default();
+    for _ in 0..3 {
+        let _ = ctx.run(RawInput::default(), &mut *run_ui);
+    }
+    ctx
+}
+
+fn bench_widget_aligner(c: &mut Criterion) {
+    let mut run_ui = |ctx: &Context| {
+        CentralPanel::default().show(ctx, |ui| {
+            for i in 0..200 {
+                center_horizontal(ui, |ui| {
+                    ui.label(format!("label {i}"));
+                });
+            }
+        });
+    };
+    let ctx = warmed_up(&mut run_ui);
+
+    c.bench_function("200 centered labels", |b| {
+        b.iter(|| ctx.run(RawInput::default(), &mut run_ui))
+    });
+}
+
+fn bench_containers(c: &mut Criterion) {
+    let mut run_ui = |ctx: &Context| {
+        CentralPanel::default().show(ctx, |ui| {
+            for i in 0..50 {
+                row(ui, Align::Center, |ui| {
+                    column(ui, Align::Center, |ui| {
+                        ui.label(format!("row {i}"));
+                        ui.label("second line");
+                    });
+                    ui.label("trailing");
+                });
+            }
+        });
+    };
+    let ctx = warmed_up(&mut run_ui);
+
+    c.bench_function("50 rows of columns", |b| {
+        b.iter(|| ctx.run(RawInput::default(), &mut run_ui))
+    });
+}
+
+criterion_group!(benches, bench_widget_aligner, bench_containers);
+criterion_main!(benches);
@@ -1,4 +1,4 @@
-use egui::{Align2, Response, Ui, Widget};
+use egui::{Align, Align2, Margin, Rect, Response, Style, Ui, UiBuilder, Vec2, Widget};
 
 use crate::*;
 
@@ -8,6 +8,7 @@ use crate::*;
 /// 
 /// # Examples
 /// ```
+/// # use egui_alignments::egui;
 /// use egui::{Button, Label};
 /// use egui_alignments::AlignedWidget;
 /// 
@@ -71,6 +72,27 @@ pub trait AlignedWidget: Widget + Sized {
     fn bottom_right(self, ui: &mut Ui) -> Response {
         self.align(ui, Align2::RIGHT_BOTTOM)
     }
+
+    #[inline]
+    /// Pair the widget with an [`Aligner`], to be shown later with [`Ui::add`].
+    /// See [`Aligned`].
+    fn aligned<T: Aligner>(self, align: T) -> Aligned<Self, T> {
+        Aligned::new(self, align)
+    }
+
+    #[inline]
+    /// Override the cross-axis alignment of the layout for this widget only.
+    /// See [`AlignSelf`].
+    fn align_self(self, align: Align) -> AlignSelf<Self> {
+        AlignSelf::new(self, align)
+    }
+
+    #[inline]
+    /// Surround the widget with its own outer margin.
+    /// See [`Padded`].
+    fn padded(self, margin: impl Into<Margin>) -> Padded<Self> {
+        Padded::new(self, margin)
+    }
 }
 
 /// Implements [`AlignedWidget`] for all [`Widget`]s
@@ -81,4 +103,199 @@ impl<T: Widget> AlignedWidget for T {
             .show(ui, |ui| self.ui(ui))
             .inner
     }
-}
\ No newline at end of file
+}
+
+/// A widget paired with the way it is aligned, which is itself a [`Widget`],
+/// so aligned widgets can be stored and passed around as data
+/// rather than aligned at the call site.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align2, Button};
+/// use egui_alignments::{Aligned, AlignedWidget};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let actions = vec![
+///     Button::new("Back").aligned(Align2::LEFT_CENTER),
+///     Button::new("Next").aligned(Align2::RIGHT_CENTER),
+/// ];
+/// for action in actions {
+///     ui.add(action);
+/// }
+/// # });
+/// ```
+pub struct Aligned<W, T: Aligner = Align2> {
+    /// The aligned widget.
+    pub widget: W,
+
+    /// The aligner.
+    pub align: T,
+
+    /// The bounds in which the widget is aligned.
+    pub bounds: Bounds,
+
+    /// See [`AllocateType`].
+    pub allocate_type: AllocateType,
+}
+
+impl<W: Widget, T: Aligner> Aligned<W, T> {
+    #[inline]
+    /// Align the widget in the available space with the given aligner.
+    pub fn new(widget: W, align: T) -> Self {
+        Self {
+            widget,
+            align,
+            bounds: Bounds::available_rect(),
+            allocate_type: AllocateType::Content,
+        }
+    }
+
+    #[inline]
+    /// Set the bounds in which the widget is aligned.
+    pub fn bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    #[inline]
+    /// See [`AllocateType`].
+    pub fn allocate_type(mut self, allocate_type: AllocateType) -> Self {
+        self.allocate_type = allocate_type;
+        self
+    }
+}
+
+impl<W: Widget, T: Aligner> Widget for Aligned<W, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        WidgetAligner::from_align(self.align)
+            .bounds(self.bounds)
+            .allocate_type(self.allocate_type)
+            .show(ui, |ui| self.widget.ui(ui))
+            .inner
+    }
+}
+
+/// A widget aligned on the cross axis of the layout on its own,
+/// like `align-self` in CSS flexbox,
+/// e.g. at the bottom of a [`Row`] whose other items are centered.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, Button, Label};
+/// use egui_alignments::{AlignedWidget, Row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// Row::new(Align::Center).show(ui, |ui| {
+///     ui.heading("Inbox");
+///     ui.label("12 unread");
+///     ui.add(Button::new("Refresh").align_self(Align::Max));
+/// });
+/// # });
+/// ```
+pub struct AlignSelf<W> {
+    /// The aligned widget.
+    pub widget: W,
+
+    /// The alignment on the cross axis of the layout,
+    /// vertical in a horizontal layout and horizontal in a vertical one.
+    pub align: Align,
+}
+
+impl<W: Widget> AlignSelf<W> {
+    #[inline]
+    /// Align the widget on the cross axis of the layout.
+    pub fn new(widget: W, align: Align) -> Self {
+        Self { widget, align }
+    }
+}
+
+// the tag of the content uis of the containers, holding the rect of their memorized contents
+pub(crate) const LINE_RECT_TAG: &str = "egui_alignments::line_rect";
+
+impl<W: Widget> Widget for AlignSelf<W> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        if ui.is_sizing_pass() {
+            // the line is being measured
+            return ui.add(self.widget);
+        }
+
+        // align within the line of a container, or within the whole ui otherwise
+        let line = ui.stack().info.tags.get_downcast::<Rect>(LINE_RECT_TAG)
+            .copied()
+            .unwrap_or(ui.max_rect());
+        let mut rect = ui.available_rect_before_wrap();
+        if ui.layout().is_horizontal() {
+            rect.set_top(line.top());
+            rect.set_bottom(line.bottom());
+        } else {
+            rect.set_left(line.left());
+            rect.set_right(line.right());
+        }
+
+        let layout = ui.layout()
+            .with_cross_align(self.align)
+            .with_cross_justify(false);
+        let mut child_ui = ui.new_child(UiBuilder::new().max_rect(rect).layout(layout));
+        let response = child_ui.add(self.widget);
+        ui.advance_cursor_after_rect(child_ui.min_rect());
+        response
+    }
+}
+
+/// A widget surrounded by its own outer margin, like `margin` in CSS,
+/// e.g. to space out a single item of a [`Row`] or a [`Column`].
+///
+/// The margin is part of the space the widget takes,
+/// so the containers account for it when measuring their contents.
+/// The response is the one of the widget, without the margin.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, Button};
+/// use egui_alignments::{AlignedWidget, Row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// Row::new(Align::Center).show(ui, |ui| {
+///     ui.label("Name");
+///     ui.add(Button::new("Edit").padded(8.0));
+///     ui.label("Done");
+/// });
+/// # });
+/// ```
+pub struct Padded<W> {
+    /// The padded widget.
+    pub widget: W,
+
+    /// The margin around the widget.
+    pub margin: Margin,
+}
+
+impl<W: Widget> Padded<W> {
+    #[inline]
+    /// Surround the widget with the given margin.
+    pub fn new(widget: W, margin: impl Into<Margin>) -> Self {
+        Self {
+            widget,
+            margin: margin.into(),
+        }
+    }
+}
+
+impl<W: Widget> Widget for Padded<W> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let rect = ui.available_rect_before_wrap() - self.margin;
+        let mut child_ui = ui.new_child(UiBuilder::new().max_rect(rect).layout(*ui.layout()));
+        let response = child_ui.add(self.widget);
+        ui.advance_cursor_after_rect(child_ui.min_rect() + self.margin);
+        response
+    }
+}
+
+impl<W: Widget + SizeHint> SizeHint for Padded<W> {
+    fn intrinsic_size(&self, style: &Style) -> Option<Vec2> {
+        Some(self.widget.intrinsic_size(style)? + self.margin.sum())
+    }
+}
@@ -1,6 +1,9 @@
 use egui::{Align, Align2, Id, InnerResponse, Layout, Margin, Pos2, Rect, Sense, Ui, UiBuilder, Vec2};
 
-use crate::resize_layout_rect;
+use crate::{
+    hide_sizing_pass, layout_widget_rect, measure::MEASURE_ID_SALT, next_widget_rect, resize_layout_rect,
+    wrapped_lines_rect, AlignedResponse, Axis, LayoutError, OverflowCallback, ResizeCallback,
+};
 
 /// Represents an alignment strategy.
 /// You can directly use `egui::Align2` or closure `FnOnce(egui::Vec2, egui::Rect) -> egui::Rect`
@@ -23,8 +26,96 @@ where T: FnOnce(Vec2, Rect) -> Rect {
     }
 }
 
+/// An [`Aligner`] made of data only,
+/// so it can be compared, stored in settings and loaded from config,
+/// unlike closures.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::vec2;
+/// use egui_alignments::{Alignment, WidgetAligner};
+///
+/// # egui::__run_test_ui(|ui| {
+/// // a quarter of the way from the left, vertically centered
+/// WidgetAligner::from_align(Alignment::Fraction(vec2(0.25, 0.5)))
+///     .show(ui, |ui| {
+///         ui.label("Off-center");
+///     });
+/// # });
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Alignment {
+    /// Align with an [`Align2`].
+    Align2(Align2),
+
+    /// Place the item at the given fraction of the free space in each direction,
+    /// `(0, 0)` being the left top and `(1, 1)` the right bottom.
+    Fraction(Vec2),
+}
+
+impl Default for Alignment {
+    fn default() -> Self {
+        Alignment::Align2(Align2::CENTER_CENTER)
+    }
+}
+
+impl From<Align2> for Alignment {
+    fn from(align: Align2) -> Self {
+        Alignment::Align2(align)
+    }
+}
+
+impl Aligner for Alignment {
+    fn align(self, item_size: Vec2, bounds: Rect) -> Rect {
+        match self {
+            Alignment::Align2(align) => align.align_size_within_rect(item_size, bounds),
+            Alignment::Fraction(fraction) => {
+                let min = bounds.min + (bounds.size() - item_size) * fraction;
+                Rect::from_min_size(min, item_size)
+            },
+        }
+    }
+}
+
+/// Places the item on the line between its placements with two [`Align2`]s,
+/// `t` being `0` at the first one and `1` at the second.
+///
+/// Animating `t` moves the contents along an edge, or across the bounds.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::{Align2Lerp, WidgetAligner};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let t = ui.ctx().animate_bool(egui::Id::new("toast"), true);
+///
+/// // slide in along the bottom edge, from the left to the center
+/// WidgetAligner::from_align(Align2Lerp(Align2::LEFT_BOTTOM, Align2::CENTER_BOTTOM, t))
+///     .show(ui, |ui| {
+///         ui.label("Saved");
+///     });
+/// # });
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Align2Lerp(pub Align2, pub Align2, pub f32);
+
+impl Aligner for Align2Lerp {
+    fn align(self, item_size: Vec2, bounds: Rect) -> Rect {
+        let Align2Lerp(a, b, t) = self;
+        let from = a.align_size_within_rect(item_size, bounds).min;
+        let to = b.align_size_within_rect(item_size, bounds).min;
+        Rect::from_min_size(from.lerp(to, t), item_size)
+    }
+}
+
 /// Determines how [`WidgetAligner`] allocate space for the aligned contents.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AllocateType {
     /// Allocate no space.
     None,
@@ -42,16 +133,67 @@ pub enum AllocateType {
 
     /// Allocate the whole bounds specified to align the contents.
     Bounds,
+
+    /// Allocate the rect returned by the function,
+    /// given the rect of the contents and the bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui::{pos2, Rect};
+    /// use egui_alignments::{AllocateType, WidgetAligner};
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// // allocate the width of the contents, but a fixed height
+    /// WidgetAligner::center()
+    ///     .allocate_type(AllocateType::Custom(|content, _bounds| {
+    ///         Rect::from_min_max(
+    ///             pos2(content.left(), content.top()),
+    ///             pos2(content.right(), content.top() + 32.0),
+    ///         )
+    ///     }))
+    ///     .show(ui, |ui| {
+    ///         ui.label("Fixed height row");
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// Functions cannot be serialized, so this variant is skipped by serde.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(fn(Rect, Rect) -> Rect),
 }
 
+impl PartialEq for AllocateType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // compare the addresses, like the derived implementation would
+            (AllocateType::Custom(a), AllocateType::Custom(b)) => *a as usize == *b as usize,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for AllocateType {}
+
 /// The bounds in which its contents will be aligned.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Bounds {
     /// Align in Ui's next widget position with the given size.
     AvailableRect(Vec2),
 
     /// Align in the whole Ui, ignoring the specified margin.
     MaxRect(Margin),
+
+    /// Align in the whole Ui, ignoring the parts out of the safe area of the screen
+    /// and the specified margin.
+    /// See [`set_safe_area_insets`](crate::set_safe_area_insets).
+    SafeArea(Margin),
+
+    /// Align relative to the rect published as an anchor with the given id,
+    /// or in the whole Ui while it is unknown.
+    /// See [`Bounds::anchor`] and [`Anchors`](crate::Anchors).
+    Anchor(Id),
 }
 
 impl Bounds {
@@ -66,6 +208,18 @@ impl Bounds {
     pub fn max_rect() -> Self {
         Bounds::MaxRect(0.0.into())
     }
+
+    #[inline]
+    /// Align in the whole Ui, within the safe area of the screen.
+    pub fn safe_area() -> Self {
+        Bounds::SafeArea(0.0.into())
+    }
+
+    #[inline]
+    /// Align relative to the rect published with the given name in [`Anchors`](crate::Anchors).
+    pub fn anchor(name: impl std::hash::Hash) -> Self {
+        Bounds::Anchor(crate::anchors::anchor_id(name))
+    }
 }
 
 /// A container which aligns its contents
@@ -73,6 +227,7 @@ impl Bounds {
 /// 
 /// # Example
 /// ```
+/// # use egui_alignments::egui;
 /// use egui_alignments::WidgetAligner;
 /// 
 /// # egui::__run_test_ui(|ui| {
@@ -101,6 +256,18 @@ pub struct WidgetAligner<T: Aligner> {
     /// The layout of the contents.
     /// If None, use the layout of the current ui.
     pub layout: Option<Layout>,
+
+    /// The size of the contents, if known up front.
+    /// Used instead of a sizing pass when no size is memorized yet.
+    /// See [`SizeHint`].
+    pub size_hint: Option<Vec2>,
+
+    /// Called after the layout for each axis on which the contents exceeded the bounds.
+    /// Not called in sizing passes.
+    pub on_overflow: Option<OverflowCallback>,
+
+    /// Called when the memorized size of the contents changes, with the old and the new size.
+    pub on_resize: Option<ResizeCallback>,
 }
 
 pub type Align2WidgetAligner = WidgetAligner<egui::Align2>;
@@ -113,6 +280,9 @@ impl Default for Align2WidgetAligner {
             bounds: Bounds::available_rect(),
             allocate_type: AllocateType::Content,
             layout: None,
+            size_hint: None,
+            on_overflow: None,
+            on_resize: None,
         }
     }
 }
@@ -192,6 +362,9 @@ impl<T: Aligner> WidgetAligner<T> {
             bounds: Bounds::AvailableRect(Vec2::INFINITY),
             allocate_type: AllocateType::Content,
             layout: None,
+            size_hint: None,
+            on_overflow: None,
+            on_resize: None,
         }
     }
 }
@@ -236,6 +409,30 @@ impl<T: Aligner> WidgetAligner<T> {
         self.layout = Some(layout);
         self
     }
+
+    #[inline]
+    /// Set the size of the contents, if known up front,
+    /// to skip the sizing pass.
+    pub fn size_hint(mut self, size: impl Into<Vec2>) -> Self {
+        self.size_hint = Some(size.into());
+        self
+    }
+
+    #[inline]
+    /// Set a callback invoked after the layout with the axis and the amount
+    /// by which the contents exceeded the bounds.
+    pub fn on_overflow(mut self, on_overflow: impl Fn(Axis, f32) + 'static) -> Self {
+        self.on_overflow = Some(Box::new(on_overflow));
+        self
+    }
+
+    #[inline]
+    /// Set a callback invoked with the old and the new size of the contents
+    /// when their memorized size changes.
+    pub fn on_resize(mut self, on_resize: impl Fn(Vec2, Vec2) + 'static) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
 }
 
 impl<T: Aligner> WidgetAligner<T> {
@@ -244,7 +441,7 @@ impl<T: Aligner> WidgetAligner<T> {
         self,
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut egui::Ui) -> R
-    ) -> InnerResponse<R> {
+    ) -> AlignedResponse<R> {
         let id = self.id.unwrap_or_else(|| {
             let id = ui.next_auto_id();
             // hold the id
@@ -253,35 +450,152 @@ impl<T: Aligner> WidgetAligner<T> {
         });
 
         let layout = self.layout.unwrap_or(*ui.layout());
+        let bounds = self.bounds_rect(ui);
+
+        // try to read content size from context memory
+        // if not found, use the whole available rect to draw the contents
+        let room = self.room(ui, bounds);
+        let content_size = crate::size_cache::get_within(ui.ctx(), id, room.size()).or(self.size_hint);
+
+        self.show_sized(ui, id, layout, bounds, content_size, true, add_contents)
+    }
+
+    /// Show the aligned contents,
+    /// or return an error without showing them if the bounds are empty
+    /// or their margin is negative.
+    pub fn try_show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R
+    ) -> Result<AlignedResponse<R>, LayoutError> {
+        if let Bounds::MaxRect(margin) | Bounds::SafeArea(margin) = self.bounds {
+            LayoutError::check_margin(margin, Vec2::INFINITY)?;
+        }
+        LayoutError::check_bounds(self.bounds_rect(ui))?;
+        Ok(self.show(ui, add_contents))
+    }
+
+    /// Show the aligned contents,
+    /// measuring them in an invisible sizing pass first, in the same frame.
+    ///
+    /// No size is memorized and no pass is discarded, so the layout of a frame
+    /// depends on that frame only, at the cost of running the contents twice.
+    /// This is useful for deterministic layouts, e.g. in screenshot tests.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui_alignments::WidgetAligner;
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = WidgetAligner::center()
+    ///     .show_two_pass(ui, |ui| {
+    ///         ui.label("Centered in the first frame");
+    ///     });
+    /// assert!(!response.sizing_pass);
+    /// # });
+    /// ```
+    pub fn show_two_pass<R>(
+        self,
+        ui: &mut Ui,
+        mut add_contents: impl FnMut(&mut egui::Ui) -> R
+    ) -> AlignedResponse<R> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+
+        let layout = self.layout.unwrap_or(*ui.layout());
+        let bounds = self.bounds_rect(ui);
+
+        let content_size = self.size_hint.unwrap_or_else(|| {
+            let mut sizing_ui = ui.new_child(
+                UiBuilder::new()
+                    .id_salt(MEASURE_ID_SALT)
+                    .max_rect(self.room(ui, bounds))
+                    .layout(layout)
+                    .sizing_pass()
+                    .invisible()
+            );
+            add_contents(&mut sizing_ui);
+            sizing_ui.min_size()
+        });
+
+        self.show_sized(ui, id, layout, bounds, Some(content_size), false, add_contents)
+    }
 
-        // calculate the bounds
+    // calculate the bounds, above the on-screen keyboard
+    fn bounds_rect(&self, ui: &mut Ui) -> Rect {
+        // a page is laid out against its own rect, not the screen
+        let on_page = crate::page_rect(ui).is_some();
         let bounds = match self.bounds {
+            Bounds::AvailableRect(size) if ui.layout().main_wrap() => {
+                next_widget_rect(ui, size.min(ui.available_size()))
+            },
             Bounds::AvailableRect(size) => {
-                ui.new_child(UiBuilder::new())
-                    .allocate_space(size.min(ui.available_size()))
-                    .1
+                let available_rect = ui.available_rect_before_wrap();
+                layout_widget_rect(ui.layout(), available_rect, size.min(available_rect.size()))
             },
             Bounds::MaxRect(margin) => {
                 ui.max_rect() - margin
-            }
+            },
+            Bounds::SafeArea(margin) if on_page => {
+                ui.max_rect() - margin
+            },
+            Bounds::SafeArea(margin) => {
+                ui.max_rect().intersect(crate::safe_area_rect(ui.ctx())) - margin
+            },
+            Bounds::Anchor(id) => {
+                // the anchor is where it was published, the keyboard does not move it
+                return crate::anchors::anchor_rect(ui.ctx(), id).unwrap_or(ui.max_rect());
+            },
         };
+        if on_page {
+            bounds
+        } else {
+            crate::avoid_keyboard(ui.ctx(), bounds)
+        }
+    }
 
-        // try to read content size from context memory
-        // if not found, use the whole available rect to draw the contents
-        let mut memorized = true;
-        let content_size = ui.ctx()
-            .data(|r| r.get_temp(id))
-            .unwrap_or_else(|| {
-                memorized = false;
-                bounds.size()
-            });
+    // the rect the contents may be laid out in, which is the bounds,
+    // except for an anchor, which the contents are only aligned relative to
+    fn room(&self, ui: &Ui, bounds: Rect) -> Rect {
+        match self.bounds {
+            Bounds::Anchor(_) => ui.max_rect(),
+            _ => bounds,
+        }
+    }
+
+    // show the contents given their size if known, or in a sizing pass otherwise
+    #[allow(clippy::too_many_arguments)]
+    fn show_sized<R>(
+        self,
+        ui: &mut Ui,
+        id: Id,
+        layout: Layout,
+        bounds: Rect,
+        content_size: Option<Vec2>,
+        memorize: bool,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R
+    ) -> AlignedResponse<R> {
+        #[cfg(feature = "profiling")]
+        profiling::scope!("WidgetAligner", &id.short_debug_format());
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("WidgetAligner", ?id).entered();
+
+        let memorized = content_size.is_some();
+        let room = self.room(ui, bounds);
+        let content_size = content_size.unwrap_or(room.size());
 
         // calc the content rect
-        let content_rect = resize_layout_rect(
-            self.align.align(content_size, bounds),
-            bounds.size(),
-            &layout
-        );
+        let aligned_rect = self.align.align(content_size, bounds);
+        let content_rect = if layout.main_wrap() && layout.is_horizontal() {
+            // the lines wrap within the bounds
+            wrapped_lines_rect(ui, &layout, aligned_rect, room.width())
+        } else {
+            resize_layout_rect(aligned_rect, room.size(), &layout)
+        };
         
         // create child ui
         let mut child_ui = ui.new_child({
@@ -293,43 +607,70 @@ impl<T: Aligner> WidgetAligner<T> {
                 builder
             } else {
                 // no size memorized, set the pass to sizing pass
-                ui.ctx().request_discard("new WidgetAligner");
-                builder.sizing_pass().invisible()
+                #[cfg(feature = "tracing")]
+                tracing::debug!(bounds = ?room.size(), "starting a sizing pass");
+                crate::request_discard(ui.ctx(), "new WidgetAligner");
+                builder.sizing_pass()
             }
         });
+        if !memorized {
+            hide_sizing_pass(&mut child_ui);
+        }
 
         // paint the contents
+        let debug_node = crate::debug::begin_node(ui.ctx(), "WidgetAligner", id);
+        let ime = crate::ime::begin(ui.ctx());
         let inner = add_contents(&mut child_ui);
-        
+        crate::ime::end(ui.ctx(), ime, id, !memorized || ui.is_sizing_pass());
+        let new_rect = child_ui.min_rect();
+
         // hold the content place
         let response = ui.allocate_rect(
             match self.allocate_type {
                 AllocateType::None => Rect::from_min_size(ui.next_widget_position(), Vec2::ZERO),
-                AllocateType::Content => child_ui.min_rect(),
-                AllocateType::ContentRow => {
-                    let content_rect = child_ui.min_rect();
-                    let min = Pos2::new(bounds.left(), content_rect.top());
-                    let max = Pos2::new(bounds.right(), content_rect.bottom());
-                    Rect::from_min_max(min, max)
-                },
-                AllocateType::ContentColumn => {
-                    let content_rect = child_ui.min_rect();
-                    let min = Pos2::new(content_rect.left(), bounds.top());
-                    let max = Pos2::new(content_rect.right(), bounds.bottom());
-                    Rect::from_min_max(min, max)
-                },
+                AllocateType::Content => new_rect,
+                AllocateType::ContentRow => Rect::from_min_max(
+                    Pos2::new(bounds.left(), new_rect.top()),
+                    Pos2::new(bounds.right(), new_rect.bottom()),
+                ),
+                AllocateType::ContentColumn => Rect::from_min_max(
+                    Pos2::new(new_rect.left(), bounds.top()),
+                    Pos2::new(new_rect.right(), bounds.bottom()),
+                ),
                 AllocateType::Bounds => bounds,
+                AllocateType::Custom(allocate) => allocate(new_rect, bounds),
             },
             Sense::hover(),
         );
 
         // if the content changed size or not memorized, update the memorized size
-        let new_size = child_ui.min_size();
-        if new_size != content_size || !memorized {
-            ui.ctx().data_mut(|w| w.insert_temp(id, new_size));
+        if memorize && (new_rect.size() != content_size || !memorized) {
+            let old_size = crate::size_cache::get(ui.ctx(), id);
+            crate::size_cache::insert(ui.ctx(), id, new_rect.size(), room.size());
+            if let (Some(on_resize), Some(old_size)) = (&self.on_resize, old_size) {
+                if old_size != new_rect.size() {
+                    on_resize(old_size, new_rect.size());
+                }
+            }
         }
 
-        InnerResponse { inner, response }
+        let response = AlignedResponse {
+            inner,
+            response,
+            sizing_pass: !memorized || ui.is_sizing_pass(),
+            content_rect: new_rect,
+            bounds,
+        };
+        crate::debug::end_node(
+            ui.ctx(),
+            debug_node,
+            response.bounds,
+            response.content_rect,
+            memorized.then_some(content_size),
+            response.sizing_pass,
+        );
+        response.report_overflow(self.on_overflow.as_ref());
+        response
     }
 
     #[inline]
@@ -338,7 +679,7 @@ impl<T: Aligner> WidgetAligner<T> {
         self,
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R
-    ) -> InnerResponse<R> {
+    ) -> AlignedResponse<R> {
         let layout = if ui.layout().prefer_right_to_left() {
             Layout::right_to_left(Align::Center)
         } else {
@@ -356,7 +697,7 @@ impl<T: Aligner> WidgetAligner<T> {
         self, 
         ui: &mut Ui, 
         add_contents: impl FnOnce(&mut Ui) -> R
-    ) -> InnerResponse<R> {
+    ) -> AlignedResponse<R> {
         let layout = if ui.layout().prefer_right_to_left() {
             Layout::right_to_left(Align::Center)
         } else {
@@ -374,7 +715,7 @@ impl<T: Aligner> WidgetAligner<T> {
         self,
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R
-    ) -> InnerResponse<R> {
+    ) -> AlignedResponse<R> {
         let layout = Layout::top_down(Align::Center);
 
         self.layout(layout)
@@ -397,6 +738,7 @@ pub fn center_horizontal<R>(
     WidgetAligner::center()
         .layout(layout)
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -415,6 +757,7 @@ pub fn center_horizontal_wrapped<R>(
     WidgetAligner::center()
         .layout(layout)
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -426,6 +769,7 @@ pub fn center_vertical<R>(
     WidgetAligner::center()
         .layout(Layout::top_down(Align::Center))
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -443,6 +787,7 @@ pub fn top_horizontal<R>(
     WidgetAligner::from_align(egui::Align2::CENTER_TOP)
         .layout(layout)
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -461,6 +806,7 @@ pub fn top_horizontal_wrapped<R>(
     WidgetAligner::from_align(Align2::CENTER_TOP)
         .layout(layout)
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -487,6 +833,7 @@ pub fn bottom_horizontal<R>(
     WidgetAligner::from_align(egui::Align2::CENTER_BOTTOM)
         .layout(layout)
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -505,6 +852,7 @@ pub fn bottom_horizontal_wrapped<R>(
     WidgetAligner::from_align(egui::Align2::CENTER_BOTTOM)
         .layout(layout)
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -537,6 +885,7 @@ pub fn left_horizontal_wrapped<R>(
     WidgetAligner::from_align(egui::Align2::LEFT_CENTER)
         .layout(layout)
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -548,6 +897,7 @@ pub fn left_vertical<R>(
     WidgetAligner::from_align(egui::Align2::LEFT_CENTER)
         .layout(Layout::top_down(Align::Min))
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -570,6 +920,7 @@ pub fn right_horizontal_wrapped<R>(
     WidgetAligner::from_align(Align2::RIGHT_CENTER)
         .layout(layout)
         .show(ui, add_contents)
+        .into()
 }
 
 #[inline]
@@ -581,4 +932,5 @@ pub fn right_vertical<R>(
     WidgetAligner::from_align(Align2::RIGHT_CENTER)
         .layout(Layout::top_down(Align::Max))
         .show(ui, add_contents)
+        .into()
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+use std::hash::Hash;
+
+use egui::{Context, Id, Rect, Response, Vec2};
+
+use crate::{overlay::coach::rect_beside, Aligner, Edge};
+
+/// A registry of named rects, published by widgets for others to align relative to,
+/// so "B next to A" does not depend on where A and B are in the code.
+///
+/// An anchor is known from the pass it is published in until the pass after,
+/// so it can be used before it is published in a pass, from the previous one.
+/// The pass is discarded when an anchor appears or moves, so the aligned contents follow it.
+///
+/// Align relative to an anchor with [`Bounds::anchor`](crate::Bounds::anchor),
+/// e.g. with the [`Beside`] aligner.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{AllocateType, Anchors, Beside, Bounds, Edge, WidgetAligner};
+///
+/// # egui::__run_test_ui(|ui| {
+/// // shown before the button in the code, still placed below it
+/// WidgetAligner::from_align(Beside::new(Edge::Bottom, 4.0))
+///     .bounds(Bounds::anchor("save_button"))
+///     .allocate_type(AllocateType::None)
+///     .show(ui, |ui| {
+///         ui.weak("Unsaved changes");
+///     });
+///
+/// let response = ui.button("Save");
+/// Anchors::new(ui.ctx()).set_response("save_button", &response);
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct Anchors {
+    ctx: Context,
+}
+
+// the rect of an anchor and the pass it was published in
+#[derive(Clone, Copy)]
+struct Published {
+    rect: Rect,
+    pass: u64,
+}
+
+/// The id an anchor is memorized with.
+pub(crate) fn anchor_id(name: impl Hash) -> Id {
+    Id::new("egui_alignments::anchor").with(name)
+}
+
+/// The rect of the anchor with the given id, if published in this pass or the previous one.
+pub(crate) fn anchor_rect(ctx: &Context, id: Id) -> Option<Rect> {
+    let published = ctx.data(|data| data.get_temp::<Published>(id))?;
+    (published.pass + 1 >= ctx.cumulative_pass_nr()).then_some(published.rect)
+}
+
+impl Anchors {
+    #[inline]
+    /// The anchors of the given context.
+    pub fn new(ctx: &Context) -> Self {
+        Self { ctx: ctx.clone() }
+    }
+
+    /// Publish a rect with the given name, in the coordinates of the ui it was laid out in.
+    pub fn set(&self, name: impl Hash, rect: Rect) {
+        let id = anchor_id(name);
+        let old = anchor_rect(&self.ctx, id);
+        let pass = self.ctx.cumulative_pass_nr();
+        self.ctx.data_mut(|data| data.insert_temp(id, Published { rect, pass }));
+        if old != Some(rect) {
+            crate::request_discard(&self.ctx, "Anchor moved");
+        }
+    }
+
+    #[inline]
+    /// Publish the rect of the response with the given name.
+    pub fn set_response(&self, name: impl Hash, response: &Response) {
+        self.set(name, response.rect);
+    }
+
+    #[inline]
+    /// The rect published with the given name, in this pass or the previous one.
+    pub fn get(&self, name: impl Hash) -> Option<Rect> {
+        anchor_rect(&self.ctx, anchor_id(name))
+    }
+
+    /// Forget the rect published with the given name.
+    pub fn remove(&self, name: impl Hash) {
+        self.ctx.data_mut(|data| data.remove::<Published>(anchor_id(name)));
+    }
+}
+
+/// Places the item outside the bounds, next to the given edge, `gap` away from it,
+/// and centered along it. Useful with the bounds of an anchor, see [`Anchors`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Beside {
+    /// The edge of the bounds the item is placed next to.
+    pub edge: Edge,
+
+    /// The space between the bounds and the item.
+    pub gap: f32,
+}
+
+impl Beside {
+    #[inline]
+    /// Place the item next to the given edge of the bounds, `gap` away from it.
+    pub fn new(edge: Edge, gap: f32) -> Self {
+        Self { edge, gap }
+    }
+}
+
+impl Aligner for Beside {
+    fn align(self, item_size: Vec2, bounds: Rect) -> Rect {
+        rect_beside(self.edge, bounds, item_size, self.gap)
+    }
+}
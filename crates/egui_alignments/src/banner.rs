@@ -0,0 +1,233 @@
+//! Full-width banners pinned to the top of a ui
+
+use std::hash::Hash;
+
+use egui::{
+    pos2, vec2, Align, Align2, Button, Color32, Context, Id, InnerResponse, Layout, Rect, Sense, Ui,
+    UiBuilder, Vec2, Visuals,
+};
+
+use crate::{Bounds, WidgetAligner};
+
+/// The kind of message shown by a [`Banner`], which sets its color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// A neutral message, in the selection color.
+    #[default]
+    Info,
+
+    /// A successful outcome, in green.
+    Success,
+
+    /// A warning, in the warning color of the visuals.
+    Warning,
+
+    /// An error, in the error color of the visuals.
+    Error,
+}
+
+impl Severity {
+    /// The color of the severity with the given visuals.
+    pub fn color(self, visuals: &Visuals) -> Color32 {
+        match self {
+            Severity::Info => visuals.selection.bg_fill,
+            Severity::Success => Color32::from_rgb(60, 160, 80),
+            Severity::Warning => visuals.warn_fg_color,
+            Severity::Error => visuals.error_fg_color,
+        }
+    }
+}
+
+/// A full-width bar pinned to the top of a ui, with its contents centered
+/// and a close button aligned to the right.
+///
+/// Show the banner before the other contents of the ui, e.g. above a scroll area,
+/// so it stays in place while they scroll.
+/// It slides in when it becomes visible and slides out when it is hidden or closed.
+/// Once closed, it stays hidden until [`Banner::reopen`] is called.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{Banner, Severity};
+///
+/// # egui::__run_test_ui(|ui| {
+/// Banner::new("offline")
+///     .severity(Severity::Warning)
+///     .show(ui, |ui| {
+///         ui.label("You are offline, changes will be synced later.");
+///     });
+/// egui::ScrollArea::vertical().show(ui, |ui| {
+///     ui.label("Scrolled contents");
+/// });
+/// # });
+/// ```
+pub struct Banner {
+    /// Used to animate the sliding and to memorize the height and the closed state.
+    pub id: Id,
+
+    /// The kind of message, which sets the color of the bar.
+    pub severity: Severity,
+
+    /// If the banner is visible, or sliding out otherwise.
+    pub visible: bool,
+
+    /// If `true`, show a close button on the right.
+    pub closable: bool,
+
+    /// The space between the edges of the bar and its contents.
+    pub margin: Vec2,
+
+    /// The duration of the slide-in and slide-out, in seconds.
+    pub slide_duration: f32,
+}
+
+impl Banner {
+    #[inline]
+    /// Create a new visible info banner with a close button.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            severity: Severity::Info,
+            visible: true,
+            closable: true,
+            margin: vec2(8.0, 6.0),
+            slide_duration: 0.2,
+        }
+    }
+
+    #[inline]
+    /// Set the kind of message.
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    #[inline]
+    /// Set whether the banner is visible, or sliding out.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    #[inline]
+    /// Set whether to show a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    #[inline]
+    /// Set the space between the edges of the bar and its contents.
+    pub fn margin(mut self, margin: impl Into<Vec2>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the duration of the slide-in and slide-out, in seconds.
+    pub fn slide_duration(mut self, slide_duration: f32) -> Self {
+        self.slide_duration = slide_duration;
+        self
+    }
+}
+
+impl Banner {
+    /// If the banner with the given id was closed with its close button.
+    pub fn is_closed(ctx: &Context, id: Id) -> bool {
+        ctx.data(|data| data.get_temp::<bool>(id.with("closed")))
+            .unwrap_or(false)
+    }
+
+    /// Show the banner with the given id again after it was closed.
+    pub fn reopen(ctx: &Context, id: Id) {
+        ctx.data_mut(|data| data.remove::<bool>(id.with("closed")));
+    }
+
+    /// Show the banner at the top of the remaining space of the ui.
+    ///
+    /// Returns `None` once the banner is hidden and slid out.
+    /// The response covers the visible part of the bar.
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+        let ctx = ui.ctx().clone();
+        let shown = self.visible && !Self::is_closed(&ctx, self.id);
+
+        // start hidden to slide in
+        let t = crate::fade_in_from_hidden(&ctx, self.id, shown, self.slide_duration);
+        if !shown && t <= 0.0 {
+            return None;
+        }
+
+        let height_id = self.id.with("height");
+        let height = ctx.data(|data| data.get_temp::<f32>(height_id))
+            .unwrap_or(ui.spacing().interact_size.y + 2.0 * self.margin.y);
+
+        // the bar slides down from behind the top edge of the allocated rect
+        let (rect, response) = ui.allocate_exact_size(vec2(ui.available_width(), height * t), Sense::hover());
+        let bar_rect = Rect::from_min_max(pos2(rect.min.x, rect.max.y - height), rect.max);
+        let mut bar_ui = ui.new_child(UiBuilder::new().max_rect(bar_rect));
+        bar_ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+
+        let color = self.severity.color(ui.visuals());
+        bar_ui.painter().rect_filled(bar_rect, 0.0, color.gamma_multiply(0.25));
+
+        let inner_rect = bar_rect.shrink2(self.margin);
+        let mut close_width = 0.0;
+        if self.closable {
+            let close_size = Vec2::splat(ui.spacing().interact_size.y);
+            let close_rect = Align2::RIGHT_CENTER.align_size_within_rect(close_size, inner_rect);
+            if bar_ui.put(close_rect, Button::new("✖").frame(false)).clicked() {
+                ctx.data_mut(|data| data.insert_temp(self.id.with("closed"), true));
+            }
+            close_width = close_size.x + ui.spacing().item_spacing.x;
+        }
+
+        // leave the same room on both sides so the contents stay centered in the bar
+        let layout = Layout::left_to_right(Align::Center);
+        let mut content_ui = bar_ui.new_child(
+            UiBuilder::new()
+                .max_rect(inner_rect.shrink2(vec2(close_width, 0.0)))
+                .layout(layout)
+        );
+        let aligned = WidgetAligner::center()
+            .id(self.id.with("contents"))
+            .bounds(Bounds::max_rect())
+            .layout(layout)
+            .show(&mut content_ui, add_contents);
+
+        if !aligned.sizing_pass {
+            let content_height = aligned.content_rect.height().max(ui.spacing().interact_size.y);
+            let new_height = content_height + 2.0 * self.margin.y;
+            if new_height != height {
+                ctx.data_mut(|data| data.insert_temp(height_id, new_height));
+                ctx.request_repaint();
+            }
+        }
+
+        Some(InnerResponse { inner: aligned.inner, response })
+    }
+}
+
+#[inline]
+/// Pin a closable banner of the given severity to the top of the ui, with its contents centered
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::{banner, Severity};
+///
+/// # egui::__run_test_ui(|ui| {
+/// banner(ui, Severity::Error, |ui| {
+///     ui.label("The last sync failed.");
+///     let _ = ui.link("Retry");
+/// });
+/// # });
+/// ```
+pub fn banner<R>(ui: &mut Ui, severity: Severity, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+    let id = ui.next_auto_id();
+    ui.skip_ahead_auto_ids(1);
+    Banner::new(id)
+        .severity(severity)
+        .show(ui, add_contents)
+}
@@ -0,0 +1,123 @@
+//! Bars with groups of items aligned to their ends
+
+use egui::{Align, Align2, InnerResponse, Layout, Sense, TextStyle, Ui, UiBuilder, Vec2, ViewportCommand};
+
+use crate::WidgetAligner;
+
+/// Show a menu bar with the menus on the left and other items,
+/// such as a search box or an avatar, aligned to the right.
+///
+/// The right items are laid out left to right in their reading order,
+/// their width is measured and memorized to align them to the right end of the bar.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::menu_bar_aligned;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let mut search = String::new();
+/// menu_bar_aligned(
+///     ui,
+///     |ui| {
+///         ui.menu_button("File", |ui| {
+///             if ui.button("Quit").clicked() {
+///                 ui.close_menu();
+///             }
+///         });
+///         ui.menu_button("Edit", |ui| {
+///             let _ = ui.button("Undo");
+///         });
+///     },
+///     |ui| {
+///         ui.text_edit_singleline(&mut search);
+///         ui.label("👤");
+///     },
+/// );
+/// # });
+/// ```
+pub fn menu_bar_aligned<L, R>(
+    ui: &mut Ui,
+    left_menus: impl FnOnce(&mut Ui) -> L,
+    right_items: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<(L, R)> {
+    egui::menu::bar(ui, |ui| {
+        let left = left_menus(ui);
+        let right = WidgetAligner::from_align(Align2::RIGHT_CENTER)
+            .layout(Layout::left_to_right(Align::Center))
+            .show(ui, right_items)
+            .inner;
+        (left, right)
+    })
+}
+
+/// Show a title bar for apps with a borderless window:
+/// the items on the left, such as window controls, the centered title,
+/// and the items on the right, such as buttons, in a bar 32 points high.
+///
+/// Dragging the bar moves the window and double-clicking it maximizes or restores the window,
+/// except on the interactive items, which are above the drag region.
+/// The returned response is the one of the drag region.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::ViewportCommand;
+/// use egui_alignments::title_bar;
+///
+/// # egui::__run_test_ui(|ui| {
+/// title_bar(
+///     ui,
+///     |ui| {
+///         if ui.button("❌").clicked() {
+///             ui.ctx().send_viewport_cmd(ViewportCommand::Close);
+///         }
+///         if ui.button("🗕").clicked() {
+///             ui.ctx().send_viewport_cmd(ViewportCommand::Minimized(true));
+///         }
+///     },
+///     "My App",
+///     |ui| {
+///         let _ = ui.button("⚙");
+///     },
+/// );
+/// # });
+/// ```
+pub fn title_bar<L, R>(
+    ui: &mut Ui,
+    left: impl FnOnce(&mut Ui) -> L,
+    title: &str,
+    right: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<(L, R)> {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 32.0), Sense::hover());
+
+    // the drag region is created first, so the items added after it are above it
+    let response = ui.interact(rect, ui.id().with("title_bar"), Sense::click_and_drag());
+    if response.double_clicked() {
+        let maximized = ui.input(|input| input.viewport().maximized.unwrap_or(false));
+        ui.ctx().send_viewport_cmd(ViewportCommand::Maximized(!maximized));
+    } else if response.drag_started() {
+        ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
+    }
+
+    ui.painter().text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        title,
+        TextStyle::Body.resolve(ui.style()),
+        ui.visuals().strong_text_color(),
+    );
+
+    let mut bar_ui = ui.new_child(
+        UiBuilder::new()
+            .max_rect(rect.shrink2(Vec2::new(ui.spacing().item_spacing.x, 0.0)))
+            .layout(Layout::left_to_right(Align::Center))
+    );
+    let left = left(&mut bar_ui);
+    let right = WidgetAligner::from_align(Align2::RIGHT_CENTER)
+        .layout(Layout::left_to_right(Align::Center))
+        .show(&mut bar_ui, right)
+        .inner;
+
+    InnerResponse { inner: (left, right), response }
+}
@@ -0,0 +1,151 @@
+// Shims over the egui APIs which changed between the supported versions.
+//
+// The rest of the crate calls these instead of the changed APIs,
+// so it builds against any egui version selected by the features.
+// The APIs the crate relies on the most, `UiBuilder`, `Margin` conversions
+// and `Context::request_discard`, are the same from egui 0.29 on,
+// which is why older versions are not supported.
+
+pub(crate) use imp::*;
+
+// egui 0.29 and 0.30
+#[cfg(not(feature = "egui_0_31"))]
+mod imp {
+    use egui::{style::WidgetVisuals, Color32, Frame, Margin, Painter, Rect, Stroke};
+
+    pub(crate) use egui::Rounding as CornerRadius;
+
+    /// A frame without margins, fill or stroke.
+    #[inline]
+    pub(crate) fn frame_none() -> Frame {
+        Frame::none()
+    }
+
+    /// Set the corner radius of a frame.
+    #[inline]
+    pub(crate) fn frame_corner_radius(frame: Frame, corner_radius: impl Into<CornerRadius>) -> Frame {
+        frame.rounding(corner_radius)
+    }
+
+    /// The corner radius of the widgets with the given visuals.
+    #[inline]
+    pub(crate) fn widget_corner_radius(visuals: &WidgetVisuals) -> CornerRadius {
+        visuals.rounding
+    }
+
+    /// Paint the outline of a rect, centered on its edges.
+    #[inline]
+    pub(crate) fn rect_stroke(
+        painter: &Painter,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        stroke: impl Into<Stroke>,
+    ) {
+        painter.rect_stroke(rect, corner_radius, stroke);
+    }
+
+    /// Paint a filled rect with an outline centered on its edges.
+    #[inline]
+    pub(crate) fn rect(
+        painter: &Painter,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<Stroke>,
+    ) {
+        painter.rect(rect, corner_radius, fill_color, stroke);
+    }
+
+    /// Add to the top of a margin.
+    #[inline]
+    pub(crate) fn margin_add_top(mut margin: Margin, top: f32) -> Margin {
+        margin.top += top;
+        margin
+    }
+}
+
+// egui 0.31 renamed `Rounding` to `CornerRadius`,
+// and the rect painters take the side of the edges to paint the stroke on
+#[cfg(feature = "egui_0_31")]
+mod imp {
+    use egui::{style::WidgetVisuals, Color32, Frame, Margin, Painter, Rect, Stroke, StrokeKind};
+
+    pub(crate) use egui::CornerRadius;
+
+    #[inline]
+    pub(crate) fn frame_none() -> Frame {
+        Frame::NONE
+    }
+
+    #[inline]
+    pub(crate) fn frame_corner_radius(frame: Frame, corner_radius: impl Into<CornerRadius>) -> Frame {
+        frame.corner_radius(corner_radius)
+    }
+
+    #[inline]
+    pub(crate) fn widget_corner_radius(visuals: &WidgetVisuals) -> CornerRadius {
+        visuals.corner_radius
+    }
+
+    #[inline]
+    pub(crate) fn rect_stroke(
+        painter: &Painter,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        stroke: impl Into<Stroke>,
+    ) {
+        painter.rect_stroke(rect, corner_radius, stroke, StrokeKind::Middle);
+    }
+
+    #[inline]
+    pub(crate) fn rect(
+        painter: &Painter,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<Stroke>,
+    ) {
+        painter.rect(rect, corner_radius, fill_color, stroke, StrokeKind::Middle);
+    }
+
+    // the margins are whole points since egui 0.31
+    #[inline]
+    pub(crate) fn margin_add_top(mut margin: Margin, top: f32) -> Margin {
+        margin.top = (margin.top as f32 + top).round().clamp(i8::MIN as f32, i8::MAX as f32) as i8;
+        margin
+    }
+}
+
+// egui 0.30 skips the widgets on the layers behind a modal when moving the focus
+#[cfg(not(any(feature = "egui_0_30", feature = "egui_0_31")))]
+#[inline]
+pub(crate) fn interested_in_focus(memory: &mut egui::Memory, id: egui::Id, _layer_id: egui::LayerId) {
+    memory.interested_in_focus(id);
+}
+
+#[cfg(any(feature = "egui_0_30", feature = "egui_0_31"))]
+#[inline]
+pub(crate) fn interested_in_focus(memory: &mut egui::Memory, id: egui::Id, layer_id: egui::LayerId) {
+    memory.interested_in_focus(id, layer_id);
+}
+
+// egui 0.30 moved the layer of a child ui to `UiBuilder`
+#[cfg(not(any(feature = "egui_0_30", feature = "egui_0_31")))]
+#[inline]
+pub(crate) fn scope_with_layer_id<R>(
+    ui: &mut egui::Ui,
+    layer_id: egui::LayerId,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    ui.with_layer_id(layer_id, add_contents)
+}
+
+#[cfg(any(feature = "egui_0_30", feature = "egui_0_31"))]
+#[inline]
+pub(crate) fn scope_with_layer_id<R>(
+    ui: &mut egui::Ui,
+    layer_id: egui::LayerId,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    ui.scope_builder(egui::UiBuilder::new().layer_id(layer_id), add_contents)
+}
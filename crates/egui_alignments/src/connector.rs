@@ -0,0 +1,202 @@
+use std::hash::Hash;
+
+use egui::{emath::Rot2, epaint::CubicBezierShape, pos2, vec2, Color32, Pos2, Rect, Shape, Stroke, Ui, Vec2};
+
+use crate::{Anchors, Edge};
+
+/// The shape of a connector drawn by [`connect`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectorShape {
+    /// A straight line between the edges.
+    Straight,
+
+    /// Straight segments turning at right angles halfway between the edges.
+    #[default]
+    Elbow,
+
+    /// A curve leaving and reaching the edges at right angles.
+    Curved,
+}
+
+/// The style of a connector drawn by [`connect`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConnectorStyle {
+    /// The shape of the connector.
+    pub shape: ConnectorShape,
+
+    /// The stroke of the connector.
+    /// If `None`, use the foreground stroke of the non-interactive widgets.
+    pub stroke: Option<Stroke>,
+
+    /// The space between the rects and the ends of the connector.
+    pub gap: f32,
+
+    /// The length of the arrow head at the end of the connector, if any.
+    pub arrow: Option<f32>,
+}
+
+impl Default for ConnectorStyle {
+    fn default() -> Self {
+        Self {
+            shape: ConnectorShape::Elbow,
+            stroke: None,
+            gap: 2.0,
+            arrow: None,
+        }
+    }
+}
+
+impl ConnectorStyle {
+    #[inline]
+    /// Set the shape of the connector.
+    pub fn shape(mut self, shape: ConnectorShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    #[inline]
+    /// Set the stroke of the connector.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    #[inline]
+    /// Set the space between the rects and the ends of the connector.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    #[inline]
+    /// End the connector with an arrow head of the given length.
+    pub fn arrow(mut self, length: f32) -> Self {
+        self.arrow = Some(length);
+        self
+    }
+}
+
+// the facing edges of two rects, along the axis they are the furthest apart on,
+// or `None` if they overlap
+fn facing_edges(a: Rect, b: Rect) -> Option<(Edge, Edge)> {
+    let dx = (b.left() - a.right()).max(a.left() - b.right());
+    let dy = (b.top() - a.bottom()).max(a.top() - b.bottom());
+    if dx <= 0.0 && dy <= 0.0 {
+        return None;
+    }
+
+    Some(if dx >= dy {
+        if b.center().x >= a.center().x { (Edge::Right, Edge::Left) } else { (Edge::Left, Edge::Right) }
+    } else if b.center().y >= a.center().y {
+        (Edge::Bottom, Edge::Top)
+    } else {
+        (Edge::Top, Edge::Bottom)
+    })
+}
+
+// the outward direction of an edge
+fn outward(edge: Edge) -> Vec2 {
+    match edge {
+        Edge::Top => vec2(0.0, -1.0),
+        Edge::Bottom => vec2(0.0, 1.0),
+        Edge::Left => vec2(-1.0, 0.0),
+        Edge::Right => vec2(1.0, 0.0),
+    }
+}
+
+// the middle of an edge, moved outward by the gap
+fn edge_point(rect: Rect, edge: Edge, gap: f32) -> Pos2 {
+    let middle = match edge {
+        Edge::Top => rect.center_top(),
+        Edge::Bottom => rect.center_bottom(),
+        Edge::Left => rect.left_center(),
+        Edge::Right => rect.right_center(),
+    };
+    middle + outward(edge) * gap
+}
+
+/// Draw a connector between the closest edges of two rects, e.g. a leader line
+/// from a label to the widget it describes, or an edge between the nodes of a diagram.
+///
+/// The connector joins the middles of the facing edges, along the axis the rects are
+/// the furthest apart on. Nothing is drawn if the rects overlap.
+/// Returns if a connector was drawn.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{connect, ConnectorShape, ConnectorStyle, Row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let nodes = Row::new(Align::Center).show(ui, |ui| {
+///     let a = ui.button("Input").rect;
+///     ui.add_space(40.0);
+///     let b = ui.button("Output").rect;
+///     (a, b)
+/// });
+/// let (a, b) = nodes.inner;
+/// connect(ui, a, b, ConnectorStyle::default().shape(ConnectorShape::Curved).arrow(6.0));
+/// # });
+/// ```
+pub fn connect(ui: &Ui, a: Rect, b: Rect, style: ConnectorStyle) -> bool {
+    let Some((edge_a, edge_b)) = facing_edges(a, b) else {
+        return false;
+    };
+    let stroke = style.stroke.unwrap_or(ui.visuals().widgets.noninteractive.fg_stroke);
+    let start = edge_point(a, edge_a, style.gap);
+    let end = edge_point(b, edge_b, style.gap);
+    let horizontal = edge_a.is_vertical();
+
+    // the direction the connector reaches the end in, for the arrow head
+    let direction = match style.shape {
+        ConnectorShape::Straight => {
+            ui.painter().line_segment([start, end], stroke);
+            (end - start).normalized()
+        },
+        ConnectorShape::Elbow => {
+            let points = if horizontal {
+                let x = (start.x + end.x) / 2.0;
+                vec![start, pos2(x, start.y), pos2(x, end.y), end]
+            } else {
+                let y = (start.y + end.y) / 2.0;
+                vec![start, pos2(start.x, y), pos2(end.x, y), end]
+            };
+            ui.painter().add(Shape::line(points, stroke));
+            -outward(edge_b)
+        },
+        ConnectorShape::Curved => {
+            let control = (end - start) * 0.5;
+            let control = if horizontal { vec2(control.x, 0.0) } else { vec2(0.0, control.y) };
+            ui.painter().add(CubicBezierShape::from_points_stroke(
+                [start, start + control, end - control, end],
+                false,
+                Color32::TRANSPARENT,
+                stroke,
+            ));
+            -outward(edge_b)
+        },
+    };
+
+    if let Some(length) = style.arrow {
+        let rot = Rot2::from_angle(std::f32::consts::TAU / 12.0);
+        let back = -direction * length;
+        ui.painter().line_segment([end, end + rot * back], stroke);
+        ui.painter().line_segment([end, end + rot.inverse() * back], stroke);
+    }
+    true
+}
+
+/// Draw a connector between the rects published with the given names in [`Anchors`].
+/// See [`connect`].
+///
+/// Returns if a connector was drawn, which it is not while an anchor is unknown.
+pub fn connect_anchors(ui: &Ui, a: impl Hash, b: impl Hash, style: ConnectorStyle) -> bool {
+    let anchors = Anchors::new(ui.ctx());
+    match (anchors.get(a), anchors.get(b)) {
+        (Some(a), Some(b)) => connect(ui, a, b, style),
+        _ => false,
+    }
+}
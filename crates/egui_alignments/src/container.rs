@@ -2,6 +2,7 @@
 //! 
 //! # Example
 //! ```
+//! # use egui_alignments::egui;
 //! use egui::Align;
 //! use egui_alignments::{column, row};
 //! 
@@ -18,90 +19,46 @@
 //! # });
 //! ```
 
+pub mod board;
+pub mod chat;
 pub mod column;
+pub mod dash_grid;
+pub mod expander;
+pub mod fit_box;
+pub mod focus_scope;
+pub mod frozen;
+pub mod generic;
+pub mod labeled_group;
+pub mod letterbox;
+pub mod pin;
+pub mod rotated;
 pub mod row;
+pub mod scroll_sync;
+pub mod sidebar;
+pub mod slide;
+pub mod split;
+pub mod timeline;
+pub mod tracks;
+pub mod zoom_pan;
 
+pub use board::*;
+pub use chat::*;
 pub use column::*;
+pub use dash_grid::*;
+pub use expander::*;
+pub use fit_box::*;
+pub use focus_scope::*;
+pub use frozen::*;
+pub use generic::*;
+pub use labeled_group::*;
+pub use letterbox::*;
+pub use pin::*;
+pub use rotated::*;
 pub use row::*;
-
-use egui::{Id, InnerResponse, Layout, Sense, Ui, UiBuilder, Vec2};
-
-use crate::resize_layout_rect;
-
-pub(crate) struct Container {
-    pub(crate) id: Option<Id>,
-    pub(crate) layout: Layout,
-    pub(crate) padding: egui::Margin,
-    pub(crate) max_size: Vec2,
-    pub(crate) min_size: Vec2,
-}
-
-impl Container {
-    pub(crate) fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
-        // used to memorize content size
-        let id = self.id.unwrap_or_else(|| {
-            let id = ui.next_auto_id();
-            ui.skip_ahead_auto_ids(1);
-            id
-        });
-
-        // try to get content size from cache
-        // if not cached, start a sizing pass
-        let mut sizing_pass = false;
-        let available_rect = ui.available_rect_before_wrap();
-        let desired_size = ui.ctx().data_mut(|data| {
-            data.get_temp(id)
-        })
-        .unwrap_or_else(|| {
-            sizing_pass = true;
-            // the current pass is a sizing pass, request a rendering pass
-            ui.ctx().request_discard("new Container");
-            available_rect.size()
-        });
-
-        // get the supposed content rect
-        let content_rect = {
-            let (_, next_rect) = ui.new_child(UiBuilder::new()).allocate_space(
-                desired_size
-                    .max(self.min_size)
-                    .min(self.max_size)
-            );
-            let expanded_rect = resize_layout_rect(next_rect, available_rect.size(), &self.layout);
-            expanded_rect - self.padding
-        };
-
-        // create child ui
-        let mut content_ui = ui.new_child({
-            let builder = UiBuilder::new()
-                .max_rect(content_rect);
-            
-            if sizing_pass {
-                builder.layout(
-                        // in sizing pass, keep the layout size minimum
-                        self.layout
-                            .with_cross_align(egui::Align::Min)
-                            .with_cross_justify(false)
-                    )
-                    .sizing_pass()
-                    .invisible()
-            } else {
-                builder.layout(self.layout)
-            }
-        });
-
-        // add contents and calculate space to be allocated
-        let inner = add_contents(&mut content_ui);
-        let new_rect = content_ui.min_rect() + self.padding;
-        // allocate space and get response
-        let response = ui.allocate_rect(new_rect, Sense::hover());
-
-        // cache content size
-        if sizing_pass || new_rect.size() != desired_size {
-            ui.ctx().data_mut(|data| {
-                data.insert_temp(id, new_rect.size())
-            });
-        }
-
-        InnerResponse { inner, response, }
-    }
-}
+pub use scroll_sync::*;
+pub use sidebar::*;
+pub use slide::*;
+pub use split::*;
+pub use timeline::*;
+pub use tracks::*;
+pub use zoom_pan::*;
@@ -0,0 +1,211 @@
+use egui::{pos2, Align, Id, InnerResponse, Layout, Rect, ScrollArea, Sense, Ui, UiBuilder, Vec2};
+
+/// A container which splits the available space into equal-width columns
+/// sharing the available height.
+/// Each column has a pinned header and an individually scrollable body,
+/// like the columns of a kanban board.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::Board;
+///
+/// # egui::__run_test_ui(|ui| {
+/// Board::new(3).show(ui, |board| {
+///     for title in ["Todo", "Doing", "Done"] {
+///         board.column(
+///             |ui| { ui.heading(title); },
+///             |ui| {
+///                 for i in 0..20 {
+///                     ui.label(format!("{} card {}", title, i));
+///                 }
+///             },
+///         );
+///     }
+/// });
+/// # });
+/// ```
+pub struct Board {
+    /// Used to memorize the header height and the scroll offsets.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The number of columns.
+    pub columns: usize,
+
+    /// The horizontal alignment of the column headers.
+    pub header_align: Align,
+
+    /// The horizontal alignment of the column bodies.
+    pub body_align: Align,
+
+    /// The fixed height of the column headers.
+    /// If `None`, all headers share the height of the tallest one.
+    pub header_height: Option<f32>,
+
+    /// The spacing between columns, and between headers and bodies.
+    /// If `None`, use the item spacing of the ui.
+    pub spacing: Option<Vec2>,
+}
+
+impl Board {
+    #[inline]
+    /// Create a new board with the given number of columns.
+    pub fn new(columns: usize) -> Self {
+        Self {
+            id: None,
+            columns,
+            header_align: Align::Center,
+            body_align: Align::Min,
+            header_height: None,
+            spacing: None,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the board.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the horizontal alignment of the column headers.
+    pub fn header_align(mut self, align: Align) -> Self {
+        self.header_align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the horizontal alignment of the column bodies.
+    pub fn body_align(mut self, align: Align) -> Self {
+        self.body_align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the fixed height of the column headers.
+    pub fn header_height(mut self, height: f32) -> Self {
+        self.header_height = Some(height);
+        self
+    }
+
+    #[inline]
+    /// Set the spacing between columns, and between headers and bodies.
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+}
+
+/// The handle used to add columns to a [`Board`].
+pub struct BoardUi<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+    rect: Rect,
+    column_width: f32,
+    spacing: Vec2,
+    header_align: Align,
+    body_align: Align,
+    header_height: f32,
+    max_header_height: f32,
+    index: usize,
+}
+
+impl BoardUi<'_> {
+    /// Add the next column with a pinned header and a scrollable body.
+    /// Columns exceeding the configured column count are not shown.
+    pub fn column<R>(
+        &mut self,
+        add_header: impl FnOnce(&mut Ui),
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<R> {
+        let index = self.index;
+        self.index += 1;
+
+        let left = self.rect.left() + index as f32 * (self.column_width + self.spacing.x);
+        if left + self.column_width > self.rect.right() + 0.5 {
+            return None;
+        }
+
+        let column_rect = Rect::from_min_max(
+            pos2(left, self.rect.top()),
+            pos2(left + self.column_width, self.rect.bottom()),
+        );
+
+        // the pinned header
+        let mut header_ui = self.ui.new_child(
+            UiBuilder::new()
+                .id_salt(("header", index))
+                .max_rect(column_rect)
+                .layout(Layout::top_down(self.header_align))
+        );
+        add_header(&mut header_ui);
+        // measured before the shared height pads it, for the headers to shrink with their contents
+        let natural_height = header_ui.min_rect().height();
+        self.max_header_height = self.max_header_height.max(natural_height);
+        header_ui.set_min_height(self.header_height);
+
+        // the scrollable body
+        let body_top = column_rect.top() + self.header_height.max(natural_height) + self.spacing.y;
+        let body_rect = Rect::from_min_max(pos2(column_rect.left(), body_top), column_rect.max);
+        let mut body_ui = self.ui.new_child(
+            UiBuilder::new()
+                .id_salt(("body", index))
+                .max_rect(body_rect)
+                .layout(Layout::top_down(self.body_align))
+        );
+        let inner = ScrollArea::vertical()
+            .id_salt(self.id.with(index))
+            .auto_shrink(false)
+            .show(&mut body_ui, add_body)
+            .inner;
+
+        Some(inner)
+    }
+}
+
+impl Board {
+    /// Show the board in the given ui.
+    pub fn show<R>(&self, ui: &mut Ui, add_columns: impl FnOnce(&mut BoardUi) -> R) -> InnerResponse<R> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+
+        let rect = ui.available_rect_before_wrap();
+        let spacing = self.spacing.unwrap_or(ui.spacing().item_spacing);
+        let columns = self.columns.max(1) as f32;
+        let column_width = ((rect.width() - spacing.x * (columns - 1.0)) / columns).max(0.0);
+
+        // headers share the height of the tallest one of the last frame
+        let header_height = self.header_height.unwrap_or_else(|| {
+            ui.ctx().data(|data| data.get_temp(id)).unwrap_or(0.0)
+        });
+
+        let mut board_ui = BoardUi {
+            ui,
+            id,
+            rect,
+            column_width,
+            spacing,
+            header_align: self.header_align,
+            body_align: self.body_align,
+            header_height,
+            max_header_height: 0.0,
+            index: 0,
+        };
+        let inner = add_columns(&mut board_ui);
+        let max_header_height = board_ui.max_header_height;
+
+        if self.header_height.is_none() && max_header_height != header_height {
+            ui.ctx().data_mut(|data| data.insert_temp(id, max_header_height));
+            crate::request_discard(ui.ctx(), "Board header height");
+        }
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+
+        InnerResponse { inner, response }
+    }
+}
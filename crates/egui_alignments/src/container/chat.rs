@@ -0,0 +1,222 @@
+use egui::{vec2, Align, Align2, Color32, Id, InnerResponse, Layout, Margin, Ui};
+
+use crate::{compat::{self, CornerRadius}, AllocateType, Bounds, WidgetAligner};
+
+/// The author side of a chat message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChatSide {
+    /// Sent by the local user, shown on the right.
+    Mine,
+
+    /// Sent by someone else, shown on the left.
+    Theirs,
+}
+
+/// A message which can be shown in a [`ChatList`].
+pub trait ChatMessage {
+    /// The side on which the message is shown.
+    fn side(&self) -> ChatSide;
+}
+
+impl<T> ChatMessage for (ChatSide, T) {
+    fn side(&self) -> ChatSide {
+        self.0
+    }
+}
+
+impl<T: ChatMessage> ChatMessage for &T {
+    fn side(&self) -> ChatSide {
+        (*self).side()
+    }
+}
+
+/// A list of chat messages, each wrapped in a bubble
+/// which is right-aligned for [`ChatSide::Mine`] and left-aligned for [`ChatSide::Theirs`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{ChatList, ChatSide};
+///
+/// let messages = vec![
+///     (ChatSide::Theirs, "Hi!"),
+///     (ChatSide::Mine, "Hello, how are you?"),
+/// ];
+///
+/// # egui::__run_test_ui(|ui| {
+/// ChatList::new().show(ui, &messages, |ui, (_, text)| {
+///     ui.label(*text);
+/// });
+/// # });
+/// ```
+pub struct ChatList {
+    /// Used to memorize bubble sizes.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The maximum width of a bubble, as a fraction of the available width.
+    /// Default: `0.7`.
+    pub max_width_ratio: f32,
+
+    /// The vertical spacing between bubbles.
+    /// If `None`, use the item spacing of the ui.
+    pub spacing: Option<f32>,
+
+    /// The inner margin of a bubble.
+    pub inner_margin: Margin,
+
+    /// The corner radius of a bubble.
+    pub corner_radius: CornerRadius,
+
+    /// The fill of [`ChatSide::Mine`] bubbles.
+    /// If `None`, use the selection color of the current visuals.
+    pub mine_fill: Option<Color32>,
+
+    /// The fill of [`ChatSide::Theirs`] bubbles.
+    /// If `None`, use the extreme background color of the current visuals.
+    pub theirs_fill: Option<Color32>,
+}
+
+impl ChatList {
+    #[inline]
+    /// Create a new chat list.
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            max_width_ratio: 0.7,
+            spacing: None,
+            inner_margin: Margin::from(vec2(8.0, 6.0)),
+            corner_radius: 8.0.into(),
+            mine_fill: None,
+            theirs_fill: None,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the chat list.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the maximum width of a bubble, as a fraction of the available width.
+    pub fn max_width_ratio(mut self, ratio: f32) -> Self {
+        self.max_width_ratio = ratio;
+        self
+    }
+
+    #[inline]
+    /// Set the vertical spacing between bubbles.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    #[inline]
+    /// Set the inner margin of a bubble.
+    pub fn inner_margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.inner_margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the corner radius of a bubble.
+    pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
+        self.corner_radius = corner_radius.into();
+        self
+    }
+
+    #[inline]
+    /// Set the fill of [`ChatSide::Mine`] bubbles.
+    pub fn mine_fill(mut self, fill: Color32) -> Self {
+        self.mine_fill = Some(fill);
+        self
+    }
+
+    #[inline]
+    /// Set the fill of [`ChatSide::Theirs`] bubbles.
+    pub fn theirs_fill(mut self, fill: Color32) -> Self {
+        self.theirs_fill = Some(fill);
+        self
+    }
+}
+
+impl Default for ChatList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChatList {
+    /// Show the messages in the given ui.
+    /// `add_message` is called inside the bubble of each message.
+    pub fn show<M: ChatMessage>(
+        &self,
+        ui: &mut Ui,
+        messages: impl IntoIterator<Item = M>,
+        mut add_message: impl FnMut(&mut Ui, M),
+    ) -> InnerResponse<()> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+
+        let mine_fill = self.mine_fill.unwrap_or(ui.visuals().selection.bg_fill);
+        let theirs_fill = self.theirs_fill.unwrap_or(ui.visuals().extreme_bg_color);
+
+        ui.with_layout(Layout::top_down(Align::Min), |ui| {
+            if let Some(spacing) = self.spacing {
+                ui.spacing_mut().item_spacing.y = spacing;
+            }
+
+            let width = ui.available_width();
+            let max_bubble_width = (width * self.max_width_ratio - self.inner_margin.sum().x).max(0.0);
+
+            for (i, message) in messages.into_iter().enumerate() {
+                let (align, fill) = match message.side() {
+                    ChatSide::Mine => (Align2::RIGHT_TOP, mine_fill),
+                    ChatSide::Theirs => (Align2::LEFT_TOP, theirs_fill),
+                };
+
+                WidgetAligner::from_align(align)
+                    .id(id.with(i))
+                    .bounds(Bounds::AvailableRect(vec2(width, f32::INFINITY)))
+                    .allocate_type(AllocateType::ContentRow)
+                    .layout(Layout::top_down(Align::Min))
+                    .show(ui, |ui| {
+                        compat::frame_corner_radius(compat::frame_none(), self.corner_radius)
+                            .fill(fill)
+                            .inner_margin(self.inner_margin)
+                            .show(ui, |ui| {
+                                ui.set_max_width(max_bubble_width);
+                                add_message(ui, message);
+                            });
+                    });
+            }
+        })
+    }
+}
+
+#[inline]
+/// Show a list of chat messages with the default bubble style
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::{chat_list, ChatSide};
+///
+/// # egui::__run_test_ui(|ui| {
+/// chat_list(ui, [(ChatSide::Mine, "Ping"), (ChatSide::Theirs, "Pong")], |ui, (_, text)| {
+///     ui.label(text);
+/// });
+/// # });
+/// ```
+pub fn chat_list<M: ChatMessage>(
+    ui: &mut Ui,
+    messages: impl IntoIterator<Item = M>,
+    add_message: impl FnMut(&mut Ui, M),
+) -> InnerResponse<()> {
+    ChatList::new().show(ui, messages, add_message)
+}
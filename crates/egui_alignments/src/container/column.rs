@@ -1,11 +1,10 @@
-use std::f32::INFINITY;
+use egui::{vec2, Align, Align2, Id, InnerResponse, Layout, Margin, Rect, Sense, Ui, UiBuilder, Vec2};
 
-use egui::{vec2, Align, Id, InnerResponse, Layout, Margin, Ui};
-
-use super::Container;
+use crate::{AlignedResponse, AllocateType, Axis, GenericContainer, OverflowCallback, LayoutError, ResizeCallback, Skeleton, WidgetAligner};
 
 /// A container which aligns its contents vertically.
 /// See module [`crate::container`] for example usage.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Column {
     /// The id of the column. Used for memorize content size.
     /// If `None`, the id will be generated automatically.
@@ -27,6 +26,34 @@ pub struct Column {
     
     /// The minimum width of the column.
     pub min_width: f32,
+
+    /// The size of the contents, if known up front.
+    /// Used instead of a sizing pass when no size is memorized yet.
+    pub size_hint: Option<Vec2>,
+
+    /// See [`AllocateType`].
+    /// The bounds are the available rect of the ui.
+    pub allocate_type: AllocateType,
+
+    /// Called after the layout for each axis on which the contents exceeded the bounds.
+    /// Not called in sizing passes.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_overflow: Option<OverflowCallback>,
+
+    /// Called when the memorized size of the contents changes, with the old and the new size.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_resize: Option<ResizeCallback>,
+
+    /// If set, painted in place of the contents during the sizing pass.
+    pub skeleton: Option<Skeleton>,
+
+    /// If set, the contents fade in over this duration, in seconds,
+    /// once their layout is stable after they are first shown.
+    pub fade_in: Option<f32>,
+
+    /// If the contents are skipped by `show_culled` when outside the clip rect.
+    /// Disable it if the contents have side effects which must run every frame.
+    pub cull: bool,
 }
 
 impl Column {
@@ -38,8 +65,15 @@ impl Column {
             halign,
             padding: Margin::ZERO,
             bottom_up: false,
-            max_width: INFINITY,
+            max_width: f32::INFINITY,
             min_width: 0.0,
+            size_hint: None,
+            allocate_type: AllocateType::Content,
+            on_overflow: None,
+            on_resize: None,
+            skeleton: None,
+            fade_in: None,
+            cull: true,
         }
     }
     
@@ -92,6 +126,57 @@ impl Column {
         self.min_width = width;
         self
     }
+    #[inline]
+    /// Set the size of the contents, if known up front, to skip the sizing pass.
+    pub fn size_hint(mut self, size: impl Into<Vec2>) -> Self {
+        self.size_hint = Some(size.into());
+        self
+    }
+
+    #[inline]
+    /// See [`AllocateType`]
+    pub fn allocate_type(mut self, allocate_type: AllocateType) -> Self {
+        self.allocate_type = allocate_type;
+        self
+    }
+
+    #[inline]
+    /// Set a callback invoked after the layout with the axis and the amount
+    /// by which the contents exceeded the bounds.
+    pub fn on_overflow(mut self, on_overflow: impl Fn(Axis, f32) + 'static) -> Self {
+        self.on_overflow = Some(Box::new(on_overflow));
+        self
+    }
+
+    #[inline]
+    /// Set a callback invoked with the old and the new size of the contents
+    /// when their memorized size changes.
+    pub fn on_resize(mut self, on_resize: impl Fn(Vec2, Vec2) + 'static) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    #[inline]
+    /// Set a placeholder painted in place of the contents during the sizing pass.
+    pub fn skeleton(mut self, skeleton: Skeleton) -> Self {
+        self.skeleton = Some(skeleton);
+        self
+    }
+
+    #[inline]
+    /// Fade the contents in over the given duration, in seconds,
+    /// once their layout is stable after they are first shown, e.g. `0.1`.
+    pub fn fade_in(mut self, duration: f32) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+
+    #[inline]
+    /// Set whether the contents are skipped by `show_culled` when outside the clip rect.
+    pub fn cull(mut self, cull: bool) -> Self {
+        self.cull = cull;
+        self
+    }
 }
 
 impl Default for Column {
@@ -102,13 +187,48 @@ impl Default for Column {
 
 impl Column {
     /// Show the column in the given ui.
-    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<R> {
+        let response = self.container().show_with_resize(ui, self.on_resize.as_ref(), add_contents);
+        response.report_overflow(self.on_overflow.as_ref());
+        response
+    }
+
+    /// Show the column, or return an error without showing it if it cannot be laid out.
+    /// See [`GenericContainer::try_show`].
+    pub fn try_show<R>(
+        &self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Result<AlignedResponse<R>, LayoutError> {
+        self.container().check(ui)?;
+        Ok(self.show(ui, add_contents))
+    }
+
+    /// Show the column, skipping the contents if it is outside the clip rect and its size is known.
+    /// See [`GenericContainer::show_culled`].
+    pub fn show_culled<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<Option<R>> {
+        let response = self.container().show_culled_with_resize(ui, self.on_resize.as_ref(), add_contents);
+        response.report_overflow(self.on_overflow.as_ref());
+        response
+    }
+
+    /// Show the column, measuring the contents in an invisible sizing pass first, in the same frame.
+    /// See [`GenericContainer::show_two_pass`].
+    pub fn show_two_pass<R>(&self, ui: &mut Ui, add_contents: impl FnMut(&mut Ui) -> R) -> AlignedResponse<R> {
+        let response = self.container().show_two_pass(ui, add_contents);
+        response.report_overflow(self.on_overflow.as_ref());
+        response
+    }
+
+    fn container(&self) -> GenericContainer {
         let Self {
             id,
             halign,
             padding,
             max_width,
             min_width,
+            size_hint,
+            allocate_type,
             ..
         } = *self;
         
@@ -118,14 +238,19 @@ impl Column {
             Layout::top_down(halign)
         };
 
-        Container {
+        GenericContainer {
             id,
             layout,
             padding,
-            max_size: vec2(max_width, INFINITY),
+            max_size: vec2(max_width, f32::INFINITY),
             min_size: vec2(min_width, 0.0),
+            size_hint,
+            allocate_type,
+            on_resize: None,
+            skeleton: self.skeleton,
+            fade_in: self.fade_in,
+            cull: self.cull,
         }
-        .show(ui, add_contents)
     }
 }
 
@@ -134,6 +259,7 @@ impl Column {
 /// 
 /// # Example
 /// ```rust
+/// # use egui_alignments::egui;
 /// use egui::Align;
 /// use egui_alignments::column;
 ///
@@ -146,5 +272,78 @@ impl Column {
 /// # });
 /// ```
 pub fn column<R>(ui: &mut Ui, halign: Align, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
-    Column::new(halign).show(ui, add_contents)
-}
\ No newline at end of file
+    Column::new(halign).show(ui, add_contents).into()
+}
+
+/// Show `count` columns of equal width, like [`Ui::columns`],
+/// but as a block only as wide as needed, aligned within the available space.
+///
+/// The columns are as wide as the widest of them, memorized from the previous pass,
+/// and grow back when their contents need more room.
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::aligned_columns;
+///
+/// # egui::__run_test_ui(|ui| {
+/// aligned_columns(ui, 3, Align2::CENTER_TOP, |columns| {
+///     columns[0].label("Free");
+///     columns[1].label("Pro");
+///     columns[2].label("Enterprise");
+/// });
+/// # });
+/// ```
+pub fn aligned_columns<R>(
+    ui: &mut Ui,
+    count: usize,
+    align: Align2,
+    add_contents: impl FnOnce(&mut [Ui]) -> R
+) -> AlignedResponse<R> {
+    let id = ui.next_auto_id();
+    ui.skip_ahead_auto_ids(1);
+    let width_id = id.with("column_width");
+
+    WidgetAligner::from_align(align)
+        .id(id)
+        .show(ui, |ui| {
+            let spacing = ui.spacing().item_spacing.x;
+            let count = count.max(1);
+            let memorized: Option<f32> = ui.ctx().data(|data| data.get_temp(width_id));
+            // the contents are laid out in a share of the available width like `Ui::columns`,
+            // so that their natural width is measured rather than the memorized one
+            let limit = ((ui.available_width() - spacing * (count - 1) as f32) / count as f32).max(0.0);
+            let width = memorized.unwrap_or(limit);
+
+            let top_left = ui.cursor().min;
+            let mut columns: Vec<Ui> = (0..count)
+                .map(|i| {
+                    let min = top_left + vec2(i as f32 * (width + spacing), 0.0);
+                    let rect = Rect::from_min_size(min, vec2(limit, ui.available_height()));
+                    ui.new_child(
+                        UiBuilder::new()
+                            .id_salt(("aligned_column", i))
+                            .max_rect(rect)
+                            .layout(Layout::top_down(Align::Min))
+                    )
+                })
+                .collect();
+            let inner = add_contents(&mut columns);
+
+            let new_width = columns.iter()
+                .map(|column| column.min_rect().width())
+                .fold(0.0, f32::max);
+            let height = columns.iter()
+                .map(|column| column.min_rect().height())
+                .fold(0.0, f32::max);
+            if memorized != Some(new_width) {
+                ui.ctx().data_mut(|data| data.insert_temp(width_id, new_width));
+                crate::request_discard(ui.ctx(), "new aligned_columns width");
+            }
+
+            let size = vec2(count as f32 * new_width + (count - 1) as f32 * spacing, height);
+            ui.allocate_rect(Rect::from_min_size(top_left, size), Sense::hover());
+            inner
+        })
+}
@@ -0,0 +1,379 @@
+use std::{collections::HashMap, hash::Hash};
+
+use egui::{
+    pos2, vec2, Align2, CursorIcon, Id, InnerResponse, Layout, Pos2, Rect, Sense, Stroke, Ui,
+    UiBuilder, Vec2,
+};
+
+use crate::{Bounds, WidgetAligner};
+
+/// The placement of a [`DashGrid`] cell, in grid units.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridCell {
+    /// The index of the leftmost column of the cell.
+    pub col: usize,
+
+    /// The index of the topmost row of the cell.
+    pub row: usize,
+
+    /// The number of columns the cell spans.
+    pub col_span: usize,
+
+    /// The number of rows the cell spans.
+    pub row_span: usize,
+}
+
+impl GridCell {
+    #[inline]
+    /// Create a cell at the given position spanning a single grid unit.
+    pub fn new(col: usize, row: usize) -> Self {
+        Self {
+            col,
+            row,
+            col_span: 1,
+            row_span: 1,
+        }
+    }
+
+    #[inline]
+    /// Set the number of columns and rows the cell spans.
+    pub fn span(mut self, col_span: usize, row_span: usize) -> Self {
+        self.col_span = col_span;
+        self.row_span = row_span;
+        self
+    }
+}
+
+/// The interaction state of a cell being dragged or resized.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct CellDrag {
+    offset: Vec2,
+    resize: Vec2,
+}
+
+/// A dashboard grid whose cells snap to a fixed number of columns and rows.
+/// Cells can be dragged to new positions and resized by the handle on their bottom-right corner.
+/// Their placements are memorized in egui memory, persisted with the `serde` feature,
+/// and the content of each cell is aligned within it using a [`WidgetAligner`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{DashGrid, GridCell};
+///
+/// # egui::__run_test_ui(|ui| {
+/// DashGrid::new(4).show(ui, |grid| {
+///     grid.cell("cpu", GridCell::new(0, 0).span(2, 1), |ui| {
+///         ui.label("CPU: 42%");
+///     });
+///     grid.cell("memory", GridCell::new(2, 0).span(2, 2), |ui| {
+///         ui.label("Memory: 1.2 GiB");
+///     });
+/// });
+/// # });
+/// ```
+pub struct DashGrid {
+    /// Used to memorize the cell placements.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The number of columns.
+    pub columns: usize,
+
+    /// The height of a single row.
+    pub row_height: f32,
+
+    /// The spacing between cells.
+    /// If `None`, use the item spacing of the ui.
+    pub spacing: Option<Vec2>,
+
+    /// The size of the resize handle.
+    pub handle_size: f32,
+
+    /// The alignment of the content of each cell.
+    pub align: Align2,
+
+    /// If `false`, cells can not be dragged or resized.
+    pub editable: bool,
+}
+
+impl DashGrid {
+    #[inline]
+    /// Create a new dashboard grid with the given number of columns.
+    pub fn new(columns: usize) -> Self {
+        Self {
+            id: None,
+            columns,
+            row_height: 80.0,
+            spacing: None,
+            handle_size: 10.0,
+            align: Align2::CENTER_CENTER,
+            editable: true,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the grid.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the height of a single row.
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    #[inline]
+    /// Set the spacing between cells.
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    #[inline]
+    /// Set the size of the resize handle.
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = handle_size;
+        self
+    }
+
+    #[inline]
+    /// Set the alignment of the content of each cell.
+    pub fn align(mut self, align: Align2) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    /// Set whether cells can be dragged and resized.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+}
+
+/// The handle used to add cells to a [`DashGrid`].
+pub struct DashGridUi<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+    origin: Pos2,
+    unit: Vec2,
+    spacing: Vec2,
+    columns: usize,
+    handle_size: f32,
+    align: Align2,
+    editable: bool,
+    placements: HashMap<Id, GridCell>,
+    placements_changed: bool,
+    bottom: f32,
+}
+
+impl DashGridUi<'_> {
+    fn cell_rect(&self, cell: GridCell) -> Rect {
+        let min = self.origin
+            + vec2(
+                cell.col as f32 * (self.unit.x + self.spacing.x),
+                cell.row as f32 * (self.unit.y + self.spacing.y),
+            );
+        let size = vec2(
+            cell.col_span as f32 * (self.unit.x + self.spacing.x) - self.spacing.x,
+            cell.row_span as f32 * (self.unit.y + self.spacing.y) - self.spacing.y,
+        );
+        Rect::from_min_size(min, size)
+    }
+
+    fn snap(&self, rect: Rect) -> GridCell {
+        let step = self.unit + self.spacing;
+        let rel = rect.min - self.origin;
+        let col_span = ((rect.width() + self.spacing.x) / step.x).round().max(1.0) as usize;
+        let row_span = ((rect.height() + self.spacing.y) / step.y).round().max(1.0) as usize;
+        let col_span = col_span.min(self.columns);
+        let col = ((rel.x / step.x).round().max(0.0) as usize).min(self.columns - col_span);
+        let row = (rel.y / step.y).round().max(0.0) as usize;
+        GridCell { col, row, col_span, row_span }
+    }
+
+    /// Add a cell identified by `id_salt`.
+    /// `initial` is used as its placement until the user moves or resizes it.
+    pub fn cell<R>(
+        &mut self,
+        id_salt: impl Hash,
+        initial: GridCell,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let cell_id = self.id.with(id_salt);
+        let drag_id = cell_id.with("drag");
+        let mut cell = self.placements.get(&cell_id).copied().unwrap_or(initial);
+        cell.col_span = cell.col_span.clamp(1, self.columns);
+        cell.col = cell.col.min(self.columns - cell.col_span);
+
+        let snapped_rect = self.cell_rect(cell);
+        let last_drag: CellDrag = self.ui.ctx().data(|data| data.get_temp(drag_id)).unwrap_or_default();
+        let mut drag = last_drag;
+        let rect = Rect::from_min_size(
+            snapped_rect.min + drag.offset,
+            (snapped_rect.size() + drag.resize).max(self.unit),
+        );
+
+        // drag the cell by its background
+        let move_response = if self.editable {
+            self.ui.interact(rect, cell_id.with("move"), Sense::drag())
+                .on_hover_cursor(CursorIcon::Grab)
+        } else {
+            self.ui.interact(rect, cell_id.with("move"), Sense::hover())
+        };
+
+        // preview the snapped target while interacting
+        let visuals = self.ui.visuals();
+        let rounding = crate::compat::widget_corner_radius(&visuals.widgets.noninteractive);
+        if drag.offset != Vec2::ZERO || drag.resize != Vec2::ZERO {
+            let target = self.cell_rect(self.snap(rect));
+            crate::compat::rect_stroke(
+                self.ui.painter(),
+                target,
+                rounding,
+                Stroke::new(1.0, visuals.selection.stroke.color),
+            );
+        }
+        crate::compat::rect(
+            self.ui.painter(),
+            rect,
+            rounding,
+            visuals.widgets.noninteractive.weak_bg_fill,
+            visuals.widgets.noninteractive.bg_stroke,
+        );
+
+        // the aligned content
+        let mut content_ui = self.ui.new_child(
+            UiBuilder::new()
+                .id_salt(cell_id)
+                .max_rect(rect.shrink(self.ui.spacing().item_spacing.x))
+                .layout(Layout::top_down(self.align.x()))
+        );
+        content_ui.set_clip_rect(rect.intersect(self.ui.clip_rect()));
+        let inner = WidgetAligner::from_align(self.align)
+            .id(cell_id.with("content"))
+            .bounds(Bounds::max_rect())
+            .show(&mut content_ui, add_contents)
+            .inner;
+
+        // the resize handle
+        if self.editable {
+            let handle_rect = Rect::from_min_max(rect.max - Vec2::splat(self.handle_size), rect.max);
+            let handle_response = self.ui
+                .interact(handle_rect, cell_id.with("resize"), Sense::drag())
+                .on_hover_cursor(CursorIcon::ResizeNwSe);
+            let stroke = self.ui.style().interact(&handle_response).fg_stroke;
+            for i in 1..=2 {
+                let d = i as f32 * self.handle_size / 3.0;
+                self.ui.painter().line_segment(
+                    [pos2(rect.right() - d, rect.bottom()), pos2(rect.right(), rect.bottom() - d)],
+                    stroke,
+                );
+            }
+
+            drag.offset += move_response.drag_delta();
+            drag.resize += handle_response.drag_delta();
+
+            if move_response.drag_stopped() || handle_response.drag_stopped() {
+                let snapped = self.snap(rect);
+                if snapped != cell {
+                    cell = snapped;
+                    self.placements.insert(cell_id, cell);
+                    self.placements_changed = true;
+                }
+                drag = CellDrag::default();
+            }
+
+            if drag != last_drag {
+                self.ui.ctx().data_mut(|data| data.insert_temp(drag_id, drag));
+            }
+        }
+
+        self.bottom = self.bottom.max(self.cell_rect(cell).bottom()).max(rect.bottom());
+
+        InnerResponse { inner, response: move_response }
+    }
+}
+
+impl DashGrid {
+    /// Show the grid in the given ui.
+    pub fn show<R>(&self, ui: &mut Ui, add_cells: impl FnOnce(&mut DashGridUi) -> R) -> InnerResponse<R> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+
+        let columns = self.columns.max(1);
+        let spacing = self.spacing.unwrap_or(ui.spacing().item_spacing);
+        let origin = ui.cursor().min;
+        let width = ui.available_width();
+        let unit = vec2(
+            ((width - spacing.x * (columns as f32 - 1.0)) / columns as f32).max(1.0),
+            self.row_height,
+        );
+
+        let placements = load_placements(ui.ctx(), id);
+
+        let mut grid_ui = DashGridUi {
+            ui,
+            id,
+            origin,
+            unit,
+            spacing,
+            columns,
+            handle_size: self.handle_size,
+            align: self.align,
+            editable: self.editable,
+            placements,
+            placements_changed: false,
+            bottom: origin.y,
+        };
+        let inner = add_cells(&mut grid_ui);
+        let DashGridUi { placements, placements_changed, bottom, .. } = grid_ui;
+
+        if placements_changed {
+            store_placements(ui.ctx(), id, placements);
+        }
+
+        let response = ui.allocate_rect(
+            Rect::from_min_max(origin, pos2(origin.x + width, bottom)),
+            Sense::hover(),
+        );
+
+        InnerResponse { inner, response }
+    }
+}
+
+// the placements moved or resized by the user, persisted with the `serde` feature
+#[cfg(feature = "serde")]
+fn load_placements(ctx: &egui::Context, id: Id) -> HashMap<Id, GridCell> {
+    ctx.data_mut(|data| data.get_persisted(id)).unwrap_or_default()
+}
+
+#[cfg(not(feature = "serde"))]
+fn load_placements(ctx: &egui::Context, id: Id) -> HashMap<Id, GridCell> {
+    ctx.data(|data| data.get_temp(id)).unwrap_or_default()
+}
+
+#[cfg(feature = "serde")]
+fn store_placements(ctx: &egui::Context, id: Id, placements: HashMap<Id, GridCell>) {
+    ctx.data_mut(|data| data.insert_persisted(id, placements));
+}
+
+#[cfg(not(feature = "serde"))]
+fn store_placements(ctx: &egui::Context, id: Id, placements: HashMap<Id, GridCell>) {
+    ctx.data_mut(|data| data.insert_temp(id, placements));
+}
+
+/// Reset the memorized placements of the grid with the given id,
+/// so that all the cells return to their initial placements.
+pub fn reset_dash_grid(ctx: &egui::Context, id: Id) {
+    ctx.data_mut(|data| data.remove::<HashMap<Id, GridCell>>(id));
+}
+
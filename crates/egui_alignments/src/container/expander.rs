@@ -0,0 +1,199 @@
+use std::hash::Hash;
+
+use egui::{vec2, Align, Context, Id, Rect, Response, Sense, Ui, UiBuilder};
+
+use crate::{Column, Row};
+
+/// A header [`Row`] which reveals a body [`Column`] below it when clicked,
+/// with the height of the body animated between zero and its memorized height.
+///
+/// Unlike [`egui::CollapsingHeader`], the whole header is supplied by the user,
+/// and the body is aligned like any column of the crate.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::Expander;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = Expander::new("advanced").show(
+///     ui,
+///     |ui| {
+///         ui.strong("Advanced settings");
+///         ui.weak("3 options");
+///     },
+///     |ui| {
+///         ui.checkbox(&mut true, "Hardware acceleration");
+///         ui.checkbox(&mut false, "Developer mode");
+///         ui.checkbox(&mut false, "Verbose logs");
+///     },
+/// );
+/// if response.header_response.clicked() {
+///     // e.g. remember the state in the settings
+/// }
+/// # });
+/// ```
+pub struct Expander {
+    /// Used to memorize the open state and the height of the body.
+    pub id: Id,
+
+    /// The vertical alignment of the header items.
+    pub header_valign: Align,
+
+    /// The horizontal alignment of the body items.
+    pub body_halign: Align,
+
+    /// If the body is revealed the first time the expander is shown.
+    pub default_open: bool,
+
+    /// The duration of the reveal, in seconds.
+    /// If `None`, use the animation time of the style.
+    pub duration: Option<f32>,
+}
+
+impl Expander {
+    #[inline]
+    /// Create a new closed expander.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            header_valign: Align::Center,
+            body_halign: Align::Min,
+            default_open: false,
+            duration: None,
+        }
+    }
+
+    #[inline]
+    /// Set the vertical alignment of the header items.
+    pub fn header_valign(mut self, valign: Align) -> Self {
+        self.header_valign = valign;
+        self
+    }
+
+    #[inline]
+    /// Set the horizontal alignment of the body items.
+    pub fn body_halign(mut self, halign: Align) -> Self {
+        self.body_halign = halign;
+        self
+    }
+
+    #[inline]
+    /// Set whether the body is revealed the first time the expander is shown.
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    #[inline]
+    /// Set the duration of the reveal, in seconds.
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+/// The response of [`Expander::show`].
+pub struct ExpanderResponse<H, B> {
+    /// What the header closure returned.
+    pub header: H,
+
+    /// The response of the header, which toggles the expander when clicked.
+    pub header_response: Response,
+
+    /// What the body closure returned, if the body was shown, even partly.
+    pub body: Option<B>,
+
+    /// If the expander is open, even if the body is still being revealed.
+    pub open: bool,
+}
+
+impl Expander {
+    /// If the expander with the given id is open.
+    pub fn is_open(ctx: &Context, id: Id) -> Option<bool> {
+        ctx.data(|data| data.get_temp::<bool>(id.with("open")))
+    }
+
+    /// Open or close the expander with the given id.
+    pub fn set_open(ctx: &Context, id: Id, open: bool) {
+        ctx.data_mut(|data| data.insert_temp(id.with("open"), open));
+    }
+
+    /// Show the header, and the body below it if the expander is open.
+    ///
+    /// Clicking the header toggles the expander,
+    /// except on its interactive widgets, e.g. buttons, which take their own clicks.
+    pub fn show<H, B>(
+        &self,
+        ui: &mut Ui,
+        add_header: impl FnOnce(&mut Ui) -> H,
+        add_body: impl FnOnce(&mut Ui) -> B,
+    ) -> ExpanderResponse<H, B> {
+        let ctx = ui.ctx().clone();
+        let mut open = Self::is_open(&ctx, self.id).unwrap_or(self.default_open);
+
+        // the toggle region is created first, so the widgets of the header are above it,
+        // with the height the header had in the last pass
+        let header_height_id = self.id.with("header_height");
+        let header_height = ctx.data(|data| data.get_temp::<f32>(header_height_id)).unwrap_or(0.0);
+        let top = ui.cursor().top();
+        let header_rect = Rect::from_x_y_ranges(ui.max_rect().x_range(), top..=top + header_height);
+        let header_response = ui.interact(header_rect, self.id.with("header_click"), Sense::click());
+        if header_response.clicked() {
+            open = !open;
+            Self::set_open(&ctx, self.id, open);
+        }
+
+        let header = Row::new(self.header_valign)
+            .id(self.id.with("header"))
+            .show(ui, |ui| {
+                // selectable labels would take the clicks of the toggle region
+                ui.style_mut().interaction.selectable_labels = false;
+                add_header(ui)
+            });
+        let new_header_height = header.response.rect.bottom() - top;
+        if !header.sizing_pass && new_header_height != header_height {
+            ctx.data_mut(|data| data.insert_temp(header_height_id, new_header_height));
+            ctx.request_repaint();
+        }
+
+        let duration = self.duration.unwrap_or(ui.style().animation_time);
+        let t = ctx.animate_bool_with_time(self.id, open, duration);
+        if t <= 0.0 {
+            return ExpanderResponse {
+                header: header.inner,
+                header_response,
+                body: None,
+                open,
+            };
+        }
+
+        // the body is revealed from its top, clipped to the animated height
+        let height_id = self.id.with("body_height");
+        let height = ctx.data(|data| data.get_temp::<f32>(height_id)).unwrap_or(0.0);
+        let visible_height = height * t;
+
+        let body_rect = ui.available_rect_before_wrap();
+        let mut body_ui = ui.new_child(UiBuilder::new().max_rect(body_rect));
+        let clip_rect = Rect::from_min_size(body_rect.min, vec2(body_rect.width(), visible_height));
+        body_ui.set_clip_rect(clip_rect.intersect(ui.clip_rect()));
+        let body = Column::new(self.body_halign)
+            .id(self.id.with("body"))
+            .show(&mut body_ui, add_body);
+
+        let new_height = body.response.rect.bottom() - body_rect.top();
+        if !body.sizing_pass && new_height != height {
+            ctx.data_mut(|data| data.insert_temp(height_id, new_height));
+            ctx.request_repaint();
+        }
+
+        ui.allocate_rect(clip_rect, Sense::hover());
+
+        ExpanderResponse {
+            header: header.inner,
+            header_response,
+            body: Some(body.inner),
+            open,
+        }
+    }
+}
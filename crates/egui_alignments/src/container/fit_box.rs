@@ -0,0 +1,226 @@
+use egui::{Align2, Id, Image, InnerResponse, Layout, Rect, Response, Sense, Ui, UiBuilder, Vec2};
+
+use crate::Aligner;
+
+/// Determines how the content of a [`FitBox`] is sized to its bounds,
+/// like CSS `object-fit`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FitMode {
+    /// Scale the content to fit within the bounds, keeping its aspect ratio.
+    Contain,
+
+    /// Scale the content to cover the bounds, keeping its aspect ratio.
+    /// The overflowing part is clipped, the visible part is picked by the aligner.
+    Cover,
+
+    /// Stretch the content to the bounds, ignoring its aspect ratio.
+    Fill,
+
+    /// Keep the size of the content, clipping it if it is larger than the bounds.
+    None,
+}
+
+impl FitMode {
+    /// Get the size of the content fitted to the bounds.
+    pub fn fit(self, content_size: Vec2, bounds_size: Vec2) -> Vec2 {
+        if content_size.x <= 0.0 || content_size.y <= 0.0 {
+            return content_size.max(Vec2::ZERO);
+        }
+
+        let scale = bounds_size / content_size;
+        match self {
+            FitMode::Contain => content_size * scale.min_elem(),
+            FitMode::Cover => content_size * scale.max_elem(),
+            FitMode::Fill => bounds_size,
+            FitMode::None => content_size,
+        }
+    }
+}
+
+/// A container which sizes and clips its content to its bounds according to a [`FitMode`].
+/// The fitted content is placed by the aligner,
+/// which also picks the visible part in [`FitMode::Cover`] and [`FitMode::None`] modes.
+///
+/// The content is expected to fill the space it is given, like an [`Image`] does.
+/// For images, prefer [`FitBox::show_image`] which reads the size of the texture.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{vec2, Align2};
+/// use egui_alignments::{FitBox, FitMode};
+///
+/// # egui::__run_test_ui(|ui| {
+/// FitBox::from_align(Align2::CENTER_TOP)
+///     .mode(FitMode::Cover)
+///     .size(vec2(160.0, 90.0))
+///     .show_image(ui, egui::Image::new("https://picsum.photos/480/640"));
+/// # });
+/// ```
+pub struct FitBox<T: Aligner> {
+    /// Used to memorize the natural size of the content.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// See [`FitMode`].
+    pub mode: FitMode,
+
+    /// The aligner used to place the fitted content within the bounds.
+    pub align: T,
+
+    /// The size of the bounds.
+    /// If `None`, use all the available space.
+    pub size: Option<Vec2>,
+
+    /// The natural size of the content.
+    /// If `None`, it is measured in a sizing pass, again when the bounds or the shown size of the content change,
+    /// or read from the texture in [`FitBox::show_image`].
+    pub content_size: Option<Vec2>,
+}
+
+pub type Align2FitBox = FitBox<Align2>;
+
+impl Align2FitBox {
+    #[inline]
+    /// Create a new fit box which centers its content.
+    pub fn new(mode: FitMode) -> Self {
+        Self::from_align(Align2::CENTER_CENTER).mode(mode)
+    }
+}
+
+impl<T: Aligner> FitBox<T> {
+    #[inline]
+    /// Create a new fit box in [`FitMode::Contain`] mode with the given aligner.
+    pub fn from_align(align: T) -> Self {
+        Self {
+            id: None,
+            mode: FitMode::Contain,
+            align,
+            size: None,
+            content_size: None,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the fit box.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// See [`FitMode`].
+    pub fn mode(mut self, mode: FitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[inline]
+    /// Set the size of the bounds.
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    #[inline]
+    /// Set the natural size of the content.
+    pub fn content_size(mut self, content_size: impl Into<Vec2>) -> Self {
+        self.content_size = Some(content_size.into());
+        self
+    }
+}
+
+impl<T: Aligner> FitBox<T> {
+    fn allocate_bounds(&self, ui: &mut Ui) -> Response {
+        let size = self.size
+            .unwrap_or(Vec2::INFINITY)
+            .min(ui.available_size());
+        ui.allocate_exact_size(size, Sense::hover()).1
+    }
+
+    fn child_ui(ui: &mut Ui, rect: Rect, bounds: Rect) -> Ui {
+        let mut child_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(rect)
+                .layout(Layout::centered_and_justified(egui::Direction::TopDown))
+        );
+        child_ui.set_clip_rect(bounds.intersect(ui.clip_rect()));
+        child_ui
+    }
+
+    /// Show the content fitted to the bounds.
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+
+        let response = self.allocate_bounds(ui);
+        let bounds = response.rect;
+
+        let content_size = self.content_size
+            .or_else(|| crate::size_cache::get_within(ui.ctx(), id, bounds.size()));
+
+        // the size the content took when last shown, to notice when it changes
+        let shown_id = id.with("shown_size");
+        let inner = match content_size {
+            Some(content_size) => {
+                let rect = self.align.align(self.mode.fit(content_size, bounds.size()), bounds);
+                let mut child_ui = Self::child_ui(ui, rect, bounds);
+                let inner = add_contents(&mut child_ui);
+
+                if self.content_size.is_none() && !ui.is_sizing_pass() {
+                    let shown_size = child_ui.min_size();
+                    let last_shown_size = ui.ctx().data(|data| data.get_temp::<Vec2>(shown_id));
+                    if last_shown_size.is_some_and(|last| last != shown_size) {
+                        // the content changed, measure its natural size again
+                        crate::size_cache::remove(ui.ctx(), id);
+                        crate::request_discard(ui.ctx(), "FitBox content changed");
+                    }
+                    if last_shown_size != Some(shown_size) {
+                        ui.ctx().data_mut(|data| data.insert_temp(shown_id, shown_size));
+                    }
+                }
+                inner
+            },
+            None => {
+                // measure the natural size of the content first
+                crate::request_discard(ui.ctx(), "new FitBox");
+                let mut child_ui = ui.new_child(
+                    UiBuilder::new()
+                        .max_rect(bounds)
+                        .sizing_pass()
+                        .invisible()
+                );
+                let inner = add_contents(&mut child_ui);
+                crate::size_cache::insert(ui.ctx(), id, child_ui.min_size(), bounds.size());
+                ui.ctx().data_mut(|data| data.remove::<Vec2>(shown_id));
+                inner
+            },
+        };
+
+        InnerResponse { inner, response }
+    }
+
+    /// Show an image fitted to the bounds.
+    /// The natural size of the content is the size of the texture if not set.
+    pub fn show_image(self, ui: &mut Ui, image: Image<'_>) -> Response {
+        let response = self.allocate_bounds(ui);
+        let bounds = response.rect;
+
+        let content_size = self.content_size
+            .or_else(|| {
+                image.load_for_size(ui.ctx(), bounds.size())
+                    .ok()
+                    .and_then(|poll| poll.size())
+            })
+            .unwrap_or(bounds.size());
+
+        let rect = self.align.align(self.mode.fit(content_size, bounds.size()), bounds);
+        let child_ui = Self::child_ui(ui, rect, bounds);
+        image.paint_at(&child_ui, rect);
+
+        response
+    }
+}
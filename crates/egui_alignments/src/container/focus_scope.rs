@@ -0,0 +1,233 @@
+use std::hash::Hash;
+
+use egui::{Align, Context, Event, Id, InnerResponse, Key, Rect, Response, Ui, UiBuilder, UiStackInfo, Vec2};
+
+use crate::compat;
+
+// the tag of the content uis of the focus scopes, holding the id of the scope
+const FOCUS_SCOPE_TAG: &str = "egui_alignments::focus_scope";
+
+#[derive(Clone, Copy, Debug, Default)]
+struct FocusScopeState {
+    /// The first child taking the focus with Tab, once seen.
+    first: Option<Id>,
+
+    /// The last child taking the focus with Tab, once seen.
+    last: Option<Id>,
+
+    /// The child with the focus at the end of the last pass, or a sentinel.
+    focused: Option<Id>,
+
+    /// The pass the scope was last shown in.
+    shown_pass: u64,
+
+    /// The pass the scope was opened in, after not being shown.
+    opened_pass: u64,
+
+    /// Shift+Tab moved the focus out of a child, to the previous one or the first sentinel.
+    previous_pending: bool,
+
+    /// Shift+Tab wrapped around from the first child to the last one.
+    wrapped_to_last: bool,
+
+    /// The focus moved to another child, to be scrolled into view.
+    scroll_pending: bool,
+}
+
+/// A container keeping the Tab navigation within its children, e.g. for a modal or a drawer,
+/// focusing a chosen child when it opens and scrolling the focused child into view.
+///
+/// Once the focus is within the scope, Tab from the last child moves it to the first one,
+/// and Shift+Tab from the first child moves it to the last one.
+/// egui does not tell which widgets a ui contains, so the first and the last children
+/// are learned as the focus goes through them: the very first wrap around may take an extra key press.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::FocusScope;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let mut name = String::new();
+/// FocusScope::new("rename_dialog").show(ui, |ui| {
+///     let response = ui.text_edit_singleline(&mut name);
+///     FocusScope::initial_focus(ui, &response);
+///     ui.horizontal(|ui| {
+///         let _ = ui.button("Cancel");
+///         let _ = ui.button("Rename");
+///     });
+/// });
+/// # });
+/// ```
+pub struct FocusScope {
+    /// Used to memorize the children the focus goes through.
+    pub id: Id,
+
+    /// The alignment of the focused child in the enclosing scroll areas when the focus moves.
+    /// If `None`, scroll just enough for it to be visible.
+    pub scroll_align: Option<Align>,
+}
+
+impl FocusScope {
+    #[inline]
+    /// Create a new focus scope.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            scroll_align: None,
+        }
+    }
+
+    #[inline]
+    /// Set the alignment of the focused child in the enclosing scroll areas when the focus moves.
+    pub fn scroll_align(mut self, align: Align) -> Self {
+        self.scroll_align = Some(align);
+        self
+    }
+
+    /// Focus the widget of the response if the focus scope the ui is in has just opened.
+    pub fn initial_focus(ui: &Ui, response: &Response) {
+        let Some(id) = ui.stack()
+            .iter()
+            .find_map(|frame| frame.info.tags.get_downcast::<Id>(FOCUS_SCOPE_TAG).copied()) else {
+            return;
+        };
+        let state = ui.ctx().data(|data| data.get_temp::<FocusScopeState>(id)).unwrap_or_default();
+        if state.opened_pass == ui.ctx().cumulative_pass_nr() {
+            response.request_focus();
+        }
+    }
+
+    /// If the focus is on a child of the focus scope with the given id.
+    pub fn has_focus(ctx: &Context, id: Id) -> bool {
+        let focused = ctx.data(|data| data.get_temp::<FocusScopeState>(id)).and_then(|state| state.focused);
+        focused.is_some() && focused == ctx.memory(|memory| memory.focused())
+    }
+
+    /// Show the children in the given ui.
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let ctx = ui.ctx().clone();
+        let pass = ctx.cumulative_pass_nr();
+        let first_sentinel = self.id.with("first_sentinel");
+        let last_sentinel = self.id.with("last_sentinel");
+        let layer_id = ui.layer_id();
+        let is_sentinel = |id: Option<Id>| id == Some(first_sentinel) || id == Some(last_sentinel);
+
+        let state = ctx.data(|data| data.get_temp::<FocusScopeState>(self.id));
+        // `Option::is_none_or` needs Rust 1.82
+        #[allow(clippy::unnecessary_map_or)]
+        let opened = state.map_or(true, |state| state.shown_pass + 1 < pass);
+        let mut state = state.unwrap_or_default();
+        if opened {
+            state.focused = None;
+            state.opened_pass = pass;
+        }
+        state.shown_pass = pass;
+
+        // like egui, use the modifiers of the key events
+        let (tab, shift_tab) = ctx.input(|input| {
+            input.events.iter().fold((false, false), |(tab, shift_tab), event| match event {
+                Event::Key { key: Key::Tab, pressed: true, modifiers, .. } => (tab || !modifiers.shift, shift_tab || modifiers.shift),
+                _ => (tab, shift_tab),
+            })
+        });
+        let focused = ctx.memory(|memory| memory.focused());
+
+        // where the focus moved since the last pass
+        let mut inside = state.focused.filter(|id| focused == Some(*id));
+        if std::mem::take(&mut state.previous_pending) && focused.is_some() {
+            inside = focused;
+            state.scroll_pending = true;
+            if std::mem::take(&mut state.wrapped_to_last) {
+                state.last = focused;
+            } else if focused == Some(first_sentinel) {
+                // Shift+Tab from the first child
+                state.first = state.focused;
+                if let Some(last) = state.last {
+                    ctx.memory_mut(|memory| memory.request_focus(last));
+                    inside = Some(last);
+                }
+            }
+        }
+
+        if state.scroll_pending && !is_sentinel(inside) {
+            if let Some(response) = inside.and_then(|id| ctx.read_response(id)) {
+                response.scroll_to_me(self.scroll_align);
+            }
+        }
+        state.scroll_pending = false;
+
+        // the first sentinel only takes part in the navigation when it has to
+        let mut first_pending = false;
+        let mut wrap_to_last = false;
+        if tab && focused.is_none() {
+            // the focus goes to the first child taking it
+            first_pending = true;
+        } else if tab && inside.is_some() && (is_sentinel(inside) || inside == state.last) {
+            // from the last child, give the focus to the first one
+            ctx.memory_mut(|memory| {
+                memory.request_focus(first_sentinel);
+                compat::interested_in_focus(memory, first_sentinel, layer_id);
+            });
+            first_pending = true;
+        } else if shift_tab && inside.is_some() {
+            if is_sentinel(inside) || inside == state.first {
+                // from the first child, the last sentinel gives the focus to the last one
+                ctx.memory_mut(|memory| memory.request_focus(last_sentinel));
+                wrap_to_last = true;
+            } else {
+                // the first child gives the focus to the sentinel rather than to the widget before the scope
+                ctx.memory_mut(|memory| compat::interested_in_focus(memory, first_sentinel, layer_id));
+                state.previous_pending = true;
+            }
+        }
+
+        let focused_before = ctx.memory(|memory| memory.focused());
+        let response = ui.scope_builder(
+            UiBuilder::new().ui_stack_info(UiStackInfo::default().with_tag_value(FOCUS_SCOPE_TAG, self.id)),
+            add_contents,
+        );
+        let focused_after = ctx.memory(|memory| memory.focused());
+
+        if focused_after != focused_before && focused_after.is_some() {
+            // a child took the focus
+            inside = focused_after;
+            state.scroll_pending = true;
+            if first_pending {
+                state.first = focused_after;
+            }
+        } else if tab && focused_after.is_none() && inside.is_some() && !is_sentinel(inside) {
+            // Tab from the last child, the last sentinel keeps the focus in the scope
+            state.last = inside;
+            ctx.memory_mut(|memory| compat::interested_in_focus(memory, last_sentinel, layer_id));
+            inside = Some(last_sentinel);
+            if let Some(first) = state.first {
+                ctx.memory_mut(|memory| memory.request_focus(first));
+                inside = Some(first);
+                state.scroll_pending = true;
+            }
+        }
+        if wrap_to_last {
+            // the focus goes to the last child taking it, in the next pass
+            ctx.memory_mut(|memory| compat::interested_in_focus(memory, last_sentinel, layer_id));
+            state.previous_pending = true;
+            state.wrapped_to_last = true;
+        }
+
+        // keep the focus on a sentinel alive, egui drops the focus of the widgets not shown
+        let focused = ctx.memory(|memory| memory.focused());
+        for sentinel in [first_sentinel, last_sentinel] {
+            if focused == Some(sentinel) {
+                ctx.check_for_id_clash(sentinel, Rect::from_min_size(response.response.rect.min, Vec2::ZERO), "FocusScope sentinel");
+            }
+        }
+
+        if state.scroll_pending {
+            ctx.request_repaint();
+        }
+        state.focused = inside.filter(|id| focused == Some(*id));
+        ctx.data_mut(|data| data.insert_temp(self.id, state));
+
+        response
+    }
+}
@@ -0,0 +1,124 @@
+use egui::{vec2, Align, Id, InnerResponse, Layout, ScrollArea, Ui, UiBuilder};
+
+/// A container of rows whose first column stays in place
+/// while the rest of the rows scroll horizontally together.
+///
+/// Both parts scroll vertically together, and each row is as high
+/// as the highest of the contents of its frozen and scrolling parts, memorized from the previous pass.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::FrozenColumn;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let cities = ["Paris", "Tokyo", "Lima"];
+/// FrozenColumn::new("temperatures").show(
+///     ui,
+///     cities.len(),
+///     |ui, row| {
+///         ui.strong(cities[row]);
+///     },
+///     |ui, row| {
+///         for month in 0..12 {
+///             ui.label(format!("{}°", 10 + row * 3 + month));
+///         }
+///     },
+/// );
+/// # });
+/// ```
+pub struct FrozenColumn {
+    /// Used to memorize the row heights and the scroll areas.
+    pub id: Id,
+
+    /// The vertical alignment of the contents of each row.
+    pub valign: Align,
+}
+
+impl FrozenColumn {
+    #[inline]
+    /// Create a new container with the row contents centered vertically.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            valign: Align::Center,
+        }
+    }
+
+    #[inline]
+    /// Set the vertical alignment of the contents of each row.
+    pub fn valign(mut self, valign: Align) -> Self {
+        self.valign = valign;
+        self
+    }
+}
+
+impl FrozenColumn {
+    /// Show `row_count` rows, adding the frozen cell and then the scrolling cells of each row.
+    pub fn show(
+        &self,
+        ui: &mut Ui,
+        row_count: usize,
+        mut add_frozen: impl FnMut(&mut Ui, usize),
+        mut add_scrolling: impl FnMut(&mut Ui, usize),
+    ) -> InnerResponse<()> {
+        let heights_id = self.id.with("row_heights");
+        let heights: Vec<f32> = ui.ctx().data(|data| data.get_temp(heights_id)).unwrap_or_default();
+        let layout = Layout::left_to_right(self.valign);
+
+        // show a row at its memorized height, returning the height of its contents.
+        // the frames of the widgets of a centered row fill its height, so the contents are measured
+        // in an invisible top-aligned ui, for the row to shrink when they do
+        let add_row = |ui: &mut Ui, row: usize, add_contents: &mut dyn FnMut(&mut Ui, usize)| {
+            let mut sizing_ui = ui.new_child(
+                UiBuilder::new()
+                    .id_salt(("frozen_row_measure", row))
+                    .max_rect(ui.available_rect_before_wrap())
+                    .layout(Layout::left_to_right(Align::Min))
+                    .sizing_pass()
+                    .invisible()
+            );
+            add_contents(&mut sizing_ui, row);
+            let natural_height = sizing_ui.min_rect().height();
+
+            let height = heights.get(row).copied().unwrap_or(natural_height);
+            ui.allocate_ui_with_layout(vec2(ui.available_width(), height), layout, |ui| {
+                ui.set_min_height(height);
+                add_contents(ui, row);
+            });
+            natural_height
+        };
+
+        let mut new_heights = vec![0.0_f32; row_count];
+        let response = ScrollArea::vertical()
+            .id_salt(self.id.with("vertical"))
+            .show(ui, |ui| {
+                ui.horizontal_top(|ui| {
+                    ui.vertical(|ui| {
+                        for (row, height) in new_heights.iter_mut().enumerate() {
+                            *height = height.max(add_row(ui, row, &mut add_frozen));
+                        }
+                    });
+
+                    ScrollArea::horizontal()
+                        .id_salt(self.id.with("horizontal"))
+                        .show(ui, |ui| {
+                            ui.vertical(|ui| {
+                                for (row, height) in new_heights.iter_mut().enumerate() {
+                                    *height = height.max(add_row(ui, row, &mut add_scrolling));
+                                }
+                            });
+                        });
+                })
+                .response
+            })
+            .inner;
+
+        if new_heights != heights {
+            ui.ctx().data_mut(|data| data.insert_temp(heights_id, new_heights));
+            crate::request_discard(ui.ctx(), "FrozenColumn row heights");
+        }
+
+        InnerResponse { inner: (), response }
+    }
+}
@@ -0,0 +1,579 @@
+use egui::{Id, Layout, Margin, Pos2, Rect, Sense, Ui, UiBuilder, UiStackInfo, Vec2};
+
+use crate::{
+    hide_sizing_pass, layout_widget_rect, measure::MEASURE_ID_SALT, next_widget_rect, resize_layout_rect,
+    wrapped_lines_rect, AlignedResponse, AllocateType, LayoutError, ResizeCallback, Skeleton,
+};
+
+/// A container laying out its contents with any [`Layout`],
+/// which memorizes the size of its contents and runs a sizing pass when it is unknown.
+/// [`crate::Row`] and [`crate::Column`] are built on it.
+///
+/// Use it to build custom containers without handling the sizing pass yourself.
+/// No sizing pass is needed if the size is fixed by equal minimum and maximum sizes,
+/// or if it fills the bounds with [`AllocateType::Bounds`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, Layout};
+/// use egui_alignments::GenericContainer;
+///
+/// # egui::__run_test_ui(|ui| {
+/// // a right-aligned wrapping flow
+/// GenericContainer::new(Layout::right_to_left(Align::Center).with_main_wrap(true))
+///     .padding(4.0)
+///     .max_size((200.0, f32::INFINITY))
+///     .show(ui, |ui| {
+///         for i in 0..10 {
+///             ui.label(format!("Item {}", i));
+///         }
+///     });
+/// # });
+/// ```
+///
+/// A container of fixed size is shown from the first frame:
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, Layout};
+/// use egui_alignments::GenericContainer;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = GenericContainer::new(Layout::top_down(Align::Center))
+///     .min_size((120.0, 40.0))
+///     .max_size((120.0, 40.0))
+///     .show(ui, |ui| {
+///         ui.label("Badge");
+///     });
+/// assert!(!response.sizing_pass);
+/// # });
+/// ```
+pub struct GenericContainer {
+    /// Used to memorize the size of the contents.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The layout of the contents.
+    pub layout: Layout,
+
+    /// The padding around the contents.
+    pub padding: Margin,
+
+    /// The maximum size of the container, including the padding.
+    pub max_size: Vec2,
+
+    /// The minimum size of the container, including the padding.
+    pub min_size: Vec2,
+
+    /// The size of the contents, if known up front.
+    /// Used instead of a sizing pass when no size is memorized yet.
+    pub size_hint: Option<Vec2>,
+
+    /// See [`AllocateType`].
+    /// The bounds are the available rect of the ui.
+    pub allocate_type: AllocateType,
+
+    /// Called when the memorized size of the contents changes, with the old and the new size.
+    pub on_resize: Option<ResizeCallback>,
+
+    /// If set, painted in place of the contents during the sizing pass.
+    pub skeleton: Option<Skeleton>,
+
+    /// If set, the contents fade in over this duration, in seconds,
+    /// once their layout is stable after they are first shown.
+    pub fade_in: Option<f32>,
+
+    /// If the contents are skipped by [`GenericContainer::show_culled`]
+    /// when the container is outside the clip rect and its size is known.
+    /// Disable it if the contents have side effects which must run every frame.
+    pub cull: bool,
+}
+
+impl GenericContainer {
+    #[inline]
+    /// Create a new container with the given layout.
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            id: None,
+            layout,
+            padding: Margin::ZERO,
+            max_size: Vec2::INFINITY,
+            min_size: Vec2::ZERO,
+            size_hint: None,
+            allocate_type: AllocateType::Content,
+            on_resize: None,
+            skeleton: None,
+            fade_in: None,
+            cull: true,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the container.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the layout of the contents.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    #[inline]
+    /// Set the padding around the contents.
+    pub fn padding(mut self, padding: impl Into<Margin>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    #[inline]
+    /// Set the maximum size of the container.
+    pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
+        self.max_size = max_size.into();
+        self
+    }
+
+    #[inline]
+    /// Set the minimum size of the container.
+    pub fn min_size(mut self, min_size: impl Into<Vec2>) -> Self {
+        self.min_size = min_size.into();
+        self
+    }
+
+    #[inline]
+    /// Set the size of the contents, if known up front, to skip the sizing pass.
+    pub fn size_hint(mut self, size: impl Into<Vec2>) -> Self {
+        self.size_hint = Some(size.into());
+        self
+    }
+
+    #[inline]
+    /// See [`AllocateType`]
+    pub fn allocate_type(mut self, allocate_type: AllocateType) -> Self {
+        self.allocate_type = allocate_type;
+        self
+    }
+
+    #[inline]
+    /// Set a callback invoked with the old and the new size of the contents
+    /// when their memorized size changes.
+    pub fn on_resize(mut self, on_resize: impl Fn(Vec2, Vec2) + 'static) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    #[inline]
+    /// Set a placeholder painted in place of the contents during the sizing pass.
+    /// The sizing pass is then presented rather than discarded, with its contents hidden and disabled.
+    pub fn skeleton(mut self, skeleton: Skeleton) -> Self {
+        self.skeleton = Some(skeleton);
+        self
+    }
+
+    #[inline]
+    /// Fade the contents in over the given duration, in seconds,
+    /// once their layout is stable after they are first shown, e.g. `0.1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui::{vec2, Align, Color32, Layout, RawInput, Shape};
+    /// use egui_alignments::GenericContainer;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut output = None;
+    /// for frame in 0..10 {
+    ///     let input = RawInput { time: Some(frame as f64 * 0.05), ..Default::default() };
+    ///     output = Some(ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             GenericContainer::new(Layout::top_down(Align::Center))
+    ///                 .min_size(vec2(120.0, 40.0))
+    ///                 .max_size(vec2(120.0, 40.0))
+    ///                 .fade_in(0.1)
+    ///                 .show(ui, |ui| {
+    ///                     let rect = ui.max_rect();
+    ///                     ui.painter().rect_filled(rect, 0.0, Color32::RED);
+    ///                 });
+    ///         });
+    ///     }));
+    /// }
+    /// // the contents are fully visible once faded in
+    /// assert!(output.unwrap().shapes.iter().any(|clipped| {
+    ///     matches!(&clipped.shape, Shape::Rect(rect) if rect.fill == Color32::RED)
+    /// }));
+    /// ```
+    pub fn fade_in(mut self, duration: f32) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+
+    #[inline]
+    /// Set whether the contents are skipped by [`GenericContainer::show_culled`]
+    /// when the container is outside the clip rect.
+    pub fn cull(mut self, cull: bool) -> Self {
+        self.cull = cull;
+        self
+    }
+}
+
+impl GenericContainer {
+    // the size of the container if it does not depend on the contents,
+    // in which case the contents need no sizing pass
+    fn fixed_size(&self, available_rect: Rect) -> Option<Vec2> {
+        if self.allocate_type == AllocateType::Bounds {
+            // the container fills the bounds whatever the contents are
+            Some(available_rect.size().max(self.min_size).min(self.max_size))
+        } else if self.min_size == self.max_size && self.max_size.is_finite() {
+            Some(self.max_size)
+        } else {
+            None
+        }
+    }
+
+    /// Show the container in the given ui.
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<R> {
+        self.show_with_resize(ui, self.on_resize.as_ref(), add_contents)
+    }
+
+    /// Show the container in the given ui,
+    /// or return an error without showing it if it cannot be laid out,
+    /// e.g. if the minimum size is larger than the maximum size.
+    pub fn try_show<R>(
+        &self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Result<AlignedResponse<R>, LayoutError> {
+        self.check(ui)?;
+        Ok(self.show(ui, add_contents))
+    }
+
+    // check the configuration of the container in the given ui
+    pub(crate) fn check(&self, ui: &Ui) -> Result<(), LayoutError> {
+        if self.min_size.x > self.max_size.x || self.min_size.y > self.max_size.y {
+            return Err(LayoutError::UnsatisfiableSize {
+                min: self.min_size,
+                max: self.max_size,
+            });
+        }
+        LayoutError::check_margin(self.padding, self.max_size)?;
+        LayoutError::check_bounds(self.bounds(ui.available_rect_before_wrap()))
+    }
+
+    // show the container, reporting the changes of the memorized size to the given callback,
+    // for the containers built on this one
+    pub(crate) fn show_with_resize<R>(
+        &self,
+        ui: &mut Ui,
+        on_resize: Option<&ResizeCallback>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> AlignedResponse<R> {
+        let id = self.container_id(ui);
+
+        // try to get content size from cache
+        // if not cached, start a sizing pass
+        let available_rect = ui.available_rect_before_wrap();
+        let desired_size = self.known_size(ui, id, available_rect)
+            .or(self.size_hint.map(|size| size + self.padding.sum()));
+
+        self.show_sized(ui, id, available_rect, desired_size, true, on_resize, add_contents)
+    }
+
+    /// Show the container in the given ui, skipping the contents
+    /// if the container is entirely outside the clip rect, e.g. scrolled away,
+    /// and its size is already known.
+    ///
+    /// The cached rect is still allocated, so the layout around the container does not change.
+    /// The inner value is `None` if the contents were skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui::{Align, Layout, ScrollArea};
+    /// use egui_alignments::GenericContainer;
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// ScrollArea::vertical().show(ui, |ui| {
+    ///     for i in 0..1000 {
+    ///         GenericContainer::new(Layout::left_to_right(Align::Center))
+    ///             .show_culled(ui, |ui| {
+    ///                 ui.label(format!("Item {}", i));
+    ///             });
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn show_culled<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<Option<R>> {
+        self.show_culled_with_resize(ui, self.on_resize.as_ref(), add_contents)
+    }
+
+    // show the container culled when outside the clip rect,
+    // reporting the changes of the memorized size to the given callback
+    pub(crate) fn show_culled_with_resize<R>(
+        &self,
+        ui: &mut Ui,
+        on_resize: Option<&ResizeCallback>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> AlignedResponse<Option<R>> {
+        let id = self.container_id(ui);
+        let available_rect = ui.available_rect_before_wrap();
+        let desired_size = self.known_size(ui, id, available_rect);
+
+        if let Some(desired_size) = desired_size.filter(|_| self.cull && !ui.is_sizing_pass()) {
+            let content_rect = self.next_rect(ui, available_rect, desired_size);
+            if !ui.is_rect_visible(content_rect) {
+                let response = ui.allocate_rect(
+                    self.allocated_rect(ui, content_rect, available_rect),
+                    Sense::hover(),
+                );
+                crate::scroll_anchor::report(ui.ctx(), id, content_rect);
+                return AlignedResponse {
+                    inner: None,
+                    response,
+                    sizing_pass: false,
+                    content_rect,
+                    bounds: self.bounds(available_rect),
+                };
+            }
+        }
+
+        let desired_size = desired_size.or(self.size_hint.map(|size| size + self.padding.sum()));
+        self.show_sized(ui, id, available_rect, desired_size, true, on_resize, |ui| Some(add_contents(ui)))
+    }
+
+    // the id used to memorize the content size
+    fn container_id(&self, ui: &mut Ui) -> Id {
+        self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        })
+    }
+
+    // the size of the container if fixed or memorized
+    fn known_size(&self, ui: &Ui, id: Id, available_rect: Rect) -> Option<Vec2> {
+        self.fixed_size(available_rect)
+            .or_else(|| crate::size_cache::get_within(ui.ctx(), id, self.bounds(available_rect).size()))
+    }
+
+    /// Show the container in the given ui,
+    /// measuring the contents in an invisible sizing pass first, in the same frame.
+    ///
+    /// No size is memorized and no pass is discarded, so the layout of a frame
+    /// depends on that frame only, at the cost of running the contents twice.
+    /// This is useful for deterministic layouts, e.g. in screenshot tests.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui::{Align, Layout};
+    /// use egui_alignments::GenericContainer;
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = GenericContainer::new(Layout::top_down(Align::Center))
+    ///     .show_two_pass(ui, |ui| {
+    ///         ui.label("Laid out in the first frame");
+    ///     });
+    /// assert!(!response.sizing_pass);
+    /// # });
+    /// ```
+    pub fn show_two_pass<R>(&self, ui: &mut Ui, mut add_contents: impl FnMut(&mut Ui) -> R) -> AlignedResponse<R> {
+        let id = self.container_id(ui);
+
+        let available_rect = ui.available_rect_before_wrap();
+        let desired_size = self.fixed_size(available_rect).unwrap_or_else(|| {
+            let mut sizing_ui = ui.new_child(
+                UiBuilder::new()
+                    .id_salt(MEASURE_ID_SALT)
+                    .max_rect(Rect::from_min_size(available_rect.min, available_rect.size().min(self.max_size)) - self.padding)
+                    .layout(self.sizing_layout())
+                    .sizing_pass()
+                    .invisible()
+            );
+            add_contents(&mut sizing_ui);
+            sizing_ui.min_size() + self.padding.sum()
+        });
+
+        self.show_sized(ui, id, available_rect, Some(desired_size), false, None, add_contents)
+    }
+
+    // in sizing pass, keep the layout size minimum
+    fn sizing_layout(&self) -> Layout {
+        self.layout
+            .with_cross_align(egui::Align::Min)
+            .with_cross_justify(false)
+    }
+
+    // show the contents given the size of the container if known,
+    // or in a sizing pass otherwise
+    #[allow(clippy::too_many_arguments)]
+    fn show_sized<R>(
+        &self,
+        ui: &mut Ui,
+        id: Id,
+        available_rect: Rect,
+        desired_size: Option<Vec2>,
+        memorize: bool,
+        on_resize: Option<&ResizeCallback>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> AlignedResponse<R> {
+        #[cfg(feature = "profiling")]
+        profiling::scope!("GenericContainer", &id.short_debug_format());
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("GenericContainer", ?id).entered();
+
+        let sizing_pass = desired_size.is_none();
+        let skeleton = self.skeleton.filter(|_| sizing_pass && !ui.is_sizing_pass());
+        let desired_size = desired_size.unwrap_or_else(|| {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(available = ?available_rect.size(), "starting a sizing pass");
+            if skeleton.is_some() {
+                // the sizing pass is presented with the skeleton, lay out the contents in the next frame
+                ui.ctx().request_repaint();
+            } else {
+                // the current pass is a sizing pass, request a rendering pass
+                crate::request_discard(ui.ctx(), "new Container");
+            }
+            available_rect.size()
+        });
+
+        // get the supposed content rect
+        let (content_rect, line_rect) = {
+            let next_rect = self.next_rect(ui, available_rect, desired_size);
+            let content_rect = if self.layout.main_wrap() && self.layout.is_horizontal() {
+                // the lines wrap within the available width
+                let max_width = available_rect.width() - self.padding.sum().x;
+                wrapped_lines_rect(ui, &self.layout, next_rect - self.padding, max_width)
+            } else {
+                resize_layout_rect(next_rect, available_rect.size(), &self.layout) - self.padding
+            };
+            (content_rect, next_rect - self.padding)
+        };
+
+        // create child ui
+        let mut content_ui = ui.new_child({
+            let builder = UiBuilder::new()
+                .max_rect(content_rect);
+            
+            if sizing_pass {
+                builder.layout(self.sizing_layout())
+                    .sizing_pass()
+            } else {
+                // the rect of the memorized contents, to align the children on their own
+                builder.layout(self.layout)
+                    .ui_stack_info(
+                        UiStackInfo::default()
+                            .with_tag_value(crate::LINE_RECT_TAG, line_rect)
+                            .with_tag_value(crate::stretch::CONTAINER_ID_TAG, id)
+                    )
+            }
+        });
+        if sizing_pass {
+            hide_sizing_pass(&mut content_ui);
+
+            if let Some(skeleton) = skeleton {
+                let size = skeleton.size(ui, available_rect.size(), self.min_size, self.max_size);
+                skeleton.paint(ui, layout_widget_rect(ui.layout(), available_rect, size));
+            }
+        }
+
+        // hide the contents until a pass lays them out at their memorized size,
+        // then fade them in, so the reposition of a cold memory is not seen
+        let fade_id = id.with("fade_in");
+        let stable = self.fade_in.map(|duration| {
+            let stable = ui.ctx().data(|data| data.get_temp::<bool>(fade_id)).unwrap_or(false);
+            content_ui.multiply_opacity(crate::fade_in_from_hidden(ui.ctx(), fade_id, stable, duration));
+            stable
+        });
+
+        // add contents and calculate space to be allocated
+        let debug_node = crate::debug::begin_node(ui.ctx(), "GenericContainer", id);
+        let ime = crate::ime::begin(ui.ctx());
+        let measuring = sizing_pass || ui.is_sizing_pass();
+        if !measuring {
+            crate::stretch::begin(ui.ctx(), id);
+        }
+        let inner = add_contents(&mut content_ui);
+        crate::ime::end(ui.ctx(), ime, id, measuring);
+        if !measuring {
+            // the stretches share the space left in the bounds
+            let max_size = self.bounds(available_rect).size() - self.padding.sum();
+            crate::stretch::end(ui.ctx(), id, self.layout.is_horizontal(), content_ui.min_size(), max_size);
+        }
+        let new_rect = content_ui.min_rect() + self.padding;
+        // the size of a fixed container is not the size of its contents, which need no sizing pass
+        let laid_out = self.fixed_size(available_rect).is_some() || new_rect.size() == desired_size;
+        if stable == Some(false) && !measuring && laid_out {
+            ui.ctx().data_mut(|data| data.insert_temp(fade_id, true));
+            ui.ctx().request_repaint();
+        }
+        // allocate space and get response
+        let response = ui.allocate_rect(self.allocated_rect(ui, new_rect, available_rect), Sense::hover());
+
+        if !sizing_pass && !ui.is_sizing_pass() {
+            crate::scroll_anchor::report(ui.ctx(), id, new_rect);
+        }
+
+        // cache content size
+        if memorize && (sizing_pass || new_rect.size() != desired_size) {
+            let old_size = crate::size_cache::get(ui.ctx(), id);
+            crate::size_cache::insert(ui.ctx(), id, new_rect.size(), self.bounds(available_rect).size());
+            if let (Some(on_resize), Some(old_size)) = (on_resize, old_size) {
+                if old_size != new_rect.size() {
+                    on_resize(old_size, new_rect.size());
+                }
+            }
+        }
+
+        let response = AlignedResponse {
+            inner,
+            response,
+            sizing_pass: sizing_pass || ui.is_sizing_pass(),
+            content_rect: new_rect,
+            bounds: self.bounds(available_rect),
+        };
+        crate::debug::end_node(
+            ui.ctx(),
+            debug_node,
+            response.bounds,
+            response.content_rect,
+            (!sizing_pass).then_some(desired_size),
+            response.sizing_pass,
+        );
+        response
+    }
+
+    // the rect of the container of the given size, at the next widget position
+    fn next_rect(&self, ui: &mut Ui, available_rect: Rect, size: Vec2) -> Rect {
+        let size = size.max(self.min_size).min(self.max_size);
+        if ui.layout().main_wrap() {
+            next_widget_rect(ui, size)
+        } else {
+            layout_widget_rect(ui.layout(), available_rect, size)
+        }
+    }
+
+    // the rect to allocate for the contents laid out in the given rect
+    fn allocated_rect(&self, ui: &Ui, new_rect: Rect, available_rect: Rect) -> Rect {
+        match self.allocate_type {
+            AllocateType::None => Rect::from_min_size(ui.next_widget_position(), Vec2::ZERO),
+            AllocateType::Content => new_rect,
+            AllocateType::ContentRow => Rect::from_min_max(
+                Pos2::new(available_rect.left(), new_rect.top()),
+                Pos2::new(available_rect.right(), new_rect.bottom()),
+            ),
+            AllocateType::ContentColumn => Rect::from_min_max(
+                Pos2::new(new_rect.left(), available_rect.top()),
+                Pos2::new(new_rect.right(), available_rect.bottom()),
+            ),
+            AllocateType::Bounds => available_rect,
+            AllocateType::Custom(allocate) => allocate(new_rect, available_rect),
+        }
+    }
+
+    fn bounds(&self, available_rect: Rect) -> Rect {
+        Rect::from_min_size(available_rect.min, available_rect.size().min(self.max_size))
+    }
+}
@@ -0,0 +1,194 @@
+use egui::{
+    pos2, vec2, Align, Id, Layout, Margin, Rect, Stroke, TextStyle, TextWrapMode, Ui, WidgetText,
+};
+
+use crate::{compat, AlignedResponse, GenericContainer};
+
+/// A framed container whose label sits on the top border of the frame, like a classic group box.
+///
+/// The contents are laid out like a [`crate::Column`] by default, or like a [`crate::Row`]
+/// with [`LabeledGroup::horizontal`], and the frame is drawn around the space they took.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::LabeledGroup;
+///
+/// # egui::__run_test_ui(|ui| {
+/// LabeledGroup::new("Display")
+///     .label_align(Align::Center)
+///     .show(ui, |ui| {
+///         ui.checkbox(&mut true, "Dark mode");
+///         ui.checkbox(&mut false, "Reduce motion");
+///     });
+/// # });
+/// ```
+pub struct LabeledGroup {
+    /// Used to memorize the size of the contents.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The label shown on the top border.
+    pub label: WidgetText,
+
+    /// The horizontal alignment of the label on the top border.
+    pub label_align: Align,
+
+    /// The layout of the contents.
+    pub layout: Layout,
+
+    /// The margin between the frame and the contents.
+    pub inner_margin: Margin,
+
+    /// The corner radius of the frame.
+    pub corner_radius: f32,
+
+    /// The stroke of the frame.
+    /// If `None`, use the stroke of the non-interactive widgets.
+    pub stroke: Option<Stroke>,
+}
+
+impl LabeledGroup {
+    #[inline]
+    /// Create a new group with the given label on the left of the top border.
+    pub fn new(label: impl Into<WidgetText>) -> Self {
+        Self {
+            id: None,
+            label: label.into(),
+            label_align: Align::Min,
+            layout: Layout::top_down(Align::Min),
+            inner_margin: Margin::from(8.0),
+            corner_radius: 4.0,
+            stroke: None,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the group.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the horizontal alignment of the label on the top border.
+    pub fn label_align(mut self, align: Align) -> Self {
+        self.label_align = align;
+        self
+    }
+
+    #[inline]
+    /// Lay the contents out vertically, with the given horizontal alignment.
+    pub fn vertical(mut self, halign: Align) -> Self {
+        self.layout = Layout::top_down(halign);
+        self
+    }
+
+    #[inline]
+    /// Lay the contents out horizontally, with the given vertical alignment.
+    pub fn horizontal(mut self, valign: Align) -> Self {
+        self.layout = Layout::left_to_right(valign);
+        self
+    }
+
+    #[inline]
+    /// Set the layout of the contents.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    #[inline]
+    /// Set the margin between the frame and the contents.
+    pub fn inner_margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.inner_margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the corner radius of the frame.
+    pub fn corner_radius(mut self, corner_radius: f32) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    #[inline]
+    /// Set the stroke of the frame.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+}
+
+impl LabeledGroup {
+    /// Show the group in the given ui.
+    pub fn show<R>(
+        &self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> AlignedResponse<R> {
+        let galley = self.label.clone().into_galley(
+            ui,
+            Some(TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Body,
+        );
+
+        // the label is inset from the corners, with a gap in the border around it
+        let gap = ui.spacing().item_spacing.x * 0.5;
+        let indent = self.corner_radius + ui.spacing().item_spacing.x;
+        let label_height = galley.size().y;
+
+        // the top border runs through the middle of the label, above the contents
+        let padding = compat::margin_add_top(self.inner_margin, label_height);
+        let container = GenericContainer {
+            id: self.id,
+            padding,
+            min_size: vec2(galley.size().x + 2.0 * (indent + gap), 0.0),
+            ..GenericContainer::new(self.layout)
+        };
+        let response = container.show(ui, add_contents);
+        if response.sizing_pass {
+            return response;
+        }
+
+        let frame_rect = response.response.rect;
+        let frame_rect = Rect::from_min_max(
+            pos2(frame_rect.left(), frame_rect.top() + label_height * 0.5),
+            frame_rect.max,
+        );
+        let label_x = match self.label_align {
+            Align::Min => frame_rect.left() + indent + gap,
+            Align::Center => frame_rect.center().x - galley.size().x * 0.5,
+            Align::Max => frame_rect.right() - indent - gap - galley.size().x,
+        };
+        let label_rect =
+            Rect::from_min_size(pos2(label_x, response.response.rect.top()), galley.size());
+
+        // paint the frame around the gap of the label, clipping it out
+        let stroke = self
+            .stroke
+            .unwrap_or(ui.visuals().widgets.noninteractive.bg_stroke);
+        let gap_rect = label_rect.expand2(vec2(gap, 0.0));
+        let outer = frame_rect.expand(stroke.width);
+        let clip_rects = [
+            Rect::from_min_max(outer.min, pos2(gap_rect.left(), outer.bottom())),
+            Rect::from_min_max(pos2(gap_rect.right(), outer.top()), outer.max),
+            Rect::from_min_max(
+                pos2(gap_rect.left(), gap_rect.bottom()),
+                pos2(gap_rect.right(), outer.bottom()),
+            ),
+        ];
+        for clip_rect in clip_rects {
+            let painter = ui
+                .painter()
+                .with_clip_rect(clip_rect.intersect(ui.clip_rect()));
+            compat::rect_stroke(&painter, frame_rect, self.corner_radius, stroke);
+        }
+        ui.painter()
+            .galley(label_rect.min, galley, ui.visuals().text_color());
+
+        response
+    }
+}
@@ -0,0 +1,166 @@
+use egui::{vec2, Align2, Color32, Layout, Rect, Response, Sense, Ui, UiBuilder, Vec2};
+
+use crate::{Aligner, DimOverlay, FitMode, LayoutError};
+
+/// The result of showing a [`Letterbox`].
+pub struct LetterboxResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the whole bounds, including the bars.
+    pub response: Response,
+
+    /// The aspect-constrained rect the contents were shown in.
+    /// Useful to paint overlays on top of the contents.
+    pub content_rect: Rect,
+}
+
+/// A container which shows its contents in a rect of a fixed aspect ratio,
+/// aligned within the bounds, and paints bars in the leftover space.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Color32;
+/// use egui_alignments::Letterbox;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let letterbox = Letterbox::new(16.0 / 9.0)
+///     .bar_color(Color32::BLACK)
+///     .show(ui, |ui| {
+///         ui.label("Video frame");
+///     });
+/// ui.painter().debug_rect(letterbox.content_rect, Color32::RED, "content");
+/// # });
+/// ```
+pub struct Letterbox {
+    /// The aspect ratio (width / height) of the content rect.
+    pub aspect: f32,
+
+    /// The alignment of the content rect within the bounds.
+    pub align: Align2,
+
+    /// The color of the bars.
+    /// If `None`, no bars are painted.
+    pub bar_color: Option<Color32>,
+
+    /// The size of the bounds.
+    /// If `None`, use all the available space.
+    pub size: Option<Vec2>,
+
+    /// The layout of the contents.
+    /// If `None`, use the layout of the current ui.
+    pub layout: Option<Layout>,
+}
+
+impl Letterbox {
+    #[inline]
+    /// Create a new letterbox with the given aspect ratio (width / height), centered in the bounds.
+    pub fn new(aspect: f32) -> Self {
+        Self {
+            aspect,
+            align: Align2::CENTER_CENTER,
+            bar_color: None,
+            size: None,
+            layout: None,
+        }
+    }
+
+    #[inline]
+    /// Set the alignment of the content rect within the bounds.
+    pub fn align(mut self, align: Align2) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the color of the bars.
+    pub fn bar_color(mut self, color: Color32) -> Self {
+        self.bar_color = Some(color);
+        self
+    }
+
+    #[inline]
+    /// Set the size of the bounds.
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    #[inline]
+    /// Set the layout of the contents.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+}
+
+impl Letterbox {
+    /// Get the aspect-constrained content rect within the given bounds.
+    pub fn content_rect(&self, bounds: Rect) -> Rect {
+        let content_size = FitMode::Contain.fit(vec2(self.aspect.max(f32::EPSILON), 1.0), bounds.size());
+        self.align.align(content_size, bounds)
+    }
+
+    /// Show the contents in the aspect-constrained rect.
+    ///
+    /// An infinite available space, e.g. the height in a vertical scroll area, is cut at the clip rect.
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> LetterboxResponse<R> {
+        let size = self.size
+            .unwrap_or(Vec2::INFINITY)
+            .min(crate::bounded_available_rect(ui).size());
+        let (bounds, response) = ui.allocate_exact_size(size, Sense::hover());
+        let content_rect = self.content_rect(bounds);
+
+        if let Some(color) = self.bar_color {
+            // the bars are the bounds around the content rect
+            DimOverlay::new(bounds)
+                .cutout(content_rect)
+                .color(color)
+                .paint_with(ui.painter());
+        }
+
+        let mut content_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(content_rect)
+                .layout(self.layout.unwrap_or(*ui.layout()))
+        );
+        content_ui.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+        let inner = add_contents(&mut content_ui);
+
+        LetterboxResponse { inner, response, content_rect }
+    }
+
+    /// Show the contents in the aspect-constrained rect,
+    /// or return an error without showing them if the bounds are infinite, even cut at the clip rect.
+    pub fn try_show<R>(
+        &self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Result<LetterboxResponse<R>, LayoutError> {
+        let rect = crate::bounded_available_rect(ui);
+        let size = self.size.unwrap_or(Vec2::INFINITY).min(rect.size());
+        if !size.is_finite() {
+            return Err(LayoutError::InfiniteBounds(Rect::from_min_size(rect.min, size)));
+        }
+        Ok(self.show(ui, add_contents))
+    }
+}
+
+#[inline]
+/// Show the contents in a centered rect of the given aspect ratio (width / height)
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::letterbox;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let content_rect = letterbox(ui, 4.0 / 3.0, |ui| {
+///     ui.label("4:3");
+/// }).content_rect;
+/// # });
+/// ```
+pub fn letterbox<R>(ui: &mut Ui, aspect: f32, add_contents: impl FnOnce(&mut Ui) -> R) -> LetterboxResponse<R> {
+    Letterbox::new(aspect).show(ui, add_contents)
+}
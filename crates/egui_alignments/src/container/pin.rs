@@ -0,0 +1,260 @@
+use egui::{pos2, InnerResponse, Layout, Rect, Sense, Ui, UiBuilder};
+
+use crate::LayoutError;
+
+/// An edge of a rect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// The top edge.
+    Top,
+
+    /// The bottom edge.
+    Bottom,
+
+    /// The left edge.
+    Left,
+
+    /// The right edge.
+    Right,
+}
+
+impl Edge {
+    #[inline]
+    /// The opposite edge.
+    pub fn opposite(self) -> Self {
+        match self {
+            Edge::Top => Edge::Bottom,
+            Edge::Bottom => Edge::Top,
+            Edge::Left => Edge::Right,
+            Edge::Right => Edge::Left,
+        }
+    }
+
+    #[inline]
+    /// If `true`, the edge is [`Edge::Left`] or [`Edge::Right`].
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Edge::Left | Edge::Right)
+    }
+
+    /// Split `rect` into a strip of the given thickness along this edge,
+    /// and the remaining rect separated from the strip by `gap`.
+    pub fn split_rect(self, rect: Rect, thickness: f32, gap: f32) -> (Rect, Rect) {
+        let mut strip = rect;
+        let mut rest = rect;
+        match self {
+            Edge::Top => {
+                strip.max.y = rect.top() + thickness;
+                rest.min.y = strip.max.y + gap;
+            },
+            Edge::Bottom => {
+                strip.min.y = rect.bottom() - thickness;
+                rest.max.y = strip.min.y - gap;
+            },
+            Edge::Left => {
+                strip.max.x = rect.left() + thickness;
+                rest.min.x = strip.max.x + gap;
+            },
+            Edge::Right => {
+                strip.min.x = rect.right() - thickness;
+                rest.max.x = strip.min.x - gap;
+            },
+        }
+        (strip, rest)
+    }
+}
+
+/// A container which reserves a strip along an edge of the available space
+/// and gives the remaining space to the rest of the contents.
+/// Like the panels of `egui::Context` but nestable at any level.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{Edge, Pin};
+///
+/// # egui::__run_test_ui(|ui| {
+/// Pin::new(Edge::Right, 120.0).show(
+///     ui,
+///     |ui| { ui.label("Pinned to the right"); },
+///     |ui| { ui.label("The rest of the contents"); },
+/// );
+/// # });
+/// ```
+pub struct Pin {
+    /// The edge along which the strip is reserved.
+    pub edge: Edge,
+
+    /// The width (for [`Edge::Left`] and [`Edge::Right`])
+    /// or the height (for [`Edge::Top`] and [`Edge::Bottom`]) of the strip.
+    pub size: f32,
+
+    /// The gap between the strip and the rest of the contents.
+    /// If `None`, use the item spacing of the ui.
+    pub gap: Option<f32>,
+
+    /// The layout of the strip.
+    /// If `None`, use the layout of the current ui.
+    pub layout: Option<Layout>,
+
+    /// If `true`, draw a separator line between the strip and the rest of the contents.
+    pub separator: bool,
+}
+
+impl Pin {
+    #[inline]
+    /// Create a new pinned strip along the given edge.
+    pub fn new(edge: Edge, size: f32) -> Self {
+        Self {
+            edge,
+            size,
+            gap: None,
+            layout: None,
+            separator: false,
+        }
+    }
+
+    #[inline]
+    /// Set the gap between the strip and the rest of the contents.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    #[inline]
+    /// Set the layout of the strip.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    #[inline]
+    /// Set whether to draw a separator line between the strip and the rest of the contents.
+    pub fn separator(mut self, separator: bool) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+impl Pin {
+    /// Show the pinned strip and the rest of the contents in the given ui.
+    ///
+    /// An available space infinite towards the edge, e.g. the height in a vertical scroll area
+    /// with [`Edge::Bottom`], is cut at the clip rect.
+    pub fn show<P, R>(
+        &self,
+        ui: &mut Ui,
+        add_pinned: impl FnOnce(&mut Ui) -> P,
+        add_rest: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<(P, R)> {
+        let rect = crate::bounded_available_rect(ui);
+        let gap = self.gap.unwrap_or(if self.edge.is_vertical() {
+            ui.spacing().item_spacing.x
+        } else {
+            ui.spacing().item_spacing.y
+        });
+        let (strip_rect, rest_rect) = self.edge.split_rect(rect, self.size, gap);
+
+        let mut pinned_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(strip_rect)
+                .layout(self.layout.unwrap_or(*ui.layout()))
+        );
+        pinned_ui.set_clip_rect(strip_rect.intersect(ui.clip_rect()));
+        let pinned = add_pinned(&mut pinned_ui);
+
+        let mut rest_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(rest_rect)
+                .layout(*ui.layout())
+        );
+        let rest = add_rest(&mut rest_ui);
+
+        if self.separator {
+            let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+            let [a, b] = match self.edge {
+                Edge::Top => {
+                    let y = strip_rect.bottom() + gap / 2.0;
+                    [pos2(rect.left(), y), pos2(rect.right(), y)]
+                },
+                Edge::Bottom => {
+                    let y = strip_rect.top() - gap / 2.0;
+                    [pos2(rect.left(), y), pos2(rect.right(), y)]
+                },
+                Edge::Left => {
+                    let x = strip_rect.right() + gap / 2.0;
+                    [pos2(x, rect.top()), pos2(x, rect.bottom())]
+                },
+                Edge::Right => {
+                    let x = strip_rect.left() - gap / 2.0;
+                    [pos2(x, rect.top()), pos2(x, rect.bottom())]
+                },
+            };
+            ui.painter().line_segment([a, b], stroke);
+        }
+
+        // the whole space is occupied if it is bounded,
+        // otherwise only the space used by the contents
+        let used_rect = pinned_ui.min_rect().union(rest_ui.min_rect());
+        let allocated_rect = if rect.is_finite() {
+            rect
+        } else {
+            Rect::from_min_max(
+                rect.min,
+                pos2(
+                    if rect.max.x.is_finite() { rect.max.x } else { used_rect.max.x },
+                    if rect.max.y.is_finite() { rect.max.y } else { used_rect.max.y },
+                ),
+            )
+        };
+        let response = ui.allocate_rect(allocated_rect, Sense::hover());
+
+        InnerResponse { inner: (pinned, rest), response }
+    }
+
+    /// Show the pinned strip and the rest of the contents,
+    /// or return an error without showing them if the available space is infinite towards the edge,
+    /// even cut at the clip rect.
+    pub fn try_show<P, R>(
+        &self,
+        ui: &mut Ui,
+        add_pinned: impl FnOnce(&mut Ui) -> P,
+        add_rest: impl FnOnce(&mut Ui) -> R,
+    ) -> Result<InnerResponse<(P, R)>, LayoutError> {
+        let rect = crate::bounded_available_rect(ui);
+        let finite = match self.edge {
+            Edge::Top | Edge::Left => true,
+            Edge::Bottom => rect.max.y.is_finite(),
+            Edge::Right => rect.max.x.is_finite(),
+        };
+        if !finite {
+            return Err(LayoutError::InfiniteBounds(rect));
+        }
+        Ok(self.show(ui, add_pinned, add_rest))
+    }
+}
+
+#[inline]
+/// Reserve a strip along an edge of the available space
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::{pin, Edge};
+///
+/// # egui::__run_test_ui(|ui| {
+/// pin(ui, Edge::Top, 24.0, |ui| {
+///     ui.label("Toolbar");
+/// }, |ui| {
+///     ui.label("Body");
+/// });
+/// # });
+/// ```
+pub fn pin<P, R>(
+    ui: &mut Ui,
+    edge: Edge,
+    size: f32,
+    add_pinned: impl FnOnce(&mut Ui) -> P,
+    add_rest: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<(P, R)> {
+    Pin::new(edge, size).show(ui, add_pinned, add_rest)
+}
@@ -0,0 +1,248 @@
+use egui::{
+    emath::Rot2,
+    epaint::{ClippedPrimitive, ClippedShape, Primitive, Tessellator},
+    Align2, Id, InnerResponse, Layout, Rect, Sense, Shape, Ui, UiBuilder, Vec2,
+};
+
+use crate::{AlignedResponse, Aligner, AllocateType, Bounds, WidgetAligner};
+
+/// Get the size of the axis-aligned bounding box of a rect of the given size rotated by `angle` radians.
+pub fn rotated_size(size: Vec2, angle: f32) -> Vec2 {
+    let (sin, cos) = angle.sin_cos();
+    Vec2::new(
+        (size.x * cos).abs() + (size.y * sin).abs(),
+        (size.x * sin).abs() + (size.y * cos).abs(),
+    )
+}
+
+/// A container which rotates its contents by an angle
+/// and aligns the bounding box of the rotated contents within the bounds,
+/// allocating space for the bounding box.
+///
+/// The contents are tessellated and painted as rotated meshes,
+/// so paint callbacks are not shown,
+/// and the interactive rects of widgets are not rotated.
+/// It is meant for labels, icons and other static contents.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::Rotated;
+///
+/// # egui::__run_test_ui(|ui| {
+/// // a vertical axis label aligned to the left edge of a plot
+/// Rotated::new(-std::f32::consts::FRAC_PI_2)
+///     .align(Align2::LEFT_CENTER)
+///     .show(ui, |ui| {
+///         ui.label("Temperature (°C)");
+///     });
+/// # });
+/// ```
+pub struct Rotated<T: Aligner> {
+    /// Used to memorize the size of the contents.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The rotation angle in radians, clockwise.
+    pub angle: f32,
+
+    /// The aligner used to place the bounding box of the rotated contents within the bounds.
+    pub align: T,
+
+    /// The bounds in which the rotated contents will be aligned.
+    /// See [`Bounds`]
+    pub bounds: Bounds,
+
+    /// See [`AllocateType`]
+    pub allocate_type: AllocateType,
+
+    /// The layout of the contents.
+    /// If `None`, use the layout of the current ui.
+    pub layout: Option<Layout>,
+}
+
+pub type Align2Rotated = Rotated<Align2>;
+
+impl Align2Rotated {
+    #[inline]
+    /// Create a new container which rotates its contents by `angle` radians, aligned to the left top.
+    pub fn new(angle: f32) -> Self {
+        Self::from_align(angle, Align2::LEFT_TOP)
+    }
+}
+
+impl<T: Aligner> Rotated<T> {
+    #[inline]
+    /// Create a new container which rotates its contents by `angle` radians with the given aligner.
+    pub fn from_align(angle: f32, align: T) -> Self {
+        Self {
+            id: None,
+            angle,
+            align,
+            bounds: Bounds::available_rect(),
+            allocate_type: AllocateType::Content,
+            layout: None,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the container.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the rotation angle in radians, clockwise.
+    pub fn angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    #[inline]
+    /// Set the aligner.
+    pub fn align(mut self, align: T) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the bounds in which the rotated contents will be aligned.
+    pub fn bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    #[inline]
+    /// See [`AllocateType`]
+    pub fn allocate_type(mut self, allocate_type: AllocateType) -> Self {
+        self.allocate_type = allocate_type;
+        self
+    }
+
+    #[inline]
+    /// Set the layout of the contents.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+}
+
+impl<T: Aligner> Rotated<T> {
+    /// Show the rotated contents.
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<R> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+        let content_id = id.with("rotated_content");
+        let angle = self.angle;
+        let layout = self.layout;
+
+        WidgetAligner::from_align(self.align)
+            .id(id)
+            .bounds(self.bounds)
+            .allocate_type(self.allocate_type)
+            .show(ui, |ui| {
+                let layout = layout.unwrap_or(*ui.layout());
+
+                let Some(content_size) = ui.ctx().data(|data| data.get_temp::<Vec2>(content_id)) else {
+                    // measure the unrotated contents first
+                    crate::request_discard(ui.ctx(), "new Rotated");
+                    let mut content_ui = ui.new_child(
+                        UiBuilder::new()
+                            .max_rect(ui.available_rect_before_wrap())
+                            .layout(layout)
+                            .sizing_pass()
+                            .invisible()
+                    );
+                    let inner = add_contents(&mut content_ui);
+                    let content_size = content_ui.min_size();
+                    ui.ctx().data_mut(|data| data.insert_temp(content_id, content_size));
+                    ui.allocate_exact_size(rotated_size(content_size, angle), Sense::hover());
+                    return inner;
+                };
+
+                let (rect, _) = ui.allocate_exact_size(rotated_size(content_size, angle), Sense::hover());
+
+                let mut content_ui = ui.new_child(
+                    UiBuilder::new()
+                        .max_rect(Rect::from_center_size(rect.center(), content_size))
+                        .layout(layout)
+                );
+                // clipping is done after the rotation
+                content_ui.set_clip_rect(Rect::EVERYTHING);
+
+                let layer_id = content_ui.layer_id();
+                let start = ui.ctx().graphics_mut(|graphics| graphics.entry(layer_id).next_idx());
+                let inner = add_contents(&mut content_ui);
+                rotate_shapes(ui, layer_id, start.0, Rot2::from_angle(angle), rect.center());
+
+                let new_size = content_ui.min_size();
+                if new_size != content_size {
+                    ui.ctx().data_mut(|data| data.insert_temp(content_id, new_size));
+                }
+
+                inner
+            })
+    }
+}
+
+/// Replace the shapes painted on the layer since `start` by meshes rotated around `origin`.
+fn rotate_shapes(ui: &Ui, layer_id: egui::LayerId, start: usize, rot: Rot2, origin: egui::Pos2) {
+    let ctx = ui.ctx();
+    let shapes: Vec<ClippedShape> = ctx.graphics_mut(|graphics| {
+        let paint_list = graphics.entry(layer_id);
+        let shapes = paint_list
+            .all_entries()
+            .skip(start)
+            .map(|clipped| ClippedShape { clip_rect: Rect::EVERYTHING, shape: clipped.shape.clone() })
+            .collect::<Vec<_>>();
+        for idx in start..paint_list.next_idx().0 {
+            paint_list.reset_shape(egui::layers::ShapeIdx(idx));
+        }
+        shapes
+    });
+
+    if shapes.is_empty() {
+        return;
+    }
+
+    let (font_tex_size, prepared_discs) = ctx.fonts(|fonts| {
+        (fonts.font_image_size(), fonts.texture_atlas().lock().prepared_discs())
+    });
+    let mut tessellator = Tessellator::new(
+        ctx.pixels_per_point(),
+        ctx.tessellation_options(|options| *options),
+        font_tex_size,
+        prepared_discs,
+    );
+
+    let painter = ui.painter_at(ui.clip_rect()).with_layer_id(layer_id);
+    for ClippedPrimitive { primitive, .. } in tessellator.tessellate_shapes(shapes) {
+        if let Primitive::Mesh(mut mesh) = primitive {
+            mesh.rotate(rot, origin);
+            painter.add(Shape::mesh(mesh));
+        }
+    }
+}
+
+#[inline]
+/// Show the contents rotated by `angle` radians, clockwise
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::rotated;
+///
+/// # egui::__run_test_ui(|ui| {
+/// rotated(ui, std::f32::consts::FRAC_PI_4, |ui| {
+///     ui.label("Sale!");
+/// });
+/// # });
+/// ```
+pub fn rotated<R>(ui: &mut Ui, angle: f32, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+    Rotated::new(angle).show(ui, add_contents).into()
+}
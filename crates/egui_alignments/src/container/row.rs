@@ -1,11 +1,54 @@
-use std::f32::INFINITY;
+use egui::{
+    vec2, Align, Color32, CursorIcon, Id, InnerResponse, Layout, Margin, Rect, Response, Sense, TextStyle, Ui,
+    UiBuilder, Vec2,
+};
 
-use egui::{vec2, Align, Id, InnerResponse, Layout, Margin, Ui};
+use crate::{AlignedResponse, AllocateType, Axis, GenericContainer, OverflowCallback, LayoutError, ResizeCallback, RovingItems, Skeleton, Tracks};
 
-use super::Container;
+/// How the contents of a [`Row`] wider than its maximum width are scrolled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Marquee {
+    /// Scroll back and forth between the start and the end of the contents.
+    Bounce,
+
+    /// Scroll the contents out, then let them enter again from the other side.
+    Loop,
+}
+
+/// How a track of a [`Row::template`] is sized.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Size {
+    /// Exactly this width.
+    Exact(f32),
+
+    /// The width of the contents of the item.
+    Auto,
+
+    /// A share of the width left by the other tracks, proportional to the given weight.
+    Fraction(f32),
+}
 
 /// A container which aligns its contents horizontally.
 /// See module [`crate::container`] for example usage.
+///
+/// # Marquee
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{Marquee, Row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// Row::new(Align::Center)
+///     .max_width(120.0)
+///     .marquee(Marquee::Bounce)
+///     .show(ui, |ui| {
+///         ui.label("Now playing: a song with a title far too long for the player");
+///     });
+/// # });
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Row {
     /// The id of the row. Used to memorize the size of the contents.
     /// If None, the id will be generated automatically.
@@ -24,11 +67,64 @@ pub struct Row {
     /// If the row should wrap its contents, instead of overflowing.
     pub wrapping: bool,
 
+    /// The maximum width of the row.
+    pub max_width: f32,
+
     /// The maximum height of the row.
     pub max_height: f32,
     
     /// The minimum height of the row.
     pub min_height: f32,
+
+    /// The size of the contents, if known up front.
+    /// Used instead of a sizing pass when no size is memorized yet.
+    pub size_hint: Option<Vec2>,
+
+    /// See [`AllocateType`].
+    /// The bounds are the available rect of the ui.
+    pub allocate_type: AllocateType,
+
+    /// Called after the layout for each axis on which the contents exceeded the bounds.
+    /// Not called in sizing passes.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_overflow: Option<OverflowCallback>,
+
+    /// Called when the memorized size of the contents changes, with the old and the new size.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_resize: Option<ResizeCallback>,
+
+    /// If set, painted in place of the contents during the sizing pass.
+    pub skeleton: Option<Skeleton>,
+
+    /// If set, the contents fade in over this duration, in seconds,
+    /// once their layout is stable after they are first shown.
+    pub fade_in: Option<f32>,
+
+    /// If the contents are skipped by `show_culled` when outside the clip rect.
+    /// Disable it if the contents have side effects which must run every frame.
+    pub cull: bool,
+
+    /// If the items shown with [`Row::show_items`] can be resized
+    /// by dragging the handles between them.
+    pub resizable: bool,
+
+    /// The minimum width of a resizable item.
+    pub min_item_width: f32,
+
+    /// If set, contents wider than `max_width` are clipped
+    /// and scrolled automatically, pausing while hovered.
+    pub marquee: Option<Marquee>,
+
+    /// The scrolling speed of the marquee, in points per second.
+    pub marquee_speed: f32,
+
+    /// If the items shown with [`Row::show_items`] which do not fit in `max_width`
+    /// should be hidden behind a trailing "…", whose tooltip lists them.
+    pub ellipsis: bool,
+
+    /// The sizes of the items shown with [`Row::show_items`], in order.
+    /// The items after the last track take the width of their contents.
+    pub template: Vec<Size>,
 }
 
 impl Row {
@@ -41,8 +137,22 @@ impl Row {
             padding: Margin::ZERO,
             right_to_left: None,
             wrapping: false,
-            max_height: INFINITY,
+            max_width: f32::INFINITY,
+            max_height: f32::INFINITY,
             min_height: 0.0,
+            size_hint: None,
+            allocate_type: AllocateType::Content,
+            on_overflow: None,
+            on_resize: None,
+            skeleton: None,
+            fade_in: None,
+            cull: true,
+            resizable: false,
+            min_item_width: 16.0,
+            marquee: None,
+            marquee_speed: 40.0,
+            ellipsis: false,
+            template: Vec::new(),
         }
     }
     
@@ -74,6 +184,13 @@ impl Row {
         self
     }
 
+    #[inline]
+    /// Set the maximum width of the row.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
     #[inline]
     /// Set the maximum height of the row.
     pub fn max_height(mut self, max_height: f32) -> Self {
@@ -87,6 +204,124 @@ impl Row {
         self.min_height = min_height;
         self
     }
+
+    #[inline]
+    /// Set the size of the contents, if known up front, to skip the sizing pass.
+    pub fn size_hint(mut self, size: impl Into<Vec2>) -> Self {
+        self.size_hint = Some(size.into());
+        self
+    }
+
+    #[inline]
+    /// See [`AllocateType`]
+    pub fn allocate_type(mut self, allocate_type: AllocateType) -> Self {
+        self.allocate_type = allocate_type;
+        self
+    }
+
+    #[inline]
+    /// Set a callback invoked after the layout with the axis and the amount
+    /// by which the contents exceeded the bounds.
+    pub fn on_overflow(mut self, on_overflow: impl Fn(Axis, f32) + 'static) -> Self {
+        self.on_overflow = Some(Box::new(on_overflow));
+        self
+    }
+
+    #[inline]
+    /// Set a callback invoked with the old and the new size of the contents
+    /// when their memorized size changes.
+    pub fn on_resize(mut self, on_resize: impl Fn(Vec2, Vec2) + 'static) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    #[inline]
+    /// Set a placeholder painted in place of the contents during the sizing pass.
+    pub fn skeleton(mut self, skeleton: Skeleton) -> Self {
+        self.skeleton = Some(skeleton);
+        self
+    }
+
+    #[inline]
+    /// Fade the contents in over the given duration, in seconds,
+    /// once their layout is stable after they are first shown, e.g. `0.1`.
+    pub fn fade_in(mut self, duration: f32) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+
+    #[inline]
+    /// Set whether the contents are skipped by `show_culled` when outside the clip rect.
+    pub fn cull(mut self, cull: bool) -> Self {
+        self.cull = cull;
+        self
+    }
+
+    #[inline]
+    /// Set whether the items shown with [`Row::show_items`] can be resized.
+    /// The widths are memorized with the id of the row.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    #[inline]
+    /// Set the minimum width of a resizable item.
+    pub fn min_item_width(mut self, min_item_width: f32) -> Self {
+        self.min_item_width = min_item_width;
+        self
+    }
+
+    #[inline]
+    /// Scroll the contents automatically if they are wider than the maximum width.
+    /// See [`Marquee`].
+    pub fn marquee(mut self, marquee: Marquee) -> Self {
+        self.marquee = Some(marquee);
+        self
+    }
+
+    #[inline]
+    /// Set the scrolling speed of the marquee, in points per second.
+    pub fn marquee_speed(mut self, speed: f32) -> Self {
+        self.marquee_speed = speed;
+        self
+    }
+
+    #[inline]
+    /// Set whether the items shown with [`Row::show_items`] which overflow `max_width`
+    /// should be hidden behind a trailing "…".
+    /// Name the items with [`RowItems::named_item`] to list them in its tooltip.
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    #[inline]
+    /// Set the sizes of the items shown with [`Row::show_items`], like the tracks of a CSS grid.
+    ///
+    /// The [`Size::Fraction`] tracks share the width of the row left by the other tracks,
+    /// so the row fills its maximum width, or the available width.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui::Align;
+    /// use egui_alignments::{Row, Size};
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// Row::new(Align::Center)
+    ///     .template(&[Size::Exact(80.0), Size::Fraction(1.0), Size::Auto])
+    ///     .show_items(ui, |row| {
+    ///         row.item(|ui| ui.label("Title"));
+    ///         row.item(|ui| ui.text_edit_singleline(&mut String::new()));
+    ///         row.item(|ui| ui.button("Save"));
+    ///     });
+    /// # });
+    /// ```
+    pub fn template(mut self, template: &[Size]) -> Self {
+        self.template = template.to_vec();
+        self
+    }
 }
 
 impl Default for Row {
@@ -96,34 +331,561 @@ impl Default for Row {
 }
 
 impl Row {
-    /// Show the row in the given ui.
-    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+    fn container(&self, ui: &Ui, id: Option<Id>) -> GenericContainer {
         let Self {
-            id,
             valign,
             padding,
+            max_width,
             max_height,
             min_height,
+            size_hint,
+            allocate_type,
             ..
         } = *self;
 
-        let right_to_left = self.right_to_left.unwrap_or(ui.layout().prefer_right_to_left());
-
-        let layout = if right_to_left {
+        let layout = if self.is_right_to_left(ui) {
             Layout::right_to_left(valign)
         } else {
             Layout::left_to_right(valign)
         }
         .with_main_wrap(self.wrapping);
 
-        Container {
+        GenericContainer {
             id,
             layout,
             padding,
-            max_size: vec2(INFINITY, max_height),
+            max_size: vec2(max_width, max_height),
             min_size: vec2(0.0, min_height),
+            size_hint,
+            allocate_type,
+            on_resize: None,
+            skeleton: self.skeleton,
+            fade_in: self.fade_in,
+            cull: self.cull,
+        }
+    }
+
+    fn is_right_to_left(&self, ui: &Ui) -> bool {
+        self.right_to_left.unwrap_or(ui.layout().prefer_right_to_left())
+    }
+
+    /// Show the row in the given ui.
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<R> {
+        let response = match self.marquee {
+            Some(marquee) => self.show_marquee(ui, marquee, add_contents),
+            None => self.container(ui, self.id).show_with_resize(ui, self.on_resize.as_ref(), add_contents),
+        };
+        response.report_overflow(self.on_overflow.as_ref());
+        response
+    }
+
+    /// Show the row, or return an error without showing it if it cannot be laid out.
+    /// See [`GenericContainer::try_show`].
+    pub fn try_show<R>(
+        &self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Result<AlignedResponse<R>, LayoutError> {
+        self.container(ui, self.id).check(ui)?;
+        Ok(self.show(ui, add_contents))
+    }
+
+    /// Show the row, skipping the contents if it is outside the clip rect and its size is known.
+    /// See [`GenericContainer::show_culled`].
+    pub fn show_culled<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<Option<R>> {
+        let response = match self.marquee {
+            Some(marquee) => self.show_marquee(ui, marquee, |ui| Some(add_contents(ui))),
+            None => self.container(ui, self.id).show_culled_with_resize(ui, self.on_resize.as_ref(), add_contents),
+        };
+        response.report_overflow(self.on_overflow.as_ref());
+        response
+    }
+
+    /// Show the row, measuring the contents in an invisible sizing pass first, in the same frame.
+    /// See [`GenericContainer::show_two_pass`].
+    ///
+    /// The marquee is animated over frames, so it is not shown in this mode.
+    pub fn show_two_pass<R>(&self, ui: &mut Ui, add_contents: impl FnMut(&mut Ui) -> R) -> AlignedResponse<R> {
+        let response = self.container(ui, self.id).show_two_pass(ui, add_contents);
+        response.report_overflow(self.on_overflow.as_ref());
+        response
+    }
+
+    fn show_marquee<R>(&self, ui: &mut Ui, marquee: Marquee, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<R> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+        let right_to_left = self.is_right_to_left(ui);
+
+        // the contents are laid out without width limit and their size is cached by the container
+        let mut container = self.container(ui, Some(id));
+        container.max_size.x = f32::INFINITY;
+        let content_size = crate::size_cache::get(ui.ctx(), id).unwrap_or(Vec2::ZERO);
+
+        let max_width = self.max_width.min(ui.available_width());
+        let (viewport, response) = ui.allocate_exact_size(
+            vec2(content_size.x.min(max_width), content_size.y),
+            Sense::hover(),
+        );
+
+        let overflow = content_size.x - viewport.width();
+        let phase_id = id.with("marquee_phase");
+        let mut phase = ui.ctx().data(|data| data.get_temp::<f32>(phase_id)).unwrap_or(0.0);
+        let offset = if overflow > 0.0 {
+            if !response.contains_pointer() {
+                phase += ui.input(|input| input.stable_dt).min(0.1) * self.marquee_speed;
+                ui.ctx().request_repaint();
+            }
+
+            match marquee {
+                Marquee::Bounce => {
+                    let phase = phase % (2.0 * overflow);
+                    if phase > overflow { 2.0 * overflow - phase } else { phase }
+                },
+                Marquee::Loop => {
+                    let period = content_size.x + viewport.width();
+                    let phase = phase % period;
+                    if phase > content_size.x { phase - period } else { phase }
+                },
+            }
+        } else {
+            phase = 0.0;
+            0.0
+        };
+        ui.ctx().data_mut(|data| data.insert_temp(phase_id, phase));
+
+        let content_rect = if right_to_left {
+            let max_x = viewport.max.x + offset;
+            Rect::from_x_y_ranges(max_x - content_size.x..=max_x, viewport.y_range())
+        } else {
+            let min_x = viewport.min.x - offset;
+            Rect::from_x_y_ranges(min_x..=min_x + content_size.x, viewport.y_range())
+        };
+
+        let mut content_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(content_rect)
+                .layout(container.layout)
+        );
+        content_ui.set_clip_rect(viewport.intersect(ui.clip_rect()));
+        let AlignedResponse { inner, sizing_pass, content_rect, .. } = container.show_with_resize(&mut content_ui, self.on_resize.as_ref(), add_contents);
+
+        AlignedResponse {
+            inner,
+            sizing_pass,
+            content_rect,
+            bounds: Rect::from_min_size(viewport.min, vec2(max_width, viewport.height())),
+            response,
+        }
+    }
+
+    /// Show the row with its items added one by one through [`RowItems`],
+    /// which allows the row to treat each item individually.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui::Align;
+    /// use egui_alignments::Row;
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// Row::new(Align::Center)
+    ///     .resizable(true)
+    ///     .show_items(ui, |row| {
+    ///         row.item(|ui| ui.label("Name"));
+    ///         row.item(|ui| ui.label("Size"));
+    ///         row.item(|ui| ui.label("Modified"));
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// With [`Row::ellipsis`], the items which do not fit are hidden:
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui::Align;
+    /// use egui_alignments::Row;
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = Row::new(Align::Center)
+    ///     .max_width(160.0)
+    ///     .ellipsis(true)
+    ///     .show_items(ui, |row| {
+    ///         for tag in ["rust", "egui", "layout", "alignment", "gui"] {
+    ///             row.named_item(tag, |ui| ui.button(tag));
+    ///         }
+    ///     });
+    /// if response.hidden_count > 0 {
+    ///     // e.g. offer to expand the row
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// The padding is taken from the width the items may use, so a padded row stays in a narrow parent:
+    /// ```
+    /// # use egui_alignments::egui;
+    /// use egui::{vec2, Align};
+    /// use egui_alignments::Row;
+    ///
+    /// let ctx = egui::Context::default();
+    /// for pass in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             ui.allocate_ui(vec2(200.0, 100.0), |ui| {
+    ///                 let parent = ui.max_rect();
+    ///                 let response = Row::new(Align::Center)
+    ///                     .padding(20.0)
+    ///                     .ellipsis(true)
+    ///                     .show_items(ui, |row| {
+    ///                         for tag in ["one", "two", "three", "four", "five", "six", "seven"] {
+    ///                             row.named_item(tag, |ui| ui.button(tag));
+    ///                         }
+    ///                     });
+    ///                 if pass > 0 {
+    ///                     assert!(response.response.rect.right() <= parent.right());
+    ///                     assert!(response.hidden_count > 0);
+    ///                 }
+    ///             });
+    ///         });
+    ///     });
+    /// }
+    /// ```
+    pub fn show_items<R>(&self, ui: &mut Ui, add_items: impl FnOnce(&mut RowItems) -> R) -> RowItemsResponse<R> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+        let right_to_left = self.is_right_to_left(ui);
+        let max_width = (self.max_width.min(ui.available_width()) - self.padding.sum().x).max(0.0);
+
+        let aligned = self.container(ui, Some(id))
+            .show_with_resize(ui, self.on_resize.as_ref(), |ui| {
+                let widths_id = id.with("item_widths");
+                let widths = if self.resizable {
+                    ui.ctx().data(|data| data.get_temp(widths_id)).unwrap_or_default()
+                } else {
+                    Vec::new()
+                };
+
+                let natural_widths_id = id.with("item_natural_widths");
+                let memorized_natural_widths: Vec<f32> = if self.ellipsis || !self.template.is_empty() {
+                    ui.ctx().data(|data| data.get_temp(natural_widths_id)).unwrap_or_default()
+                } else {
+                    Vec::new()
+                };
+                let visible_count = if self.ellipsis {
+                    visible_item_count(ui, &memorized_natural_widths, max_width)
+                } else {
+                    usize::MAX
+                };
+                let template = template_widths(ui, &self.template, &memorized_natural_widths, max_width);
+
+                let mut items = RowItems {
+                    ui,
+                    id,
+                    resizable: self.resizable,
+                    min_item_width: self.min_item_width,
+                    right_to_left,
+                    widths,
+                    template,
+                    inner_width: max_width,
+                    rects: Vec::new(),
+                    count: 0,
+                    visible_count,
+                    natural_widths: Vec::new(),
+                    hidden: Vec::new(),
+                };
+                let inner = add_items(&mut items);
+                let hidden_count = items.hidden.len();
+                if (self.ellipsis || !self.template.is_empty()) && items.natural_widths != memorized_natural_widths {
+                    items.ui.ctx().data_mut(|data| {
+                        data.insert_temp(natural_widths_id, items.natural_widths.clone())
+                    });
+                    if !self.template.is_empty() {
+                        // the fractions were shared with the old widths of the auto tracks
+                        crate::request_discard(items.ui.ctx(), "Row template");
+                    }
+                }
+                items.finish(widths_id);
+                (inner, hidden_count)
+            });
+
+        aligned.report_overflow(self.on_overflow.as_ref());
+        let AlignedResponse { inner: (inner, hidden_count), response, .. } = aligned;
+        RowItemsResponse { inner, response, hidden_count }
+    }
+}
+
+// the number of items which fit in the max width, leaving room for the ellipsis if some do not
+fn visible_item_count(ui: &Ui, natural_widths: &[f32], max_width: f32) -> usize {
+    let spacing = ui.spacing().item_spacing.x;
+    let total = natural_widths.iter().sum::<f32>()
+        + spacing * natural_widths.len().saturating_sub(1) as f32;
+    if total <= max_width {
+        return usize::MAX;
+    }
+
+    let ellipsis_width = ui.fonts(|fonts| {
+        fonts.layout_no_wrap("…".into(), TextStyle::Body.resolve(ui.style()), Color32::PLACEHOLDER)
+            .size()
+            .x
+    });
+    let mut used = ellipsis_width;
+    natural_widths.iter()
+        .take_while(|width| {
+            used += *width + spacing;
+            used <= max_width
+        })
+        .count()
+}
+
+// the widths of the template tracks, None for the auto tracks
+fn template_widths(ui: &Ui, template: &[Size], natural_widths: &[f32], max_width: f32) -> Vec<Option<f32>> {
+    if template.is_empty() {
+        return Vec::new();
+    }
+
+    let spacing = ui.spacing().item_spacing.x * template.len().saturating_sub(1) as f32;
+    let mut used = spacing;
+    let mut total_weight = 0.0;
+    for (index, size) in template.iter().enumerate() {
+        match *size {
+            Size::Exact(width) => used += width,
+            Size::Auto => used += natural_widths.get(index).copied().unwrap_or(0.0),
+            Size::Fraction(weight) => total_weight += weight.max(0.0),
+        }
+    }
+
+    // nothing to share if the row is unbounded
+    let remaining = if max_width.is_finite() { (max_width - used).max(0.0) } else { 0.0 };
+    template.iter()
+        .map(|size| match *size {
+            Size::Exact(width) => Some(width),
+            Size::Auto => None,
+            Size::Fraction(weight) if total_weight > 0.0 => Some(remaining * weight.max(0.0) / total_weight),
+            Size::Fraction(_) => Some(0.0),
+        })
+        .collect()
+}
+
+/// The response of [`Row::show_items`].
+pub struct RowItemsResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the row.
+    pub response: Response,
+
+    /// The number of items hidden behind the ellipsis.
+    /// Always zero unless [`Row::ellipsis`] is set.
+    pub hidden_count: usize,
+}
+
+/// The handle used to add items to a [`Row`] one by one.
+/// See [`Row::show_items`].
+pub struct RowItems<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+    resizable: bool,
+    min_item_width: f32,
+    right_to_left: bool,
+    widths: Vec<f32>,
+    template: Vec<Option<f32>>,
+    inner_width: f32,
+    rects: Vec<Rect>,
+    count: usize,
+    visible_count: usize,
+    natural_widths: Vec<f32>,
+    hidden: Vec<String>,
+}
+
+impl RowItems<'_> {
+    #[inline]
+    /// The ui of the row contents.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    #[inline]
+    /// The number of items added so far, including the hidden ones.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    #[inline]
+    /// If no item has been added yet.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The rects of the visible items added so far.
+    pub fn item_rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    #[inline]
+    /// Add an item to the row.
+    pub fn item<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        self.add_item(None, None, add_contents)
+    }
+
+    #[inline]
+    /// Add an item to the row, with a name listed in the tooltip of the ellipsis if it is hidden.
+    pub fn named_item<R>(&mut self, name: impl Into<String>, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        self.add_item(Some(name.into()), None, add_contents)
+    }
+
+    #[inline]
+    /// Add spacing before the next item, which is not counted as an item.
+    /// See [`spacer`](crate::spacer).
+    pub fn spacer(&mut self, amount: f32) {
+        crate::spacer(self.ui, amount);
+    }
+
+    #[inline]
+    /// Add flexible spacing before the next item, which is not counted as an item,
+    /// taking a share of the space left in the row proportional to its weight.
+    /// See [`stretch`](crate::stretch()).
+    pub fn stretch(&mut self, weight: f32) {
+        crate::stretch(self.ui, weight);
+    }
+
+    #[inline]
+    /// Add an item exactly as wide as the given fraction of the inner width of the row.
+    /// See [`pct_width`].
+    pub fn child_pct<R>(&mut self, fraction: f32, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let width = fraction_of_width(self.ui, fraction, self.inner_width);
+        self.add_item(None, Some(width), add_contents)
+    }
+
+    /// Add an item to the row taking part in a [`RovingFocus`](crate::RovingFocus) group,
+    /// returning the response of its focusable widget.
+    /// The items hidden behind the ellipsis are left out of the group.
+    pub fn roving_item(&mut self, roving: &mut RovingItems, add_contents: impl FnOnce(&mut Ui) -> Response) -> Response {
+        let visible_count = self.rects.len();
+        let response = self.item(add_contents).inner;
+        if self.rects.len() > visible_count {
+            roving.item(&response);
+        }
+        response
+    }
+
+    /// Add an item to the row in a track shared with other rows,
+    /// at least as wide as the widest item of the track. See [`Tracks`].
+    pub fn track_item<R>(&mut self, tracks: Tracks, track: impl std::hash::Hash, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let width = tracks.width(self.ui.ctx(), &track);
+        let right_to_left = self.right_to_left;
+        self.add_item(None, None, |ui| {
+            let inner = add_contents(ui);
+            // measure before widening, so the track can shrink
+            let rect = ui.min_rect();
+            tracks.record(ui.ctx(), &track, rect.width());
+            if let Some(width) = width {
+                let x = if right_to_left { rect.right() - width } else { rect.left() + width };
+                ui.expand_to_include_x(x);
+            }
+            inner
+        })
+    }
+
+    fn add_item<R>(&mut self, name: Option<String>, width: Option<f32>, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let index = self.count;
+        self.count += 1;
+
+        if index >= self.visible_count {
+            // still run the contents to measure them, but invisibly
+            let mut hidden_ui = self.ui.new_child(
+                UiBuilder::new()
+                    .max_rect(self.ui.available_rect_before_wrap())
+                    .layout(*self.ui.layout())
+                    .sizing_pass()
+                    .invisible()
+            );
+            let inner = add_contents(&mut hidden_ui);
+            self.natural_widths.push(hidden_ui.min_rect().width());
+            self.hidden.push(name.unwrap_or_else(|| format!("#{}", index + 1)));
+            return InnerResponse { inner, response: hidden_ui.response() };
+        }
+
+        let index = self.rects.len();
+        let width = width.or_else(|| if self.resizable {
+            self.widths.get(index).map(|width| width.max(self.min_item_width))
+        } else {
+            None
+        })
+        .or_else(|| self.template.get(index).copied().flatten());
+
+        let response = match width {
+            Some(width) => {
+                let size = vec2(width, self.ui.available_height());
+                self.ui.allocate_ui_with_layout(size, Layout::top_down(Align::Min), |ui| {
+                    ui.set_width(width);
+                    add_contents(ui)
+                })
+            },
+            None => self.ui.scope(add_contents),
+        };
+
+        self.rects.push(response.response.rect);
+        self.natural_widths.push(response.response.rect.width());
+        response
+    }
+
+    fn finish(self, widths_id: Id) {
+        if !self.hidden.is_empty() {
+            self.ui.label("…").on_hover_ui(|ui| {
+                for name in &self.hidden {
+                    ui.label(name);
+                }
+            });
+        }
+
+        if !self.resizable {
+            return;
+        }
+
+        let mut widths = self.widths.clone();
+        widths.resize(self.rects.len(), 0.0);
+        for (width, rect) in widths.iter_mut().zip(&self.rects) {
+            if *width <= 0.0 {
+                *width = rect.width().max(self.min_item_width);
+            }
+        }
+
+        // a handle in the gap after each item but the last one
+        let grab = self.ui.style().interaction.resize_grab_radius_side;
+        for (i, pair) in self.rects.windows(2).enumerate() {
+            let (a, b) = (pair[0], pair[1]);
+            let x = if self.right_to_left {
+                (a.left() + b.right()) / 2.0
+            } else {
+                (a.right() + b.left()) / 2.0
+            };
+            let y_range = a.y_range().min.min(b.top())..=a.y_range().max.max(b.bottom());
+            let handle_rect = Rect::from_x_y_ranges(x - grab..=x + grab, y_range.clone());
+            let response = self.ui
+                .interact(handle_rect, self.id.with(("item_handle", i)), Sense::drag())
+                .on_hover_cursor(CursorIcon::ResizeHorizontal);
+
+            let delta = response.drag_delta().x;
+            if delta != 0.0 {
+                let delta = if self.right_to_left { -delta } else { delta };
+                widths[i] = (widths[i] + delta).max(self.min_item_width);
+            }
+
+            let stroke = if response.hovered() || response.dragged() {
+                self.ui.visuals().widgets.active.bg_stroke
+            } else {
+                self.ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            self.ui.painter().vline(x, y_range, stroke);
+        }
+
+        if widths != self.widths {
+            self.ui.ctx().data_mut(|data| data.insert_temp(widths_id, widths));
         }
-        .show(ui, add_contents)
     }
 }
 
@@ -132,6 +894,7 @@ impl Row {
 /// 
 /// # Example
 /// ```rust
+/// # use egui_alignments::egui;
 /// use egui::Align;
 /// use egui_alignments::row;
 ///
@@ -144,5 +907,43 @@ impl Row {
 /// # });
 /// ```
 pub fn row(ui: &mut Ui, valign: Align, add_contents: impl FnOnce(&mut Ui)) -> InnerResponse<()> {
-    Row::new(valign).show(ui, add_contents)
-}
\ No newline at end of file
+    Row::new(valign).show(ui, add_contents).into()
+}
+
+// the fraction of the width, less its share of the spacing,
+// so the fractions of several items summing to one fill the width with their spacing
+fn fraction_of_width(ui: &Ui, fraction: f32, width: f32) -> f32 {
+    let spacing = ui.spacing().item_spacing.x;
+    (fraction * (width + spacing) - spacing).max(0.0)
+}
+
+/// Show the contents exactly as wide as the given fraction of the width of a horizontal ui,
+/// e.g. for a 30/70 split.
+///
+/// The item spacing is shared between the children,
+/// so children whose fractions sum to one fill the width of the ui.
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::pct_width;
+///
+/// # egui::__run_test_ui(|ui| {
+/// ui.horizontal(|ui| {
+///     pct_width(ui, 0.3, |ui| {
+///         ui.label("Folders");
+///     });
+///     pct_width(ui, 0.7, |ui| {
+///         ui.label("Messages");
+///     });
+/// });
+/// # });
+/// ```
+pub fn pct_width<R>(ui: &mut Ui, fraction: f32, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+    let width = fraction_of_width(ui, fraction, ui.max_rect().width());
+    let size = vec2(width, ui.available_height());
+    ui.allocate_ui_with_layout(size, Layout::top_down(Align::Min), |ui| {
+        ui.set_width(width);
+        add_contents(ui)
+    })
+}
@@ -0,0 +1,195 @@
+use egui::{
+    scroll_area::ScrollBarVisibility, vec2, Align, Id, InnerResponse, Layout, ScrollArea, Ui,
+};
+
+use crate::{Row, RowItems};
+
+/// A header [`Row`] pinned above a scrolling body, for building tables out of rows.
+///
+/// The header scrolls horizontally with the body, and the widths of its items,
+/// which can be resized by dragging the handles between them,
+/// are the widths of the cells of the body rows.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::ScrollSync;
+///
+/// # egui::__run_test_ui(|ui| {
+/// ScrollSync::new("files").show(
+///     ui,
+///     |header| {
+///         header.item(|ui| ui.strong("Name"));
+///         header.item(|ui| ui.strong("Size"));
+///         header.item(|ui| ui.strong("Modified"));
+///     },
+///     |body| {
+///         for (name, size, modified) in [("notes.txt", "2 KB", "today"), ("photo.png", "3 MB", "May 4")] {
+///             body.row(|cells| {
+///                 cells.cell(|ui| ui.label(name));
+///                 cells.cell(|ui| ui.label(size));
+///                 cells.cell(|ui| ui.label(modified));
+///             });
+///         }
+///     },
+/// );
+/// # });
+/// ```
+pub struct ScrollSync {
+    /// Used to memorize the column widths and the horizontal scroll offset.
+    pub id: Id,
+
+    /// The vertical alignment of the header items and of the body cells.
+    pub valign: Align,
+
+    /// If the columns can be resized by dragging the handles between the header items.
+    pub resizable: bool,
+}
+
+impl ScrollSync {
+    #[inline]
+    /// Create a new header and body pair with resizable columns.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            valign: Align::Center,
+            resizable: true,
+        }
+    }
+
+    #[inline]
+    /// Set the vertical alignment of the header items and of the body cells.
+    pub fn valign(mut self, valign: Align) -> Self {
+        self.valign = valign;
+        self
+    }
+
+    #[inline]
+    /// Set whether the columns can be resized.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+impl ScrollSync {
+    /// Show the header and the body below it, filling the available space.
+    pub fn show<H, B>(
+        &self,
+        ui: &mut Ui,
+        add_header: impl FnOnce(&mut RowItems) -> H,
+        add_body: impl FnOnce(&mut ScrollSyncBody) -> B,
+    ) -> InnerResponse<(H, B)> {
+        let offset_id = self.id.with("offset");
+        let offset: f32 = ui.ctx().data(|data| data.get_temp(offset_id)).unwrap_or_default();
+
+        ui.vertical(|ui| {
+            // the header follows the body and cannot be scrolled on its own
+            let header = ScrollArea::horizontal()
+                .id_salt(self.id.with("header"))
+                .horizontal_scroll_offset(offset)
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .enable_scrolling(false)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        let inner = Row::new(self.valign)
+                            .id(self.id.with("header_row"))
+                            .resizable(self.resizable)
+                            .show_items(ui, |items| {
+                                let inner = add_header(items);
+                                let widths: Vec<f32> = items.item_rects().iter().map(|rect| rect.width()).collect();
+                                (inner, widths)
+                            })
+                            .inner;
+                        // leave room for the vertical scroll bar of the body,
+                        // so the header can scroll as far as the body
+                        ui.add_space(ui.spacing().scroll.allocated_width());
+                        inner
+                    })
+                    .inner
+                })
+                .inner;
+            let (header_inner, widths) = header;
+
+            let body = ScrollArea::both()
+                .id_salt(self.id.with("body"))
+                .auto_shrink(false)
+                .show(ui, |ui| {
+                    let mut body = ScrollSyncBody {
+                        ui,
+                        widths,
+                        valign: self.valign,
+                    };
+                    add_body(&mut body)
+                });
+
+            let new_offset = body.state.offset.x;
+            if new_offset != offset {
+                ui.ctx().data_mut(|data| data.insert_temp(offset_id, new_offset));
+                // the header was shown at the old offset
+                crate::request_discard(ui.ctx(), "ScrollSync offset");
+            }
+
+            (header_inner, body.inner)
+        })
+    }
+}
+
+/// The body of a [`ScrollSync`], adding rows whose cells are aligned under the header items.
+pub struct ScrollSyncBody<'a> {
+    ui: &'a mut Ui,
+    widths: Vec<f32>,
+    valign: Align,
+}
+
+impl ScrollSyncBody<'_> {
+    #[inline]
+    /// The ui of the body contents.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    #[inline]
+    /// The widths of the columns, from the header items.
+    pub fn column_widths(&self) -> &[f32] {
+        &self.widths
+    }
+
+    /// Add a row, whose cells are as wide as the header items.
+    pub fn row<R>(&mut self, add_cells: impl FnOnce(&mut ScrollSyncCells) -> R) -> InnerResponse<R> {
+        let widths = &self.widths;
+        let valign = self.valign;
+        let size = vec2(self.ui.available_width(), self.ui.spacing().interact_size.y);
+        self.ui.allocate_ui_with_layout(size, Layout::left_to_right(valign), |ui| {
+            let mut cells = ScrollSyncCells { ui, widths, index: 0 };
+            add_cells(&mut cells)
+        })
+    }
+}
+
+/// The cells of a row of a [`ScrollSyncBody`].
+pub struct ScrollSyncCells<'a> {
+    ui: &'a mut Ui,
+    widths: &'a [f32],
+    index: usize,
+}
+
+impl ScrollSyncCells<'_> {
+    /// Add a cell as wide as the next header item.
+    /// The cells after the last header item take the width of their contents.
+    pub fn cell<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let width = self.widths.get(self.index).copied();
+        self.index += 1;
+
+        match width {
+            Some(width) => {
+                let size = vec2(width, self.ui.spacing().interact_size.y);
+                self.ui.allocate_ui_with_layout(size, Layout::top_down(Align::Min), |ui| {
+                    ui.set_width(width);
+                    add_contents(ui)
+                })
+            },
+            None => self.ui.scope(add_contents),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use egui::{lerp, CursorIcon, Id, InnerResponse, Layout, Rect, Sense, Ui};
+
+use super::{Edge, Pin};
+
+/// A sidebar within a Ui, with a fixed or resizable width,
+/// collapsible to a narrow icon rail with an animated transition.
+/// The remaining width is given to the main contents.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::Sidebar;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let collapsed = false;
+/// Sidebar::left(180.0)
+///     .resizable(true)
+///     .collapsed(collapsed)
+///     .show(
+///         ui,
+///         |ui, rail| {
+///             if rail {
+///                 ui.label("🏠");
+///             } else {
+///                 ui.label("🏠 Home");
+///             }
+///         },
+///         |ui| {
+///             ui.label("Main contents");
+///         },
+///     );
+/// # });
+/// ```
+pub struct Sidebar {
+    /// Used to memorize the width and the collapse animation.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The edge on which the sidebar is shown.
+    /// Only [`Edge::Left`] and [`Edge::Right`] are meaningful.
+    pub edge: Edge,
+
+    /// The default width of the expanded sidebar.
+    pub width: f32,
+
+    /// The minimum width of the expanded sidebar when resizing.
+    pub min_width: f32,
+
+    /// The maximum width of the expanded sidebar when resizing.
+    pub max_width: f32,
+
+    /// If `true`, the sidebar can be resized by dragging its inner edge.
+    pub resizable: bool,
+
+    /// If `true`, the sidebar is collapsed to an icon rail.
+    pub collapsed: bool,
+
+    /// The width of the collapsed icon rail.
+    pub rail_width: f32,
+
+    /// The duration of the collapse animation in seconds.
+    /// If `None`, use the animation time of the style.
+    pub animation_time: Option<f32>,
+
+    /// The layout of the sidebar contents.
+    pub layout: Layout,
+}
+
+impl Sidebar {
+    #[inline]
+    /// Create a new sidebar on the given edge.
+    pub fn new(edge: Edge, width: f32) -> Self {
+        Self {
+            id: None,
+            edge,
+            width,
+            min_width: 64.0,
+            max_width: f32::INFINITY,
+            resizable: false,
+            collapsed: false,
+            rail_width: 36.0,
+            animation_time: None,
+            layout: Layout::top_down(egui::Align::Min),
+        }
+    }
+
+    #[inline]
+    /// Create a new sidebar on the left.
+    pub fn left(width: f32) -> Self {
+        Self::new(Edge::Left, width)
+    }
+
+    #[inline]
+    /// Create a new sidebar on the right.
+    pub fn right(width: f32) -> Self {
+        Self::new(Edge::Right, width)
+    }
+
+    #[inline]
+    /// Set the id of the sidebar.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the minimum width of the expanded sidebar when resizing.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    #[inline]
+    /// Set the maximum width of the expanded sidebar when resizing.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    #[inline]
+    /// Set whether the sidebar can be resized by dragging its inner edge.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    #[inline]
+    /// Set whether the sidebar is collapsed to an icon rail.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    #[inline]
+    /// Set the width of the collapsed icon rail.
+    pub fn rail_width(mut self, rail_width: f32) -> Self {
+        self.rail_width = rail_width;
+        self
+    }
+
+    #[inline]
+    /// Set the duration of the collapse animation in seconds.
+    pub fn animation_time(mut self, animation_time: f32) -> Self {
+        self.animation_time = Some(animation_time);
+        self
+    }
+
+    #[inline]
+    /// Set the layout of the sidebar contents.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+}
+
+impl Sidebar {
+    /// Show the sidebar and the main contents in the given ui.
+    ///
+    /// The second argument of `add_sidebar` is `true`
+    /// while the sidebar is narrower than halfway between the rail and the expanded width,
+    /// so the contents can switch to their icon-only presentation.
+    pub fn show<S, R>(
+        &self,
+        ui: &mut Ui,
+        add_sidebar: impl FnOnce(&mut Ui, bool) -> S,
+        add_main: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<(S, R)> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+
+        let expanded_width: f32 = ui.ctx()
+            .data(|data| data.get_temp(id))
+            .unwrap_or(self.width)
+            .clamp(self.min_width, self.max_width);
+
+        let collapse_t = ui.ctx().animate_bool_with_time(
+            id.with("collapse"),
+            self.collapsed,
+            self.animation_time.unwrap_or(ui.style().animation_time),
+        );
+        let width = lerp(expanded_width..=self.rail_width, collapse_t);
+        let rail = collapse_t > 0.5;
+
+        let fill = ui.visuals().panel_fill;
+        let gap = ui.spacing().item_spacing.x;
+        let (strip_rect, _) = self.edge.split_rect(ui.available_rect_before_wrap(), width, gap);
+        ui.painter().rect_filled(strip_rect, 0.0, fill);
+
+        let layout = self.layout;
+        let InnerResponse { inner, response } = Pin::new(self.edge, width)
+            .gap(gap)
+            .layout(layout)
+            .separator(true)
+            .show(ui, |ui| add_sidebar(ui, rail), add_main);
+
+        // drag the separator to resize
+        if self.resizable && !self.collapsed {
+            let x = match self.edge {
+                Edge::Right => strip_rect.left() - gap / 2.0,
+                _ => strip_rect.right() + gap / 2.0,
+            };
+            let grab = ui.style().interaction.resize_grab_radius_side;
+            let handle_rect = Rect::from_x_y_ranges(x - grab..=x + grab, strip_rect.y_range());
+            let handle_response = ui
+                .interact(handle_rect, id.with("resize"), Sense::drag())
+                .on_hover_cursor(CursorIcon::ResizeHorizontal);
+
+            let delta = handle_response.drag_delta().x;
+            if delta != 0.0 {
+                let delta = if self.edge == Edge::Right { -delta } else { delta };
+                let new_width = (expanded_width + delta).clamp(self.min_width, self.max_width);
+                ui.ctx().data_mut(|data| data.insert_temp(id, new_width));
+            }
+
+            if handle_response.hovered() || handle_response.dragged() {
+                let stroke = ui.visuals().widgets.active.bg_stroke;
+                ui.painter().vline(x, strip_rect.y_range(), stroke);
+            }
+        }
+
+        InnerResponse { inner, response }
+    }
+}
@@ -0,0 +1,201 @@
+use egui::{
+    vec2, Align, Align2, Label, Layout, Margin, Rect, Response, Sense, TextStyle, Ui, UiBuilder, WidgetText,
+};
+
+use crate::{Bounds, Column, RatioSplit, WidgetAligner};
+
+/// A bullet of [`Slide::show_bullets`].
+#[derive(Clone)]
+pub struct Bullet {
+    /// The indent level of the bullet, `0` being the outermost.
+    pub level: usize,
+
+    /// The text of the bullet.
+    pub text: WidgetText,
+}
+
+impl Bullet {
+    #[inline]
+    /// Create a new bullet at the given indent level.
+    pub fn new(level: usize, text: impl Into<WidgetText>) -> Self {
+        Self { level, text: text.into() }
+    }
+}
+
+/// A container filling the available space with a presentation slide,
+/// laid out with one of the common slide templates.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{Bullet, Slide};
+///
+/// # egui::__run_test_ui(|ui| {
+/// Slide::new().show_bullets(ui, "Agenda", [
+///     Bullet::new(0, "Layout"),
+///     Bullet::new(1, "Rows and columns"),
+///     Bullet::new(1, "Aligners"),
+///     Bullet::new(0, "Questions"),
+/// ]);
+/// # });
+/// ```
+pub struct Slide {
+    /// The padding between the edges of the slide and its contents.
+    pub padding: Margin,
+
+    /// The space between the title and the body of the slide.
+    pub title_gap: f32,
+
+    /// The indent of each bullet level, which is also the width of the bullet markers.
+    pub indent: f32,
+
+    /// The text style of the titles.
+    pub title_style: TextStyle,
+}
+
+impl Default for Slide {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the markers of the bullet levels, repeated for the deeper levels
+const MARKERS: [&str; 3] = ["•", "◦", "▪"];
+
+impl Slide {
+    #[inline]
+    /// Create a new slide.
+    pub fn new() -> Self {
+        Self {
+            padding: Margin::from(32.0),
+            title_gap: 16.0,
+            indent: 24.0,
+            title_style: TextStyle::Heading,
+        }
+    }
+
+    #[inline]
+    /// Set the padding between the edges of the slide and its contents.
+    pub fn padding(mut self, padding: impl Into<Margin>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    #[inline]
+    /// Set the space between the title and the body of the slide.
+    pub fn title_gap(mut self, title_gap: f32) -> Self {
+        self.title_gap = title_gap;
+        self
+    }
+
+    #[inline]
+    /// Set the indent of each bullet level.
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    #[inline]
+    /// Set the text style of the titles.
+    pub fn title_style(mut self, title_style: TextStyle) -> Self {
+        self.title_style = title_style;
+        self
+    }
+}
+
+impl Slide {
+    // allocate the whole available space and show the contents within the padding
+    fn show_frame<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> (R, Response) {
+        let rect = ui.available_rect_before_wrap();
+        let mut content_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(rect - self.padding)
+                .layout(Layout::top_down(Align::Min))
+        );
+        let inner = add_contents(&mut content_ui);
+        let response = ui.allocate_rect(rect.union(content_ui.min_rect() + self.padding), Sense::hover());
+        (inner, response)
+    }
+
+    // show the title at the top of a content slide
+    fn show_heading(&self, ui: &mut Ui, title: impl Into<WidgetText>) {
+        ui.scope(|ui| {
+            ui.style_mut().override_text_style = Some(self.title_style.clone());
+            ui.add(Label::new(title).wrap());
+        });
+        ui.add_space(self.title_gap);
+    }
+
+    /// Show a title slide, with the title and the subtitle centered.
+    /// Nothing is shown for an empty subtitle.
+    pub fn show_title(&self, ui: &mut Ui, title: impl Into<WidgetText>, subtitle: impl Into<WidgetText>) -> Response {
+        let (title, subtitle) = (title.into(), subtitle.into());
+        self.show_frame(ui, |ui| {
+            WidgetAligner::center()
+                .bounds(Bounds::max_rect())
+                .show(ui, |ui| {
+                    Column::new(Align::Center).show(ui, |ui| {
+                        ui.scope(|ui| {
+                            ui.style_mut().override_text_style = Some(self.title_style.clone());
+                            ui.add(Label::new(title).wrap());
+                        });
+                        if !subtitle.is_empty() {
+                            ui.add(Label::new(subtitle).wrap());
+                        }
+                    });
+                });
+        })
+        .1
+    }
+
+    /// Show a slide with a title and bullets,
+    /// the markers and the texts of the same level being aligned,
+    /// and the wrapped lines of a bullet aligned with its first one.
+    pub fn show_bullets(
+        &self,
+        ui: &mut Ui,
+        title: impl Into<WidgetText>,
+        bullets: impl IntoIterator<Item = Bullet>,
+    ) -> Response {
+        self.show_frame(ui, |ui| {
+            self.show_heading(ui, title);
+            for bullet in bullets {
+                ui.horizontal_top(|ui| {
+                    ui.add_space(self.indent * bullet.level as f32);
+
+                    // the markers are right-aligned in a column as wide as the indent
+                    let marker_size = vec2(self.indent, ui.spacing().interact_size.y);
+                    ui.allocate_ui_with_layout(marker_size, Layout::right_to_left(Align::Min), |ui| {
+                        ui.set_width(self.indent);
+                        ui.label(MARKERS[bullet.level % MARKERS.len()]);
+                    });
+                    ui.add(Label::new(bullet.text).wrap());
+                });
+            }
+        })
+        .1
+    }
+
+    /// Show a slide with a title and two contents side by side, each taking half of the width.
+    pub fn show_two_content<A, B>(
+        &self,
+        ui: &mut Ui,
+        title: impl Into<WidgetText>,
+        add_left: impl FnOnce(&mut Ui) -> A,
+        add_right: impl FnOnce(&mut Ui) -> B,
+    ) -> ((A, B), Response) {
+        self.show_frame(ui, |ui| {
+            self.show_heading(ui, title);
+            let body = Rect::from_min_max(ui.cursor().min, ui.max_rect().max);
+            let mut body_ui = ui.new_child(UiBuilder::new().max_rect(body).layout(Layout::top_down(Align::Min)));
+            let (left, right) = RatioSplit::new(0.5)
+                .first_align(Align2::LEFT_TOP)
+                .second_align(Align2::LEFT_TOP)
+                .gap(self.indent)
+                .show(&mut body_ui, add_left, add_right)
+                .inner;
+            ui.advance_cursor_after_rect(body_ui.min_rect());
+            (left, right)
+        })
+    }
+}
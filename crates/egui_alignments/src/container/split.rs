@@ -0,0 +1,299 @@
+use egui::{Align2, Id, InnerResponse, Layout, Rect, Sense, Ui, UiBuilder, Vec2};
+
+use crate::{Bounds, LayoutError, WidgetAligner};
+
+use super::Edge;
+
+/// A container which divides the available space into two panes by an exact ratio,
+/// regardless of the sizes of their contents.
+/// The contents of each pane are aligned independently.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::RatioSplit;
+///
+/// # egui::__run_test_ui(|ui| {
+/// RatioSplit::new(0.38)
+///     .first_align(Align2::RIGHT_CENTER)
+///     .second_align(Align2::LEFT_CENTER)
+///     .show(
+///         ui,
+///         |ui| { ui.label("Label"); },
+///         |ui| { ui.label("Value"); },
+///     );
+/// # });
+/// ```
+pub struct RatioSplit {
+    /// Used to memorize the size of the contents with [`RatioSplit::fit_contents`].
+    /// If None, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The fraction of the available space given to the first pane.
+    pub ratio: f32,
+
+    /// If `true`, the panes are stacked vertically and the ratio divides the height.
+    /// Otherwise the panes are side by side and the ratio divides the width.
+    pub vertical: bool,
+
+    /// The gap between the panes.
+    /// If `None`, use the item spacing of the ui.
+    pub gap: Option<f32>,
+
+    /// The alignment of the contents of the first pane.
+    pub first_align: Align2,
+
+    /// The alignment of the contents of the second pane.
+    pub second_align: Align2,
+
+    /// The layout of the contents of both panes.
+    /// If `None`, use the layout of the current ui.
+    pub layout: Option<Layout>,
+
+    /// If the panes are only as high as the higher of their contents,
+    /// or as wide as the wider one for a vertical split,
+    /// instead of taking all the available space.
+    pub fit_contents: bool,
+}
+
+impl RatioSplit {
+    #[inline]
+    /// Create a new horizontal split giving `ratio` of the width to the first pane.
+    pub fn new(ratio: f32) -> Self {
+        Self {
+            id: None,
+            ratio,
+            vertical: false,
+            gap: None,
+            first_align: Align2::LEFT_TOP,
+            second_align: Align2::LEFT_TOP,
+            layout: None,
+            fit_contents: false,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the split.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set whether the panes are stacked vertically.
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    #[inline]
+    /// Set the gap between the panes.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    #[inline]
+    /// Set the alignment of the contents of the first pane.
+    pub fn first_align(mut self, align: Align2) -> Self {
+        self.first_align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the alignment of the contents of the second pane.
+    pub fn second_align(mut self, align: Align2) -> Self {
+        self.second_align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the layout of the contents of both panes.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    #[inline]
+    /// Set whether the panes are only as large as the larger of their contents across the split,
+    /// so the smaller one can be aligned against the larger one.
+    pub fn fit_contents(mut self, fit_contents: bool) -> Self {
+        self.fit_contents = fit_contents;
+        self
+    }
+}
+
+impl RatioSplit {
+    /// Show the two panes in the given ui.
+    ///
+    /// An available space infinite along the split, e.g. the height in a vertical scroll area
+    /// for a vertical split, is cut at the clip rect.
+    pub fn show<A, B>(
+        &self,
+        ui: &mut Ui,
+        add_first: impl FnOnce(&mut Ui) -> A,
+        add_second: impl FnOnce(&mut Ui) -> B,
+    ) -> InnerResponse<(A, B)> {
+        let mut rect = crate::bounded_available_rect(ui);
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+        let size_id = id.with("fit_size");
+        let memorized: Option<f32> = if self.fit_contents {
+            ui.ctx().data(|data| data.get_temp(size_id))
+        } else {
+            None
+        };
+        if let Some(size) = memorized {
+            if self.vertical {
+                rect.set_width(size);
+            } else {
+                rect.set_height(size);
+            }
+        }
+
+        let (edge, length, gap) = if self.vertical {
+            (Edge::Top, rect.height(), self.gap.unwrap_or(ui.spacing().item_spacing.y))
+        } else {
+            (Edge::Left, rect.width(), self.gap.unwrap_or(ui.spacing().item_spacing.x))
+        };
+        let first_length = ((length - gap) * self.ratio.clamp(0.0, 1.0)).max(0.0);
+        let (first_rect, second_rect) = edge.split_rect(rect, first_length, gap);
+
+        let layout = self.layout.unwrap_or(*ui.layout());
+        let (first, first_size) = show_pane(ui, first_rect, layout, self.first_align, "first", add_first);
+        let (second, second_size) = show_pane(ui, second_rect, layout, self.second_align, "second", add_second);
+
+        if self.fit_contents {
+            let content_size = first_size.max(second_size);
+            let size = if self.vertical { content_size.x } else { content_size.y };
+            if memorized != Some(size) {
+                ui.ctx().data_mut(|data| data.insert_temp(size_id, size));
+                crate::request_discard(ui.ctx(), "new RatioSplit size");
+            }
+            if self.vertical {
+                rect.set_width(size);
+            } else {
+                rect.set_height(size);
+            }
+        }
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+
+        InnerResponse { inner: (first, second), response }
+    }
+
+    /// Show the two panes,
+    /// or return an error without showing them if the available space is infinite along the split,
+    /// even cut at the clip rect.
+    pub fn try_show<A, B>(
+        &self,
+        ui: &mut Ui,
+        add_first: impl FnOnce(&mut Ui) -> A,
+        add_second: impl FnOnce(&mut Ui) -> B,
+    ) -> Result<InnerResponse<(A, B)>, LayoutError> {
+        let rect = crate::bounded_available_rect(ui);
+        let length = if self.vertical { rect.height() } else { rect.width() };
+        if !length.is_finite() {
+            return Err(LayoutError::InfiniteBounds(rect));
+        }
+        Ok(self.show(ui, add_first, add_second))
+    }
+}
+
+fn show_pane<R>(
+    ui: &mut Ui,
+    rect: Rect,
+    layout: Layout,
+    align: Align2,
+    id_salt: &str,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> (R, Vec2) {
+    let mut pane_ui = ui.new_child(
+        UiBuilder::new()
+            .id_salt(id_salt)
+            .max_rect(rect)
+            .layout(layout)
+    );
+    pane_ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+
+    WidgetAligner::from_align(align)
+        .bounds(Bounds::max_rect())
+        .show(&mut pane_ui, |ui| {
+            let inner = add_contents(ui);
+            (inner, ui.min_size())
+        })
+        .inner
+}
+
+#[inline]
+/// Divide the available width into two panes by an exact ratio
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::split_ratio;
+///
+/// # egui::__run_test_ui(|ui| {
+/// split_ratio(ui, 0.38, |ui| {
+///     ui.label("38%");
+/// }, |ui| {
+///     ui.label("62%");
+/// });
+/// # });
+/// ```
+pub fn split_ratio<A, B>(
+    ui: &mut Ui,
+    ratio: f32,
+    add_first: impl FnOnce(&mut Ui) -> A,
+    add_second: impl FnOnce(&mut Ui) -> B,
+) -> InnerResponse<(A, B)> {
+    RatioSplit::new(ratio).show(ui, add_first, add_second)
+}
+
+#[inline]
+/// Divide the available height into two panes by an exact ratio
+pub fn split_ratio_vertical<A, B>(
+    ui: &mut Ui,
+    ratio: f32,
+    add_first: impl FnOnce(&mut Ui) -> A,
+    add_second: impl FnOnce(&mut Ui) -> B,
+) -> InnerResponse<(A, B)> {
+    RatioSplit::new(ratio)
+        .vertical(true)
+        .show(ui, add_first, add_second)
+}
+
+#[inline]
+/// Divide the available width into two columns by an exact ratio,
+/// as high as the higher of them, with the contents of both vertically centered
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::two_columns;
+///
+/// # egui::__run_test_ui(|ui| {
+/// two_columns(ui, 0.3, |ui| {
+///     ui.heading("Name");
+/// }, |ui| {
+///     ui.label("A longer description");
+///     ui.label("over two lines");
+/// });
+/// # });
+/// ```
+pub fn two_columns<A, B>(
+    ui: &mut Ui,
+    ratio: f32,
+    add_left: impl FnOnce(&mut Ui) -> A,
+    add_right: impl FnOnce(&mut Ui) -> B,
+) -> InnerResponse<(A, B)> {
+    RatioSplit::new(ratio)
+        .fit_contents(true)
+        .first_align(Align2::LEFT_CENTER)
+        .second_align(Align2::LEFT_CENTER)
+        .show(ui, add_left, add_right)
+}
@@ -0,0 +1,261 @@
+use egui::{pos2, Align, InnerResponse, Layout, Rect, Sense, Shape, Stroke, Ui, UiBuilder};
+
+/// The side of the spine on which a [`Timeline`] entry is shown.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimelineSide {
+    /// Left of the spine, aligned towards the spine (right-aligned).
+    Left,
+
+    /// Right of the spine, aligned towards the spine (left-aligned).
+    Right,
+}
+
+impl TimelineSide {
+    #[inline]
+    /// Get the opposite side.
+    pub fn flip(self) -> Self {
+        match self {
+            TimelineSide::Left => TimelineSide::Right,
+            TimelineSide::Right => TimelineSide::Left,
+        }
+    }
+}
+
+/// A container which shows its entries along a vertical spine,
+/// alternating between the left and the right side of the spine.
+/// Each entry is connected to the spine from its measured rect.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::Timeline;
+///
+/// # egui::__run_test_ui(|ui| {
+/// Timeline::new().show(ui, |timeline| {
+///     timeline.entry(|ui| ui.label("2022: first commit"));
+///     timeline.entry(|ui| ui.label("2023: first release"));
+///     timeline.entry(|ui| ui.label("2024: containers"));
+/// });
+/// # });
+/// ```
+pub struct Timeline {
+    /// The x offset of the spine, relative to the left of the available space.
+    /// If `None`, the spine is centered.
+    pub spine_x: Option<f32>,
+
+    /// The horizontal gap between the spine and the entries.
+    pub gap: f32,
+
+    /// The vertical spacing between entries.
+    /// If `None`, use the item spacing of the ui.
+    pub spacing: Option<f32>,
+
+    /// The stroke of the spine and the connectors.
+    /// If `None`, use the noninteractive stroke of the current visuals.
+    pub stroke: Option<Stroke>,
+
+    /// The radius of the dots drawn on the spine for each entry.
+    pub dot_radius: f32,
+
+    /// The side of the first entry.
+    /// Default: [`TimelineSide::Left`].
+    pub first_side: TimelineSide,
+}
+
+impl Timeline {
+    #[inline]
+    /// Create a new timeline with a centered spine.
+    pub fn new() -> Self {
+        Self {
+            spine_x: None,
+            gap: 16.0,
+            spacing: None,
+            stroke: None,
+            dot_radius: 4.0,
+            first_side: TimelineSide::Left,
+        }
+    }
+
+    #[inline]
+    /// Set the x offset of the spine, relative to the left of the available space.
+    pub fn spine_x(mut self, spine_x: f32) -> Self {
+        self.spine_x = Some(spine_x);
+        self
+    }
+
+    #[inline]
+    /// Set the horizontal gap between the spine and the entries.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    #[inline]
+    /// Set the vertical spacing between entries.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    #[inline]
+    /// Set the stroke of the spine and the connectors.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    #[inline]
+    /// Set the radius of the dots drawn on the spine.
+    pub fn dot_radius(mut self, dot_radius: f32) -> Self {
+        self.dot_radius = dot_radius;
+        self
+    }
+
+    #[inline]
+    /// Set the side of the first entry.
+    pub fn first_side(mut self, side: TimelineSide) -> Self {
+        self.first_side = side;
+        self
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The handle used to add entries to a [`Timeline`].
+pub struct TimelineUi<'a> {
+    ui: &'a mut Ui,
+    rect: Rect,
+    spine_x: f32,
+    gap: f32,
+    spacing: f32,
+    stroke: Stroke,
+    dot_radius: f32,
+    next_side: TimelineSide,
+    cursor_y: f32,
+    count: usize,
+}
+
+impl TimelineUi<'_> {
+    /// Add an entry on the next side, alternating after each entry.
+    pub fn entry<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let side = self.next_side;
+        self.entry_on(side, add_contents)
+    }
+
+    /// Add an entry on the given side.
+    /// The next entry added with [`TimelineUi::entry`] will be on the opposite side.
+    pub fn entry_on<R>(&mut self, side: TimelineSide, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        if self.cursor_y > self.rect.top() {
+            self.cursor_y += self.spacing;
+        }
+
+        let (side_rect, layout) = match side {
+            TimelineSide::Left => (
+                Rect::from_min_max(
+                    pos2(self.rect.left(), self.cursor_y),
+                    pos2((self.spine_x - self.gap).max(self.rect.left()), self.rect.bottom()),
+                ),
+                Layout::top_down(Align::Max),
+            ),
+            TimelineSide::Right => (
+                Rect::from_min_max(
+                    pos2((self.spine_x + self.gap).min(self.rect.right()), self.cursor_y),
+                    pos2(self.rect.right(), self.rect.bottom()),
+                ),
+                Layout::top_down(Align::Min),
+            ),
+        };
+
+        let index = self.count;
+        self.count += 1;
+        let mut entry_ui = self.ui.new_child(
+            UiBuilder::new()
+                .id_salt(("timeline_entry", index))
+                .max_rect(side_rect)
+                .layout(layout)
+        );
+        let inner = add_contents(&mut entry_ui);
+        let entry_rect = entry_ui.min_rect();
+
+        // connect the entry to the spine
+        let y = entry_rect.center().y;
+        let edge_x = match side {
+            TimelineSide::Left => entry_rect.right(),
+            TimelineSide::Right => entry_rect.left(),
+        };
+        let painter = self.ui.painter();
+        painter.line_segment([pos2(edge_x, y), pos2(self.spine_x, y)], self.stroke);
+        painter.circle_filled(pos2(self.spine_x, y), self.dot_radius, self.stroke.color);
+
+        self.cursor_y = entry_rect.bottom();
+        self.next_side = side.flip();
+
+        let response = self.ui.interact(entry_rect, entry_ui.id(), Sense::hover());
+        InnerResponse { inner, response }
+    }
+}
+
+impl Timeline {
+    /// Show the timeline in the given ui.
+    pub fn show<R>(&self, ui: &mut Ui, add_entries: impl FnOnce(&mut TimelineUi) -> R) -> InnerResponse<R> {
+        let rect = ui.available_rect_before_wrap();
+        let spine_x = match self.spine_x {
+            Some(x) => rect.left() + x,
+            None => rect.center().x,
+        };
+        let stroke = self.stroke.unwrap_or(ui.visuals().widgets.noninteractive.bg_stroke);
+
+        // reserve a shape for the spine so it is painted behind the dots
+        let spine_idx = ui.painter().add(Shape::Noop);
+
+        let mut timeline_ui = TimelineUi {
+            spine_x,
+            gap: self.gap,
+            spacing: self.spacing.unwrap_or(ui.spacing().item_spacing.y),
+            stroke,
+            dot_radius: self.dot_radius,
+            next_side: self.first_side,
+            cursor_y: rect.top(),
+            count: 0,
+            rect,
+            ui,
+        };
+        let inner = add_entries(&mut timeline_ui);
+        let bottom = timeline_ui.cursor_y;
+
+        ui.painter().set(
+            spine_idx,
+            Shape::line_segment([pos2(spine_x, rect.top()), pos2(spine_x, bottom)], stroke),
+        );
+
+        let response = ui.allocate_rect(
+            Rect::from_min_max(rect.min, pos2(rect.right(), bottom)),
+            Sense::hover(),
+        );
+
+        InnerResponse { inner, response }
+    }
+}
+
+#[inline]
+/// Create a new timeline with a centered spine
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::timeline;
+///
+/// # egui::__run_test_ui(|ui| {
+/// timeline(ui, |timeline| {
+///     timeline.entry(|ui| ui.label("Left entry"));
+///     timeline.entry(|ui| ui.label("Right entry"));
+/// });
+/// # });
+/// ```
+pub fn timeline<R>(ui: &mut Ui, add_entries: impl FnOnce(&mut TimelineUi) -> R) -> InnerResponse<R> {
+    Timeline::new().show(ui, add_entries)
+}
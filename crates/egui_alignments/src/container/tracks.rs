@@ -0,0 +1,96 @@
+use std::hash::Hash;
+
+use egui::{Context, Id, IdMap};
+
+/// Named column tracks shared by independent [`Row`](crate::Row)s,
+/// so ad-hoc rows line up like a table without a [`egui::Grid`].
+///
+/// The items of the rows are added with [`RowItems::track_item`](crate::RowItems::track_item),
+/// and each track is as wide as its widest item across all the rows, memorized from the previous pass.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{Row, Tracks};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let tracks = Tracks::new("settings");
+/// for (name, value) in [("Volume", "80%"), ("Notification sound", "Chime")] {
+///     Row::new(Align::Center).show_items(ui, |row| {
+///         row.track_item(tracks, "name", |ui| ui.label(name));
+///         row.track_item(tracks, "value", |ui| ui.label(value));
+///     });
+/// }
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tracks(pub Id);
+
+#[derive(Clone, Default)]
+struct TracksState {
+    pass_nr: u64,
+    widths: IdMap<f32>,
+    current: IdMap<f32>,
+}
+
+impl TracksState {
+    // the widths measured in a finished pass become the widths of the tracks
+    fn rotate(&mut self, pass_nr: u64) -> bool {
+        if self.pass_nr == pass_nr {
+            return false;
+        }
+
+        self.pass_nr = pass_nr;
+        let changed = self.current != self.widths;
+        self.widths = std::mem::take(&mut self.current);
+        changed
+    }
+}
+
+impl Tracks {
+    #[inline]
+    /// Create new tracks with the given id salt.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self(Id::new(id_salt))
+    }
+
+    fn track_id(&self, track: impl Hash) -> Id {
+        self.0.with(track)
+    }
+
+    fn with_state<R>(&self, ctx: &Context, f: impl FnOnce(&mut TracksState) -> R) -> R {
+        let pass_nr = ctx.cumulative_pass_nr();
+        let (changed, result) = ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<TracksState>(self.0);
+            let changed = state.rotate(pass_nr);
+            (changed, f(state))
+        });
+        if changed {
+            // a track shrank or was removed, show it again at its new width
+            ctx.request_repaint();
+        }
+        result
+    }
+
+    /// The width of a track, as memorized from the previous pass.
+    pub fn width(&self, ctx: &Context, track: impl Hash) -> Option<f32> {
+        let track = self.track_id(track);
+        self.with_state(ctx, |state| state.widths.get(&track).copied())
+    }
+
+    /// Record the natural width of an item of a track in this pass.
+    /// Called by [`RowItems::track_item`](crate::RowItems::track_item).
+    pub fn record(&self, ctx: &Context, track: impl Hash, width: f32) {
+        let track = self.track_id(track);
+        let grew = self.with_state(ctx, |state| {
+            let current = state.current.entry(track).or_insert(0.0);
+            *current = current.max(width);
+            !matches!(state.widths.get(&track), Some(memorized) if *memorized >= width)
+        });
+        if grew {
+            // the items of the track shown before were too narrow
+            crate::request_discard(ctx, "Tracks width");
+        }
+    }
+}
@@ -0,0 +1,358 @@
+use egui::{
+    emath::TSTransform, Align2, Area, Context, Id, InnerResponse, LayerId, Margin, Order, Painter, Pos2,
+    Rect, Response, Sense, Stroke, Ui, UiBuilder, Vec2,
+};
+
+use crate::{view_transform, zoom_around, ViewFit};
+
+#[derive(Copy, Clone, Debug)]
+struct ZoomPanState {
+    /// Transform from content space to the space relative to the bounds.
+    transform: TSTransform,
+    content_size: Vec2,
+    bounds_size: Vec2,
+    initialized: bool,
+}
+
+// the layer the contents of a zoom/pan container are shown on,
+// in the order of the layer of the container, as egui only moves sublayers within an order
+fn content_layer_id(id: Id, order: Order) -> LayerId {
+    LayerId::new(order, id.with("content"))
+}
+
+/// A container whose contents can be zoomed with the scroll wheel or pinch gestures
+/// and panned by dragging.
+/// The initial view is scaled by a [`ViewFit`] and placed by an aligner,
+/// and can be restored with [`ZoomPan::reset_view`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::{ViewFit, ZoomPan};
+///
+/// # egui::__run_test_ui(|ui| {
+/// ZoomPan::new("map")
+///     .initial_fit(ViewFit::FitWidth)
+///     .initial_align(Align2::CENTER_TOP)
+///     .show(ui, |ui| {
+///         ui.label("A very large diagram");
+///     });
+/// # });
+/// ```
+pub struct ZoomPan {
+    /// Used to memorize the view.
+    pub id: Id,
+
+    /// The scale of the initial view.
+    pub initial_fit: ViewFit,
+
+    /// The alignment of the initial view within the bounds.
+    pub initial_align: Align2,
+
+    /// The minimum zoom scale.
+    pub min_scale: f32,
+
+    /// The maximum zoom scale.
+    pub max_scale: f32,
+
+    /// If `true`, the scroll wheel zooms, otherwise it pans.
+    /// Pinch gestures and `Ctrl` + scroll always zoom.
+    pub scroll_zoom: bool,
+
+    /// The size of the bounds.
+    /// If `None`, use all the available space.
+    pub size: Option<Vec2>,
+}
+
+impl ZoomPan {
+    #[inline]
+    /// Create a new zoom/pan container whose initial view contains the whole content, centered.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            initial_fit: ViewFit::Contain,
+            initial_align: Align2::CENTER_CENTER,
+            min_scale: 0.05,
+            max_scale: 20.0,
+            scroll_zoom: true,
+            size: None,
+        }
+    }
+
+    #[inline]
+    /// Set the scale of the initial view.
+    pub fn initial_fit(mut self, fit: ViewFit) -> Self {
+        self.initial_fit = fit;
+        self
+    }
+
+    #[inline]
+    /// Set the alignment of the initial view within the bounds.
+    pub fn initial_align(mut self, align: Align2) -> Self {
+        self.initial_align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the range of the zoom scale.
+    pub fn scale_range(mut self, min_scale: f32, max_scale: f32) -> Self {
+        self.min_scale = min_scale;
+        self.max_scale = max_scale;
+        self
+    }
+
+    #[inline]
+    /// Set whether the scroll wheel zooms instead of panning.
+    pub fn scroll_zoom(mut self, scroll_zoom: bool) -> Self {
+        self.scroll_zoom = scroll_zoom;
+        self
+    }
+
+    #[inline]
+    /// Set the size of the bounds.
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl ZoomPan {
+    /// Restore the initial view of the container with the given id on the next frame.
+    pub fn reset_view(ctx: &Context, id: Id) {
+        ctx.data_mut(|data| {
+            if let Some(mut state) = data.get_temp::<ZoomPanState>(id) {
+                state.initialized = false;
+                data.insert_temp(id, state);
+            }
+        });
+    }
+
+    /// Pan the view of the container with the given id on the next frame,
+    /// so the given point of the content is at the center of the bounds.
+    pub fn center_on(ctx: &Context, id: Id, pos: Pos2) {
+        ctx.data_mut(|data| {
+            if let Some(mut state) = data.get_temp::<ZoomPanState>(id) {
+                state.transform.translation = state.bounds_size / 2.0 - pos.to_vec2() * state.transform.scaling;
+                data.insert_temp(id, state);
+            }
+        });
+    }
+
+    /// Get the current transform from content space to screen space of the container with the given id.
+    pub fn transform(ctx: &Context, id: Id) -> Option<TSTransform> {
+        ctx.data(|data| data.get_temp::<ZoomPanState>(id))
+            .map(|state| state.transform)
+    }
+
+    /// Show the contents in the given ui.
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let size = self.size
+            .unwrap_or(Vec2::INFINITY)
+            .min(ui.available_size());
+        let (bounds, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+
+        let mut state = ui.ctx().data(|data| data.get_temp::<ZoomPanState>(self.id));
+
+        // align the initial view once the content size is known
+        if let Some(state) = state.as_mut().filter(|state| !state.initialized) {
+            let bounds_rect = Rect::from_min_size(Pos2::ZERO, bounds.size());
+            state.transform = view_transform(state.content_size, bounds_rect, self.initial_fit, self.initial_align);
+            state.transform.scaling = state.transform.scaling.clamp(self.min_scale, self.max_scale);
+            state.initialized = true;
+        }
+
+        let mut transform = state.map_or(TSTransform::IDENTITY, |state| state.transform);
+
+        if response.dragged() {
+            transform.translation += response.drag_delta();
+        }
+
+        if response.hovered() {
+            let (zoom_delta, scroll_delta, pointer) = ui.ctx().input(|input| {
+                (input.zoom_delta(), input.smooth_scroll_delta, input.pointer.hover_pos())
+            });
+
+            let mut zoom = zoom_delta;
+            if self.scroll_zoom {
+                zoom *= (scroll_delta.y / 200.0).exp();
+            } else {
+                transform.translation += scroll_delta;
+            }
+
+            if zoom != 1.0 {
+                // a zoom past the scale range stops at its limit
+                let min_zoom = self.min_scale / transform.scaling;
+                let zoom = zoom.clamp(min_zoom, (self.max_scale / transform.scaling).max(min_zoom));
+                let pointer = pointer.unwrap_or(bounds.center()) - bounds.min.to_vec2();
+                transform = zoom_around(transform, zoom, pointer);
+            }
+        }
+
+        // show the contents in content space on a transformed layer above the ui
+        let screen_transform = TSTransform::from_translation(bounds.min.to_vec2()) * transform;
+        let content_layer_id = content_layer_id(self.id, ui.layer_id().order);
+        let area_response = Area::new(content_layer_id.id)
+            .order(content_layer_id.order)
+            .fixed_pos(Pos2::ZERO)
+            .constrain(false)
+            .interactable(true)
+            .show(ui.ctx(), |area_ui| {
+                area_ui.set_clip_rect(screen_transform.inverse() * bounds.intersect(ui.clip_rect()));
+                let mut content_ui = area_ui.new_child({
+                    let builder = UiBuilder::new()
+                        .max_rect(Rect::from_min_size(Pos2::ZERO, Vec2::INFINITY))
+                        .layout(*ui.layout());
+
+                    if state.is_some() {
+                        builder
+                    } else {
+                        // no content size memorized, measure it before showing the initial view
+                        crate::request_discard(ui.ctx(), "new ZoomPan");
+                        builder.sizing_pass().invisible()
+                    }
+                });
+                let inner = add_contents(&mut content_ui);
+                (inner, content_ui.min_rect().size())
+            });
+        let layer_id = area_response.response.layer_id;
+        ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+        ui.ctx().set_transform_layer(layer_id, screen_transform);
+
+        let (inner, content_size) = area_response.inner;
+        let initialized = state.is_some_and(|state| state.initialized);
+        ui.ctx().data_mut(|data| {
+            data.insert_temp(self.id, ZoomPanState {
+                transform,
+                content_size,
+                bounds_size: bounds.size(),
+                initialized,
+            })
+        });
+
+        InnerResponse { inner, response }
+    }
+}
+
+/// An overview of the whole content of a [`ZoomPan`], with the rect of the current view,
+/// aligned to a corner of the container and shown above its contents.
+/// Clicking or dragging on the map centers the view on that point.
+///
+/// The content itself is not drawn on the map, paint a simplified version with [`MiniMap::show_with`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::{MiniMap, ZoomPan};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let zoom_pan = ZoomPan::new("diagram");
+/// let response = zoom_pan.show(ui, |ui| {
+///     ui.label("A very large diagram");
+/// }).response;
+/// MiniMap::new(zoom_pan.id)
+///     .align(Align2::RIGHT_BOTTOM)
+///     .show(&response);
+/// # });
+/// ```
+pub struct MiniMap {
+    /// The id of the [`ZoomPan`] container.
+    pub zoom_pan_id: Id,
+
+    /// The alignment of the map within the container.
+    pub align: Align2,
+
+    /// The space between the map and the edges of the container.
+    pub margin: Margin,
+
+    /// The maximum size of the map, which keeps the aspect ratio of the content.
+    pub max_size: Vec2,
+}
+
+impl MiniMap {
+    #[inline]
+    /// Create a new map of the [`ZoomPan`] container with the given id, at its bottom right.
+    pub fn new(zoom_pan_id: Id) -> Self {
+        Self {
+            zoom_pan_id,
+            align: Align2::RIGHT_BOTTOM,
+            margin: Margin::from(8.0),
+            max_size: Vec2::new(160.0, 120.0),
+        }
+    }
+
+    #[inline]
+    /// Set the alignment of the map within the container.
+    pub fn align(mut self, align: Align2) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the space between the map and the edges of the container.
+    pub fn margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the maximum size of the map.
+    pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
+        self.max_size = max_size.into();
+        self
+    }
+}
+
+impl MiniMap {
+    /// Show the map within the rect of the response of the [`ZoomPan`] container.
+    ///
+    /// Returns `None` until the container has been shown once.
+    #[inline]
+    pub fn show(&self, zoom_pan: &Response) -> Option<Response> {
+        self.show_with(zoom_pan, |_, _| {})
+    }
+
+    /// Show the map within the rect of the response of the [`ZoomPan`] container,
+    /// painting the content with the given function, using the transform from content space to the map.
+    ///
+    /// Returns `None` until the container has been shown once.
+    pub fn show_with(&self, zoom_pan: &Response, paint_contents: impl FnOnce(&Painter, TSTransform)) -> Option<Response> {
+        let ctx = &zoom_pan.ctx;
+        let state = ctx.data(|data| data.get_temp::<ZoomPanState>(self.zoom_pan_id))?;
+
+        let scale = ViewFit::Contain.scale(state.content_size, self.max_size);
+        let map_size = (state.content_size * scale).max(Vec2::splat(1.0));
+        let map_rect = self.align.align_size_within_rect(map_size, zoom_pan.rect - self.margin);
+        let map_transform = TSTransform::new(map_rect.min.to_vec2(), scale);
+        let view = state.transform.inverse() * Rect::from_min_size(Pos2::ZERO, state.bounds_size);
+
+        let area_response = Area::new(self.zoom_pan_id.with("mini_map"))
+            .order(zoom_pan.layer_id.order)
+            .fixed_pos(map_rect.min)
+            .default_size(map_size)
+            .constrain(false)
+            .interactable(true)
+            .show(ctx, |ui| {
+                let response = ui.allocate_rect(map_rect, Sense::click_and_drag());
+                let visuals = ui.visuals();
+                let painter = ui.painter_at(map_rect);
+                painter.rect_filled(map_rect, 0.0, visuals.extreme_bg_color);
+                paint_contents(&painter, map_transform);
+                crate::compat::rect_stroke(&painter, map_transform * view, 0.0, visuals.selection.stroke);
+                crate::compat::rect_stroke(ui.painter(), map_rect, 0.0, Stroke::new(1.0, visuals.weak_text_color()));
+
+                if let Some(pos) = response.interact_pointer_pos() {
+                    if response.is_pointer_button_down_on() {
+                        ZoomPan::center_on(ui.ctx(), self.zoom_pan_id, map_transform.inverse() * pos);
+                        ui.ctx().request_repaint();
+                    }
+                }
+                response
+            });
+
+        // above the contents of the container, which are brought to the top when clicked
+        ctx.set_sublayer(content_layer_id(self.zoom_pan_id, zoom_pan.layer_id.order), area_response.response.layer_id);
+        Some(area_response.inner)
+    }
+}
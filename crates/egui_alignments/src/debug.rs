@@ -0,0 +1,450 @@
+//! Debugging tools for the layout
+//!
+//! The aligners and containers of this crate can record the tree they form in a frame,
+//! with their ids, bounds, content rects and memorized sizes.
+//!
+//! # Example
+//! ```
+//! # use egui_alignments::egui;
+//! use egui::Align;
+//! use egui_alignments::{column, debug, row};
+//!
+//! # let ctx = egui::Context::default();
+//! debug::record_layout(&ctx);
+//! # let _ = ctx.run(Default::default(), |ctx| {
+//! egui::CentralPanel::default().show(ctx, |ui| {
+//!     column(ui, Align::Center, |ui| {
+//!         row(ui, Align::Center, |ui| {
+//!             ui.label("left");
+//!             ui.label("right");
+//!         });
+//!     });
+//! });
+//! # });
+//! # let _ = ctx.run(Default::default(), |_| {});
+//!
+//! // once the frame is over
+//! let tree = debug::recorded_layout(&ctx).expect("recorded");
+//! assert_eq!(tree.roots().count(), 1);
+//! assert_eq!(tree.nodes[0].children.len(), 1);
+//! // the sizing pass of the new containers is discarded, the drawn layout is recorded
+//! assert!(!tree.nodes[0].sizing_pass);
+//! let json = tree.to_json();
+//! # assert!(json.contains("\"kind\":\"GenericContainer\""));
+//! ```
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use egui::{Color32, Context, Id, Rect, Stroke, Ui, Vec2};
+
+use crate::size_cache;
+
+/// An aligner or a container recorded in a [`LayoutTree`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutNode {
+    /// The id used to memorize the size of the contents.
+    pub id: Id,
+
+    /// The type of the aligner or container, e.g. `"WidgetAligner"`.
+    pub kind: &'static str,
+
+    /// The index of the parent node in [`LayoutTree::nodes`].
+    pub parent: Option<usize>,
+
+    /// The indices of the child nodes in [`LayoutTree::nodes`].
+    pub children: Vec<usize>,
+
+    /// The bounds the contents were laid out in.
+    pub bounds: Rect,
+
+    /// The rect taken by the contents.
+    pub content_rect: Rect,
+
+    /// The size memorized from the previous frame, if any.
+    pub cached_size: Option<Vec2>,
+
+    /// If the contents were shown in a sizing pass.
+    pub sizing_pass: bool,
+}
+
+/// The aligners and containers shown in a frame, in the order they were shown.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayoutTree {
+    /// The pass the tree was recorded in. See [`Context::cumulative_pass_nr`].
+    pub pass_nr: u64,
+
+    /// All the nodes, parents before their children.
+    pub nodes: Vec<LayoutNode>,
+}
+
+impl LayoutTree {
+    /// The nodes without a parent.
+    pub fn roots(&self) -> impl Iterator<Item = (usize, &LayoutNode)> {
+        self.nodes.iter()
+            .enumerate()
+            .filter(|(_, node)| node.parent.is_none())
+    }
+
+    /// Serialize the tree to JSON, with the children nested in their parents.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        let _ = write!(json, "{{\"pass_nr\":{},\"roots\":[", self.pass_nr);
+        for (i, (index, _)) in self.roots().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            self.write_node_json(&mut json, index);
+        }
+        json.push_str("]}");
+        json
+    }
+
+    fn write_node_json(&self, json: &mut String, index: usize) {
+        let node = &self.nodes[index];
+        let _ = write!(
+            json,
+            "{{\"id\":\"{:016x}\",\"kind\":\"{}\",\"bounds\":{},\"content_rect\":{},\"cached_size\":",
+            node.id.value(),
+            node.kind,
+            rect_json(node.bounds),
+            rect_json(node.content_rect),
+        );
+        match node.cached_size {
+            Some(size) => {
+                let _ = write!(json, "[{},{}]", number_json(size.x), number_json(size.y));
+            },
+            None => json.push_str("null"),
+        }
+        let _ = write!(json, ",\"sizing_pass\":{},\"children\":[", node.sizing_pass);
+        for (i, &child) in node.children.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            self.write_node_json(json, child);
+        }
+        json.push_str("]}");
+    }
+}
+
+// `[min.x, min.y, max.x, max.y]`
+fn rect_json(rect: Rect) -> String {
+    format!(
+        "[{},{},{},{}]",
+        number_json(rect.min.x),
+        number_json(rect.min.y),
+        number_json(rect.max.x),
+        number_json(rect.max.y),
+    )
+}
+
+// JSON has no infinity or NaN
+fn number_json(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_owned()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum RecordMode {
+    #[default]
+    Off,
+    Once,
+    Continuous,
+}
+
+#[derive(Clone, Debug, Default)]
+struct LayoutRecorder {
+    mode: RecordMode,
+    current: Option<LayoutTree>,
+    stack: Vec<usize>,
+    last: Option<LayoutTree>,
+    keep_alive_pass_nr: u64,
+    // the pass of the current tree is discarded, so the tree is not the drawn layout
+    discarded: bool,
+    end_pass_registered: bool,
+}
+
+impl LayoutRecorder {
+    // move the tree of a finished pass to `last`, unless the pass was discarded
+    fn rotate(&mut self, pass_nr: u64) {
+        if self.current.as_ref().is_some_and(|tree| tree.pass_nr != pass_nr) {
+            let tree = self.current.take();
+            self.stack.clear();
+            if std::mem::take(&mut self.discarded) {
+                // keep recording the pass run after it in the same frame
+                return;
+            }
+            self.last = tree;
+            let stopped = match self.mode {
+                RecordMode::Once => true,
+                RecordMode::Continuous => self.keep_alive_pass_nr + 1 < pass_nr,
+                RecordMode::Off => false,
+            };
+            if stopped {
+                self.mode = RecordMode::Off;
+            }
+        }
+    }
+}
+
+fn recorder_id() -> Id {
+    Id::new("egui_alignments::debug::LayoutRecorder")
+}
+
+// mark the tree of a discarded pass at the end of the pass
+fn register_end_pass(ctx: &Context) {
+    let registered = ctx.data_mut(|data| {
+        let recorder = data.get_temp_mut_or_default::<LayoutRecorder>(recorder_id());
+        std::mem::replace(&mut recorder.end_pass_registered, true)
+    });
+    if !registered {
+        ctx.on_end_pass("egui_alignments::debug", Arc::new(end_pass));
+    }
+}
+
+fn end_pass(ctx: &Context) {
+    if !size_cache::flags(ctx).recording {
+        return;
+    }
+
+    let pass_nr = ctx.cumulative_pass_nr();
+    let discarded = ctx.will_discard();
+    ctx.data_mut(|data| {
+        let recorder = data.get_temp_mut_or_default::<LayoutRecorder>(recorder_id());
+        if recorder.current.as_ref().is_some_and(|tree| tree.pass_nr == pass_nr) {
+            recorder.discarded = discarded;
+        }
+    });
+}
+
+/// Record the layout tree of the next frame, as drawn:
+/// the passes the frame discards, e.g. for the sizing passes, are skipped.
+/// Get it with [`recorded_layout`] once the frame is over.
+pub fn record_layout(ctx: &Context) {
+    ctx.data_mut(|data| {
+        let recorder = data.get_temp_mut_or_default::<LayoutRecorder>(recorder_id());
+        recorder.mode = RecordMode::Once;
+        recorder.current = None;
+        recorder.discarded = false;
+        recorder.stack.clear();
+    });
+    // set while the context records its layout, to skip the memory lock otherwise
+    size_cache::set_flag(ctx, |flags| &mut flags.recording, true);
+    register_end_pass(ctx);
+}
+
+/// Get the last layout tree recorded after [`record_layout`], if any.
+pub fn recorded_layout(ctx: &Context) -> Option<LayoutTree> {
+    let pass_nr = ctx.cumulative_pass_nr();
+    ctx.data_mut(|data| {
+        let recorder = data.get_temp_mut_or_default::<LayoutRecorder>(recorder_id());
+        recorder.rotate(pass_nr);
+        recorder.last.clone()
+    })
+}
+
+// start recording a node if the layout is being recorded, returning its index
+pub(crate) fn begin_node(ctx: &Context, kind: &'static str, id: Id) -> Option<usize> {
+    if !size_cache::flags(ctx).recording {
+        return None;
+    }
+
+    let pass_nr = ctx.cumulative_pass_nr();
+    let index = ctx.data_mut(|data| {
+        let recorder = data.get_temp_mut_or_default::<LayoutRecorder>(recorder_id());
+        recorder.rotate(pass_nr);
+        if recorder.mode == RecordMode::Off {
+            return None;
+        }
+
+        let parent = recorder.stack.last().copied();
+        let tree = recorder.current.get_or_insert_with(|| LayoutTree {
+            pass_nr,
+            nodes: Vec::new(),
+        });
+        let index = tree.nodes.len();
+        tree.nodes.push(LayoutNode {
+            id,
+            kind,
+            parent,
+            children: Vec::new(),
+            bounds: Rect::NOTHING,
+            content_rect: Rect::NOTHING,
+            cached_size: None,
+            sizing_pass: false,
+        });
+        if let Some(parent) = parent {
+            tree.nodes[parent].children.push(index);
+        }
+        recorder.stack.push(index);
+        Some(index)
+    });
+    if index.is_none() {
+        // the recording stopped
+        size_cache::set_flag(ctx, |flags| &mut flags.recording, false);
+    }
+    index
+}
+
+// finish recording the node returned by `begin_node`
+pub(crate) fn end_node(
+    ctx: &Context,
+    index: Option<usize>,
+    bounds: Rect,
+    content_rect: Rect,
+    cached_size: Option<Vec2>,
+    sizing_pass: bool,
+) {
+    let Some(index) = index else {
+        return;
+    };
+
+    ctx.data_mut(|data| {
+        let recorder = data.get_temp_mut_or_default::<LayoutRecorder>(recorder_id());
+        let Some(node) = recorder.current.as_mut().and_then(|tree| tree.nodes.get_mut(index)) else {
+            return;
+        };
+
+        node.bounds = bounds;
+        node.content_rect = content_rect;
+        node.cached_size = cached_size;
+        node.sizing_pass = sizing_pass;
+        recorder.stack.retain(|&i| i < index);
+    });
+}
+
+#[derive(Clone, Debug, Default)]
+struct InspectorState {
+    // roots of the subtrees with debug paint
+    painted: Vec<Id>,
+}
+
+fn inspector_id() -> Id {
+    Id::new("egui_alignments::debug::inspector")
+}
+
+/// Show a window with the tree of the aligners and containers of the last frame.
+///
+/// Hovering a node highlights its bounds and content rect,
+/// and its buttons clear the memorized size or toggle the debug paint of the subtree.
+/// The layout is recorded as long as the inspector is shown every frame.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::debug;
+///
+/// # egui::__run_test_ui(|ui| {
+/// if cfg!(debug_assertions) {
+///     debug::inspector(ui.ctx());
+/// }
+/// # });
+/// ```
+pub fn inspector(ctx: &Context) {
+    let pass_nr = ctx.cumulative_pass_nr();
+    let tree = ctx.data_mut(|data| {
+        let recorder = data.get_temp_mut_or_default::<LayoutRecorder>(recorder_id());
+        recorder.rotate(pass_nr);
+        recorder.mode = RecordMode::Continuous;
+        recorder.keep_alive_pass_nr = pass_nr;
+        recorder.last.clone()
+    });
+    size_cache::set_flag(ctx, |flags| &mut flags.recording, true);
+    register_end_pass(ctx);
+    let mut state = ctx.data(|data| data.get_temp::<InspectorState>(inspector_id()))
+        .unwrap_or_default();
+
+    egui::Window::new("Layout inspector")
+        .default_size((320.0, 400.0))
+        .show(ctx, |ui| {
+            let Some(tree) = &tree else {
+                ui.weak("Recording the next frame...");
+                return;
+            };
+
+            ui.label(format!("{} nodes in pass {}", tree.nodes.len(), tree.pass_nr));
+            ui.separator();
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for (index, _) in tree.roots() {
+                    node_ui(ui, tree, index, &mut state);
+                }
+            });
+        });
+
+    if let Some(tree) = &tree {
+        for (index, node) in tree.nodes.iter().enumerate() {
+            if state.painted.contains(&node.id) {
+                paint_subtree(ctx, tree, index);
+            }
+        }
+    }
+
+    // keep the subtrees which are still shown
+    if let Some(tree) = &tree {
+        state.painted.retain(|id| tree.nodes.iter().any(|node| node.id == *id));
+    }
+    ctx.data_mut(|data| data.insert_temp(inspector_id(), state));
+}
+
+fn node_ui(ui: &mut Ui, tree: &LayoutTree, index: usize, state: &mut InspectorState) {
+    let node = &tree.nodes[index];
+    let title = format!("{} {}", node.kind, node.id.short_debug_format());
+
+    let header = egui::CollapsingHeader::new(title)
+        .id_salt((node.id, index))
+        .default_open(node.parent.is_none())
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                if ui.small_button("Clear cache").clicked() {
+                    crate::size_cache::remove(ui.ctx(), node.id);
+                    ui.ctx().request_repaint();
+                }
+
+                let mut painted = state.painted.contains(&node.id);
+                if ui.checkbox(&mut painted, "Debug paint").changed() {
+                    if painted {
+                        state.painted.push(node.id);
+                    } else {
+                        state.painted.retain(|id| *id != node.id);
+                    }
+                }
+            });
+
+            ui.weak(format!("bounds: {:?}", node.bounds));
+            ui.weak(format!("content: {:?}", node.content_rect));
+            match node.cached_size {
+                Some(size) => ui.weak(format!("cached size: {:?}", size)),
+                None => ui.weak("cached size: none"),
+            };
+            if node.sizing_pass {
+                ui.weak("sizing pass");
+            }
+
+            for &child in &node.children {
+                node_ui(ui, tree, child, state);
+            }
+        });
+
+    if header.header_response.hovered() {
+        highlight(ui.ctx(), node);
+    }
+}
+
+fn highlight(ctx: &Context, node: &LayoutNode) {
+    let painter = ctx.debug_painter();
+    crate::compat::rect_stroke(&painter, node.bounds, 0.0, Stroke::new(1.0, Color32::LIGHT_BLUE));
+    painter.rect_filled(node.content_rect, 0.0, Color32::BLUE.gamma_multiply(0.2));
+}
+
+fn paint_subtree(ctx: &Context, tree: &LayoutTree, index: usize) {
+    let node = &tree.nodes[index];
+    let painter = ctx.debug_painter();
+    crate::compat::rect_stroke(&painter, node.bounds, 0.0, Stroke::new(1.0, Color32::GREEN.gamma_multiply(0.5)));
+    crate::compat::rect_stroke(&painter, node.content_rect, 0.0, Stroke::new(1.0, Color32::RED.gamma_multiply(0.5)));
+    for &child in &node.children {
+        paint_subtree(ctx, tree, child);
+    }
+}
@@ -0,0 +1,127 @@
+//! Progress bars along the edges of a rect
+
+use egui::{Color32, Rect, Ui};
+
+use crate::Edge;
+
+/// A thin progress bar painted along an edge of a rect, inside it,
+/// e.g. the upload progress at the bottom of a card.
+///
+/// The bar fills from the left of a horizontal edge and from the top of a vertical edge,
+/// or from the other end if reversed.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{Edge, EdgeProgress};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let card = ui.group(|ui| {
+///     ui.label("holiday.mp4");
+///     ui.weak("Uploading…");
+/// }).response;
+/// EdgeProgress::new(Edge::Bottom)
+///     .thickness(2.0)
+///     .paint(ui, card.rect, 0.4);
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EdgeProgress {
+    /// The edge the bar is painted along.
+    pub edge: Edge,
+
+    /// The thickness of the bar.
+    pub thickness: f32,
+
+    /// The color of the filled part of the bar.
+    /// If `None`, use the selection color of the visuals.
+    pub fill: Option<Color32>,
+
+    /// The color of the rest of the bar, if any.
+    pub track: Option<Color32>,
+
+    /// If `true`, fill the bar from the right or the bottom.
+    pub reverse: bool,
+}
+
+impl EdgeProgress {
+    #[inline]
+    /// Create a new progress bar along the given edge.
+    pub fn new(edge: Edge) -> Self {
+        Self {
+            edge,
+            thickness: 3.0,
+            fill: None,
+            track: None,
+            reverse: false,
+        }
+    }
+
+    #[inline]
+    /// Set the thickness of the bar.
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    #[inline]
+    /// Set the color of the filled part of the bar.
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    #[inline]
+    /// Paint the rest of the bar with the given color.
+    pub fn track(mut self, track: Color32) -> Self {
+        self.track = Some(track);
+        self
+    }
+
+    #[inline]
+    /// Fill the bar from the right or the bottom.
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Paint the bar along the edge of the rect, filled up to the given fraction, between `0.0` and `1.0`.
+    ///
+    /// The rect is usually the final rect of a container, e.g. the rect of its response.
+    pub fn paint(&self, ui: &Ui, rect: Rect, fraction: f32) {
+        let fraction = if fraction.is_nan() { 0.0 } else { fraction.clamp(0.0, 1.0) };
+        let (bar, _) = self.edge.split_rect(rect, self.thickness.min(rect.width()).min(rect.height()), 0.0);
+
+        let mut filled = bar;
+        match (self.edge.is_vertical(), self.reverse) {
+            (true, false) => filled.max.y = bar.min.y + bar.height() * fraction,
+            (true, true) => filled.min.y = bar.max.y - bar.height() * fraction,
+            (false, false) => filled.max.x = bar.min.x + bar.width() * fraction,
+            (false, true) => filled.min.x = bar.max.x - bar.width() * fraction,
+        }
+
+        let painter = ui.painter_at(rect);
+        if let Some(track) = self.track {
+            painter.rect_filled(bar, 0.0, track);
+        }
+        painter.rect_filled(filled, 0.0, self.fill.unwrap_or(ui.visuals().selection.bg_fill));
+    }
+}
+
+#[inline]
+/// Paint a thin progress bar along an edge of a rect, filled up to the given fraction.
+/// See [`EdgeProgress`].
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::{edge_progress, Edge};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.button("Download");
+/// edge_progress(ui, response.rect, Edge::Bottom, 0.75);
+/// # });
+/// ```
+pub fn edge_progress(ui: &Ui, rect: Rect, edge: Edge, fraction: f32) {
+    EdgeProgress::new(edge).paint(ui, rect, fraction);
+}
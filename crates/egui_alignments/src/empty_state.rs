@@ -0,0 +1,144 @@
+//! Placeholders for empty lists and tables
+
+use egui::{Align, Label, Layout, Response, RichText, Ui};
+
+use crate::{AlignedResponse, WidgetAligner};
+
+/// A standard stack of an icon, a title, a subtitle and an action button,
+/// centered in the available space, e.g. for a list with no items yet.
+///
+/// The stack is no wider than the maximum width, the subtitle wraps within it.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::EmptyState;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = EmptyState::new("No projects yet")
+///     .icon("📁")
+///     .subtitle("Projects you create or are invited to will show up here.")
+///     .action("New project")
+///     .show(ui);
+/// if response.inner.is_some_and(|action| action.clicked()) {
+///     // create a project
+/// }
+/// # });
+/// ```
+pub struct EmptyState {
+    /// The icon above the title, usually an emoji.
+    pub icon: Option<String>,
+
+    /// The title.
+    pub title: String,
+
+    /// The text below the title.
+    pub subtitle: Option<String>,
+
+    /// The label of the action button.
+    /// If `None`, no button is shown.
+    pub action: Option<String>,
+
+    /// The maximum width of the stack.
+    pub max_width: f32,
+}
+
+impl EmptyState {
+    #[inline]
+    /// Create a new empty state with only a title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            icon: None,
+            title: title.into(),
+            subtitle: None,
+            action: None,
+            max_width: 320.0,
+        }
+    }
+
+    #[inline]
+    /// Set the icon above the title.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    #[inline]
+    /// Set the text below the title.
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    #[inline]
+    /// Set the label of the action button.
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    #[inline]
+    /// Set the maximum width of the stack.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+}
+
+impl EmptyState {
+    /// Show the stack centered in the available space.
+    ///
+    /// The inner value is the response of the action button, if any.
+    pub fn show(&self, ui: &mut Ui) -> AlignedResponse<Option<Response>> {
+        WidgetAligner::center()
+            .layout(Layout::top_down(Align::Center))
+            .show(ui, |ui| {
+                ui.set_max_width(self.max_width);
+
+                if let Some(icon) = &self.icon {
+                    ui.label(RichText::new(icon).size(48.0));
+                }
+                ui.heading(&self.title);
+                if let Some(subtitle) = &self.subtitle {
+                    ui.add(Label::new(RichText::new(subtitle).weak()).wrap());
+                }
+                self.action.as_ref().map(|action| {
+                    ui.add_space(ui.spacing().item_spacing.y);
+                    ui.button(action)
+                })
+            })
+    }
+}
+
+#[inline]
+/// Show a standard "nothing here" placeholder centered in the available space
+///
+/// Returns `true` if the action button was clicked.
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::empty_state;
+///
+/// # egui::__run_test_ui(|ui| {
+/// if empty_state(ui, "🔍", "No results", "Try another search.", Some("Clear filters")) {
+///     // clear the filters
+/// }
+/// # });
+/// ```
+pub fn empty_state(
+    ui: &mut Ui,
+    icon: impl Into<String>,
+    title: impl Into<String>,
+    subtitle: impl Into<String>,
+    action: Option<&str>,
+) -> bool {
+    let mut empty_state = EmptyState::new(title)
+        .icon(icon)
+        .subtitle(subtitle);
+    empty_state.action = action.map(str::to_owned);
+
+    empty_state.show(ui)
+        .inner
+        .is_some_and(|action| action.clicked())
+}
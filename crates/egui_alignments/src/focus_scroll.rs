@@ -0,0 +1,123 @@
+use egui::{scroll_area::ScrollAreaOutput, Align, Context, Id, ScrollArea, Ui};
+
+/// Scrolls a [`ScrollArea`] so the widget the keyboard focus lands on is visible,
+/// however deep in the containers of the contents it is.
+///
+/// Each scroll area between the widget and the screen has to be shown with a `FocusScroll`,
+/// as a scroll area only scrolls its own contents. The nested ones settle over a few passes,
+/// while the widget is scrolled into view for [`FocusScroll::duration`] after the focus landed on it.
+/// The widgets focused by a click are left in place.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, ScrollArea};
+/// use egui_alignments::{Column, FocusScroll};
+///
+/// # egui::__run_test_ui(|ui| {
+/// FocusScroll::new().show(ui, ScrollArea::vertical().id_salt("settings"), |ui| {
+///     for section in ["General", "Network"] {
+///         ui.heading(section);
+///         FocusScroll::new().show(ui, ScrollArea::vertical().id_salt(section).max_height(120.0), |ui| {
+///             for i in 0..20 {
+///                 Column::new(Align::Min).show(ui, |ui| {
+///                     ui.checkbox(&mut false, format!("Option {}", i));
+///                 });
+///             }
+///         });
+///     }
+/// });
+/// # });
+/// ```
+pub struct FocusScroll {
+    /// The alignment of the focused widget in the viewport.
+    /// If `None`, scroll just enough for it to be visible.
+    pub align: Option<Align>,
+
+    /// How long the focused widget is scrolled into view after the focus landed on it, in seconds.
+    pub duration: f32,
+}
+
+// the widget the focus last landed on
+#[derive(Clone, Copy, Default)]
+struct Landing {
+    id: Option<Id>,
+    time: f64,
+    by_pointer: bool,
+}
+
+fn landing_id() -> Id {
+    Id::new("egui_alignments::focus_scroll")
+}
+
+impl Default for FocusScroll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FocusScroll {
+    #[inline]
+    /// Create a new focus scroll, centering the focused widget in the viewport.
+    pub fn new() -> Self {
+        Self {
+            align: Some(Align::Center),
+            duration: 1.0,
+        }
+    }
+
+    #[inline]
+    /// Set the alignment of the focused widget in the viewport,
+    /// `None` to scroll just enough for it to be visible.
+    pub fn align(mut self, align: Option<Align>) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    /// Set how long the focused widget is scrolled into view after the focus landed on it, in seconds.
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    // the focused widget, if the focus landed on it recently with the keyboard
+    fn landed(&self, ctx: &Context) -> Option<Id> {
+        let focused = ctx.memory(|memory| memory.focused());
+        let (time, pointer) = ctx.input(|input| (input.time, input.pointer.any_pressed() || input.pointer.any_released()));
+        let landing = ctx.data_mut(|data| {
+            let landing = data.get_temp_mut_or_default::<Landing>(landing_id());
+            if landing.id != focused {
+                *landing = Landing { id: focused, time, by_pointer: pointer };
+            }
+            *landing
+        });
+        focused.filter(|_| !landing.by_pointer && time - landing.time < self.duration as f64)
+    }
+
+    /// Show the scroll area, scrolling the widget the focus lands on into view if it is in the contents.
+    pub fn show<R>(
+        &self,
+        ui: &mut Ui,
+        scroll_area: ScrollArea,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> ScrollAreaOutput<R> {
+        scroll_area.show(ui, |ui| {
+            let inner = add_contents(ui);
+            if ui.is_sizing_pass() {
+                return inner;
+            }
+
+            // the scroll area takes the target of its contents once they are shown,
+            // after the nested scroll areas took theirs
+            let focused = self.landed(ui.ctx()).and_then(|id| ui.ctx().read_response(id));
+            if let Some(focused) = focused {
+                if focused.layer_id == ui.layer_id() && ui.min_rect().contains_rect(focused.rect) {
+                    ui.scroll_to_rect(focused.rect, self.align);
+                    ui.ctx().request_repaint();
+                }
+            }
+            inner
+        })
+    }
+}
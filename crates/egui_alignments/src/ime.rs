@@ -0,0 +1,47 @@
+// Keeping the IME output of the contents right during sizing passes.
+//
+// A focused `TextEdit` reports where the IME candidate window should be shown,
+// but in a sizing pass the contents are not at their final place yet.
+// The IME output of the last pass which was not a sizing pass is remembered per container,
+// and emitted again instead of the one reported in a sizing pass.
+
+use egui::{output::IMEOutput, Context, Id};
+
+pub(crate) struct ImeScope {
+    // the IME output reported before the contents
+    before: Option<IMEOutput>,
+}
+
+/// Start watching the IME output reported by the contents.
+/// `None` if no widget has the focus, so no IME output can be reported.
+pub(crate) fn begin(ctx: &Context) -> Option<ImeScope> {
+    if ctx.memory(|memory| memory.focused().is_none()) {
+        return None;
+    }
+
+    Some(ImeScope {
+        before: ctx.output(|output| output.ime),
+    })
+}
+
+/// Remember the IME output reported by the contents of the container,
+/// or replace it by the remembered one in a sizing pass.
+pub(crate) fn end(ctx: &Context, scope: Option<ImeScope>, id: Id, sizing_pass: bool) {
+    let Some(ImeScope { before }) = scope else {
+        return;
+    };
+
+    let ime = ctx.output(|output| output.ime);
+    if ime == before {
+        // not reported by the contents
+        return;
+    }
+
+    let id = id.with("ime");
+    if sizing_pass {
+        let last = ctx.data(|data| data.get_temp::<IMEOutput>(id)).or(before);
+        ctx.output_mut(|output| output.ime = last);
+    } else if let Some(ime) = ime {
+        ctx.data_mut(|data| data.insert_temp(id, ime));
+    }
+}
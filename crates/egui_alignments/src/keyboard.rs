@@ -0,0 +1,62 @@
+//! The on-screen keyboard
+//!
+//! egui does not know the height of the soft keyboard of mobile and web targets,
+//! so the app registers it while the keyboard is open.
+//! The aligners and the bottom overlays then keep their contents above it,
+//! so a bottom-aligned input is not hidden while typing in it.
+//!
+//! # Example
+//! ```
+//! # use egui_alignments::egui;
+//! use egui_alignments::{set_keyboard_inset, AlignedWidget};
+//!
+//! # egui::__run_test_ui(|ui| {
+//! // e.g. from the visual viewport on the web
+//! let keyboard_height = 260.0;
+//! set_keyboard_inset(ui.ctx(), keyboard_height);
+//!
+//! egui::TextEdit::singleline(&mut String::new())
+//!     .hint_text("Message")
+//!     .bottom(ui);
+//! # });
+//! ```
+
+use egui::{Context, Id, Rect};
+
+use crate::size_cache;
+
+fn inset_id() -> Id {
+    Id::new("egui_alignments::keyboard_inset")
+}
+
+/// Register the height of the on-screen keyboard covering the bottom of the screen,
+/// zero when it is closed.
+pub fn set_keyboard_inset(ctx: &Context, height: f32) {
+    // set once a keyboard was registered, to skip the memory lock otherwise
+    size_cache::set_flag(ctx, |flags| &mut flags.keyboard, true);
+    ctx.data_mut(|data| data.insert_temp(inset_id(), height.max(0.0)));
+}
+
+/// The height of the on-screen keyboard registered with [`set_keyboard_inset`].
+pub fn keyboard_inset(ctx: &Context) -> f32 {
+    if !size_cache::flags(ctx).keyboard {
+        return 0.0;
+    }
+    ctx.data(|data| data.get_temp(inset_id())).unwrap_or(0.0)
+}
+
+/// Cut the part of the rect hidden by the on-screen keyboard,
+/// if the rect starts above the keyboard.
+pub fn avoid_keyboard(ctx: &Context, rect: Rect) -> Rect {
+    let inset = keyboard_inset(ctx);
+    if inset <= 0.0 {
+        return rect;
+    }
+
+    let keyboard_top = ctx.screen_rect().bottom() - inset;
+    let mut rect = rect;
+    if rect.top() < keyboard_top && keyboard_top < rect.bottom() {
+        rect.max.y = keyboard_top;
+    }
+    rect
+}
@@ -7,6 +7,7 @@
 //! ### Align a single widget
 //!
 //! ```rust
+//! # use egui_alignments::egui;
 //! use egui::{Button, Label};
 //! use egui_alignments::AlignedWidget;
 //!
@@ -32,6 +33,7 @@
 //! with the tip text above and click results below.
 //!
 //! ```rust
+//! # use egui_alignments::egui;
 //! use egui::{Button, Widget};
 //! use egui_alignments::{center_horizontal, center_vertical};
 //!
@@ -75,6 +77,7 @@
 //! The following is an example usage of containers
 //!
 //! ```rust
+//! # use egui_alignments::egui;
 //! use egui::Align;
 //! use egui_alignments::{center_horizontal, column, row};
 //!
@@ -95,20 +98,163 @@
 //! ```
 //!
 //! This will show an image on the left, and a column of text on the right which contains a row of three labels in the middle.
+//!
+//! ## Prelude
+//!
+//! The traits, such as [`AlignedWidget`] and [`UiAlignExt`], and the most used aligners and containers
+//! are imported at once with `use egui_alignments::prelude::*;`, see [`prelude`].
+//!
+//! ## egui versions
+//!
+//! The version of egui to build against is selected by a feature:
+//! `egui_0_29` (the default), `egui_0_30`, `egui_0_31`, or `egui_latest` for the latest supported one.
+//! To use another version than the default, disable the default features:
+//!
+//! ```toml
+//! egui_alignments = { version = "0.3", default-features = false, features = ["egui_0_30"] }
+//! ```
+//!
+//! The selected egui is re-exported as [`egui`].
+//!
+//! ## serde
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` for [`Row`], [`Column`],
+//! [`Bounds`], [`AllocateType`] and [`Alignment`],
+//! so alignment settings can be saved in app preferences or loaded from config.
+//! Callbacks such as `on_overflow` and [`AllocateType::Custom`] are skipped.
+//!
+//! ## profiling
+//!
+//! The `profiling` feature wraps the layout of each [`WidgetAligner`] and [`GenericContainer`]
+//! in a [`profiling`](https://docs.rs/profiling) scope tagged with its id,
+//! so the containers dominating the frame time show up in flamegraphs.
+//! Enable the backend, e.g. `puffin`, with the features of the `profiling` crate.
+//!
+//! ## tracing
+//!
+//! The `tracing` feature enters a [`tracing`](https://docs.rs/tracing) span for each aligner and container
+//! tagged with its id, and emits debug events when a sizing pass starts, a pass is discarded
+//! or a memorized size changes, to find out why a ui is laid out again every frame.
+
+#[cfg(not(any(feature = "egui_0_29", feature = "egui_0_30", feature = "egui_0_31")))]
+compile_error!("select the egui version with one of the features `egui_0_29`, `egui_0_30`, `egui_0_31` or `egui_latest`");
+
+#[cfg(any(
+    all(feature = "egui_0_29", feature = "egui_0_30"),
+    all(feature = "egui_0_29", feature = "egui_0_31"),
+    all(feature = "egui_0_30", feature = "egui_0_31"),
+))]
+compile_error!("only one egui version feature can be enabled, disable the default features to select another version than egui 0.29");
+
+#[cfg(all(feature = "egui_0_29", not(any(feature = "egui_0_30", feature = "egui_0_31"))))]
+pub extern crate egui;
+#[cfg(all(feature = "egui_0_30", not(feature = "egui_0_31")))]
+pub extern crate egui_0_30 as egui;
+#[cfg(feature = "egui_0_31")]
+pub extern crate egui_0_31 as egui;
 
 pub mod aligned_widget;
 pub mod aligner;
+pub mod anchors;
+pub mod banner;
+pub mod bar;
+mod compat;
+pub mod connector;
 pub mod container;
+pub mod debug;
+pub mod edge_progress;
+pub mod empty_state;
+pub mod focus_scroll;
+mod ime;
+pub mod keyboard;
+pub mod measure;
+pub mod overlay;
+pub mod page;
+pub mod paginator;
+pub mod panel;
+pub mod popup;
+pub mod prelude;
+pub mod presets;
+pub mod response;
+pub mod ribbon;
+pub mod roving;
+pub mod safe_area;
+pub mod scroll_anchor;
+mod size_cache;
+pub mod size_hint;
+pub mod skeleton;
+pub mod stretch;
+pub mod text;
+pub mod transform;
+pub mod ui_ext;
+pub mod units;
+pub mod viewport;
 
 pub use aligned_widget::*;
 pub use aligner::*;
+pub use anchors::*;
+pub use banner::*;
+pub use bar::*;
+pub use connector::*;
 pub use container::*;
+pub use edge_progress::*;
+pub use empty_state::*;
+pub use focus_scroll::*;
+pub use keyboard::*;
+pub use measure::*;
+pub use overlay::*;
+pub use page::*;
+pub use paginator::*;
+pub use panel::*;
+pub use popup::*;
+pub use response::*;
+pub use ribbon::*;
+pub use roving::*;
+pub use safe_area::*;
+pub use scroll_anchor::*;
+pub use size_hint::*;
+pub use skeleton::*;
+pub use stretch::*;
+pub use text::*;
+pub use transform::*;
+pub use ui_ext::*;
+pub use units::*;
+pub use viewport::*;
 
-use egui::{Align, Direction, Layout, Rect, Vec2};
+use egui::{Align, Align2, Direction, Layout, Rect, Ui, UiBuilder, Vec2};
 
-// resize layout rect without moving the inner content.
-// this is useful for layouts that contain growable widgets like `ScrollArea`.
-pub(crate) fn resize_layout_rect(rect: Rect, size: Vec2, layout: &Layout) -> Rect {
+/// Resize the rect of a layout to the given size without moving its contents.
+///
+/// The rect grows or shrinks away from the side the layout starts from:
+/// - along the main direction, the start edge stays in place
+///   (left for left-to-right, bottom for bottom-up...).
+/// - along the cross direction, the edge of the cross alignment stays in place,
+///   and a centered layout keeps its size, since it centers its contents in the rect anyway.
+///
+/// This is useful to give the contents the room to grow, e.g. for a `ScrollArea`,
+/// after they were placed in a rect of their memorized size.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{pos2, vec2, Align, Layout, Rect};
+/// use egui_alignments::resize_layout_rect;
+///
+/// let rect = Rect::from_min_size(pos2(100.0, 100.0), vec2(50.0, 20.0));
+///
+/// // a right-to-left row keeps its right edge
+/// let resized = resize_layout_rect(rect, vec2(80.0, 20.0), &Layout::right_to_left(Align::Min));
+/// assert_eq!(resized, Rect::from_min_max(pos2(70.0, 100.0), pos2(150.0, 120.0)));
+///
+/// // a top-down column centered horizontally keeps its top edge and its width
+/// let resized = resize_layout_rect(rect, vec2(70.0, 40.0), &Layout::top_down(Align::Center));
+/// assert_eq!(resized, Rect::from_min_max(pos2(100.0, 100.0), pos2(150.0, 140.0)));
+///
+/// // a bottom-up column keeps its bottom edge
+/// let resized = resize_layout_rect(rect, vec2(50.0, 30.0), &Layout::bottom_up(Align::Min));
+/// assert_eq!(resized, Rect::from_min_max(pos2(100.0, 90.0), pos2(150.0, 120.0)));
+/// ```
+pub fn resize_layout_rect(rect: Rect, size: Vec2, layout: &Layout) -> Rect {
     let mut new_rect = rect;
     let x_expand = size.x - rect.width();
     let y_expand = size.y - rect.height();
@@ -156,3 +302,174 @@ pub(crate) fn resize_layout_rect(rect: Rect, size: Vec2, layout: &Layout) -> Rec
 
     new_rect
 }
+
+/// Expand the rect of a layout by the given amount without moving its contents.
+/// Negative amounts shrink it.
+/// See [`resize_layout_rect`] for the direction it grows in.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{pos2, vec2, Align, Layout, Rect};
+/// use egui_alignments::expand_layout_rect;
+///
+/// let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(50.0, 20.0));
+/// let expanded = expand_layout_rect(rect, vec2(10.0, 10.0), &Layout::left_to_right(Align::Max));
+/// assert_eq!(expanded, Rect::from_min_max(pos2(0.0, -10.0), pos2(60.0, 20.0)));
+/// ```
+pub fn expand_layout_rect(rect: Rect, expand: Vec2, layout: &Layout) -> Rect {
+    resize_layout_rect(rect, rect.size() + expand, layout)
+}
+
+/// Add spacing along the main direction of the ui, which is not an item of the layout:
+/// it does not change the height of a row and, in a wrapped row,
+/// it is dropped at the start and at the end of a line instead of wrapping it.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{spacer, Row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let mut row = Row::new(Align::Center).max_width(200.0);
+/// row.wrapping = true;
+/// row.show(ui, |ui| {
+///     for tag in ["rust", "egui", "layout", "alignment", "gui"] {
+///         ui.label(tag);
+///         spacer(ui, 12.0);
+///     }
+/// });
+/// # });
+/// ```
+pub fn spacer(ui: &mut Ui, amount: f32) {
+    let layout = ui.layout();
+    if layout.main_wrap() && layout.is_horizontal() {
+        let line_start = if layout.prefer_right_to_left() {
+            ui.cursor().right() >= ui.max_rect().right()
+        } else {
+            ui.cursor().left() <= ui.max_rect().left()
+        };
+        let line_end = ui.available_size_before_wrap().x < amount;
+        if line_start || line_end {
+            return;
+        }
+    }
+    ui.add_space(amount);
+}
+
+// request a discard of the pass, reported to `tracing` with the feature enabled.
+pub(crate) fn request_discard(ctx: &egui::Context, reason: &'static str) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(reason, "requesting a discard");
+    ctx.request_discard(reason);
+}
+
+// hide the contents of a sizing pass.
+// a discarded sizing pass keeps its contents interactive: the pass run after it in the same frame gets no input,
+// so a click on the contents is handled in the sizing pass, with the same ids as in the next pass.
+// egui hits the widgets laid out in the previous frame, so this only helps the contents shown before,
+// e.g. measured again after their bounds changed: a click on fresh contents, in their first frame, is lost.
+// a sizing pass which is not discarded, e.g. when egui ran out of passes, is presented,
+// so its hidden contents are disabled rather than taking the input.
+pub(crate) fn hide_sizing_pass(ui: &mut Ui) {
+    ui.set_opacity(0.0);
+    if !ui.ctx().will_discard() {
+        ui.disable();
+    }
+}
+
+// animate the bool of the given id towards the target, starting from `false` the first time it is animated.
+// egui does not animate the first value of an animation, so a flag under the id marks the priming as done.
+pub(crate) fn fade_in_from_hidden(ctx: &egui::Context, id: egui::Id, target: bool, duration: f32) -> f32 {
+    let seen_id = id.with("seen");
+    if ctx.data(|data| data.get_temp::<bool>(seen_id)).is_none() {
+        ctx.data_mut(|data| data.insert_temp(seen_id, true));
+        ctx.animate_bool_with_time(id, false, duration);
+    }
+    ctx.animate_bool_with_time(id, target, duration)
+}
+
+// the available rect of the ui, cut at the clip rect on the sides it is infinite,
+// e.g. the bottom in a vertical scroll area, so it can be divided.
+pub(crate) fn bounded_available_rect(ui: &Ui) -> Rect {
+    let rect = ui.available_rect_before_wrap();
+    let clip_rect = ui.clip_rect();
+    let bound = |max: f32, min: f32, clip_max: f32| if max.is_finite() { max } else { clip_max.max(min) };
+    Rect::from_min_max(
+        rect.min,
+        egui::pos2(
+            bound(rect.max.x, rect.min.x, clip_rect.max.x),
+            bound(rect.max.y, rect.min.y, clip_rect.max.y),
+        ),
+    )
+}
+
+// shift the rect back into the bounds, keeping its left top corner in if it is too large.
+pub(crate) fn keep_within(rect: Rect, bounds: Rect) -> Rect {
+    let shift = Vec2::new(
+        (bounds.right() - rect.right()).min(0.0).max(bounds.left() - rect.left()),
+        (bounds.bottom() - rect.bottom()).min(0.0).max(bounds.top() - rect.top()),
+    );
+    rect.translate(shift)
+}
+
+// the rect the ui would give to a widget of the given size, without allocating it.
+// mirrors the placement of egui layouts, to avoid building a throwaway child ui for every widget.
+pub(crate) fn next_widget_rect(ui: &mut Ui, size: Vec2) -> Rect {
+    if ui.layout().main_wrap() {
+        // wrapping depends on the rows placed so far, let egui decide
+        return ui.new_child(UiBuilder::new()).allocate_space(size).1;
+    }
+
+    layout_widget_rect(ui.layout(), ui.available_rect_before_wrap(), size)
+}
+
+// the placement math of `next_widget_rect` for a non-wrapping layout,
+// for callers which already have the available rect at hand.
+pub(crate) fn layout_widget_rect(layout: &Layout, available_rect: Rect, size: Vec2) -> Rect {
+    let size = size.max(Vec2::ZERO);
+
+    let mut frame_size = size;
+    if (layout.is_vertical() && layout.horizontal_align() == Align::Center) || layout.horizontal_justify() {
+        frame_size.x = frame_size.x.max(available_rect.width());
+    }
+    if (layout.is_horizontal() && layout.vertical_align() == Align::Center) || layout.vertical_justify() {
+        frame_size.y = frame_size.y.max(available_rect.height());
+    }
+
+    let frame_align = match layout.main_dir() {
+        Direction::LeftToRight => Align2([Align::LEFT, layout.vertical_align()]),
+        Direction::RightToLeft => Align2([Align::RIGHT, layout.vertical_align()]),
+        Direction::TopDown => Align2([layout.horizontal_align(), Align::TOP]),
+        Direction::BottomUp => Align2([layout.horizontal_align(), Align::BOTTOM]),
+    };
+    let mut frame = frame_align.align_size_within_rect(frame_size, available_rect);
+    if layout.is_horizontal() && frame.top() < available_rect.top() {
+        // horizontal layouts expand down, not over the row above
+        frame = frame.translate(Vec2::Y * (available_rect.top() - frame.top()));
+    }
+
+    let mut child_size = size;
+    if layout.horizontal_justify() {
+        child_size.x = child_size.x.max(frame.width());
+    }
+    if layout.vertical_justify() {
+        child_size.y = child_size.y.max(frame.height());
+    }
+    layout.align_size_within_rect(child_size, frame)
+}
+
+// the rect to lay out horizontally wrapped contents in, given the rect of the whole contents.
+// it is as wide as the contents, at most the given width, keeping the side the lines start from,
+// and one line high like `Ui::horizontal_wrapped`, so egui aligns the items within their line
+// and places the next lines below it, filling them from the same side for both directions.
+pub(crate) fn wrapped_lines_rect(ui: &Ui, layout: &Layout, rect: Rect, max_width: f32) -> Rect {
+    let width = rect.width().min(max_width).max(0.0);
+    let x_range = if layout.prefer_right_to_left() {
+        rect.right() - width..=rect.right()
+    } else {
+        rect.left()..=rect.left() + width
+    };
+    Rect::from_x_y_ranges(x_range, rect.top()..=rect.top() + ui.spacing().interact_size.y)
+}
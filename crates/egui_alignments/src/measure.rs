@@ -0,0 +1,47 @@
+//! Measuring contents before placing them
+
+use egui::{Ui, UiBuilder, Vec2};
+
+// the id salt of the child uis measuring contents which are shown again afterwards.
+// the measuring ui is disabled, and a disabled widget gives up the keyboard focus,
+// so the persistent ids inside it must differ from the ones of the contents shown,
+// or a focused `TextEdit` would lose the focus, and its IME composition, every frame.
+pub(crate) const MEASURE_ID_SALT: &str = "egui_alignments::measure";
+
+/// Run the contents in an invisible sizing child ui and get the size they take,
+/// without allocating any space in the ui.
+///
+/// The child ui starts at the next widget position with the layout of the ui,
+/// so the size is the same as if the contents were added to the ui directly.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::measure;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let size = measure(ui, |ui| {
+///     ui.label("Some text");
+///     ui.button("A button");
+/// });
+/// // right-align the contents by hand
+/// let padding = ui.available_width() - size.x;
+/// ui.horizontal(|ui| {
+///     ui.add_space(padding);
+///     ui.label("Some text");
+///     ui.button("A button");
+/// });
+/// # });
+/// ```
+pub fn measure(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui)) -> Vec2 {
+    let mut sizing_ui = ui.new_child(
+        UiBuilder::new()
+            .id_salt(MEASURE_ID_SALT)
+            .max_rect(ui.available_rect_before_wrap())
+            .layout(*ui.layout())
+            .sizing_pass()
+            .invisible()
+    );
+    add_contents(&mut sizing_ui);
+    sizing_ui.min_size()
+}
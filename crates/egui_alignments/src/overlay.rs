@@ -0,0 +1,110 @@
+//! Aligned overlays shown above the normal contents
+//! 
+//! # Example
+//! ```
+//! # use egui_alignments::egui;
+//! use egui_alignments::{Snackbar, SnackbarMessage};
+//! 
+//! # egui::__run_test_ui(|ui| {
+//! let snackbar = Snackbar::new("snackbar");
+//! if ui.button("Delete").clicked() {
+//!     snackbar.push(ui.ctx(), SnackbarMessage::new("Item deleted").action("Undo"));
+//! }
+//! snackbar.show(ui.ctx());
+//! # });
+//! ```
+
+pub mod background;
+pub mod coach;
+pub mod cursor;
+pub mod dim;
+pub mod hud;
+pub mod loading;
+pub mod on_response;
+pub mod shortcuts;
+pub mod snackbar;
+pub mod spotlight;
+
+pub use background::*;
+pub use coach::*;
+pub use cursor::*;
+pub use dim::*;
+pub use hud::*;
+pub use loading::*;
+pub use on_response::*;
+pub use shortcuts::*;
+pub use snackbar::*;
+pub use spotlight::*;
+
+use egui::{Area, Context, Id, InnerResponse, Layout, Order, Rect, Response, Sense, Ui, UiBuilder};
+
+use crate::{AlignedResponse, Bounds, WidgetAligner};
+
+// an area over the bounds of a dim layer, taking the pointer input over them,
+// with the contents centered in a rect within them.
+// the overlays shown over the other contents, e.g. the loading screen or the shortcuts sheet, are built on it.
+pub(crate) struct ModalArea {
+    id: Id,
+    order: Order,
+    opacity: f32,
+    dim: DimOverlay,
+}
+
+impl ModalArea {
+    #[inline]
+    pub(crate) fn new(id: Id, dim: DimOverlay) -> Self {
+        Self {
+            id,
+            order: Order::Foreground,
+            opacity: 1.0,
+            dim,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    // show the contents centered in the content rect.
+    // returns the response of the area, with the response of the backdrop and the response of the contents.
+    // the backdrop is under the contents too, so check the pointer against them when handling its clicks.
+    pub(crate) fn show<R>(
+        &self,
+        ctx: &Context,
+        content_rect: Rect,
+        layout: Layout,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<(Response, AlignedResponse<R>)> {
+        let bounds = self.dim.bounds;
+        Area::new(self.id.with("area"))
+            .order(self.order)
+            .fixed_pos(bounds.min)
+            .constrain(false)
+            .interactable(true)
+            .show(ctx, |ui| {
+                ui.set_opacity(self.opacity);
+                ui.set_clip_rect(bounds);
+
+                // take the pointer input over the bounds
+                let backdrop = ui.allocate_rect(bounds, Sense::click_and_drag());
+                self.dim.paint_with(ui.painter());
+
+                let mut content_ui = ui.new_child(UiBuilder::new().max_rect(content_rect).layout(layout));
+                let contents = WidgetAligner::center()
+                    .id(self.id.with("contents"))
+                    .bounds(Bounds::max_rect())
+                    .layout(layout)
+                    .show(&mut content_ui, add_contents);
+
+                (backdrop, contents)
+            })
+    }
+}
@@ -0,0 +1,131 @@
+use egui::{Align2, Id, InnerResponse, LayerId, Margin, Ui, UiBuilder};
+
+use crate::{compat, AlignedResponse, AllocateType, Bounds, WidgetAligner};
+
+/// A decoration (logo, texture, hint text...) aligned within the max rect of a Ui,
+/// painted without affecting the layout.
+///
+/// The decoration is painted on a layer of its own, registered as a sublayer of the layer of the ui.
+/// egui paints it right above the ui, so the frame of the ui does not hide it:
+/// give it a low opacity for the widgets to stay readable.
+/// Its labels are not selectable, so they do not take the clicks of the widgets below.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::BackgroundDecoration;
+///
+/// # egui::__run_test_ui(|ui| {
+/// BackgroundDecoration::new(Align2::RIGHT_BOTTOM)
+///     .margin(8.0)
+///     .opacity(0.3)
+///     .show(ui, |ui| {
+///         ui.heading("DRAFT");
+///     });
+/// ui.label("The normal contents");
+/// # });
+/// ```
+pub struct BackgroundDecoration {
+    /// Used to memorize the decoration size.
+    /// If `None`, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The alignment of the decoration within the max rect of the ui.
+    pub align: Align2,
+
+    /// The margin between the decoration and the edges of the max rect.
+    pub margin: Margin,
+
+    /// The opacity of the decoration.
+    pub opacity: f32,
+}
+
+impl BackgroundDecoration {
+    #[inline]
+    /// Create a new decoration with the given alignment.
+    pub fn new(align: Align2) -> Self {
+        Self {
+            id: None,
+            align,
+            margin: Margin::ZERO,
+            opacity: 1.0,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the decoration.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the margin between the decoration and the edges of the max rect.
+    pub fn margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the opacity of the decoration.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+}
+
+impl BackgroundDecoration {
+    /// Paint the decoration in the given ui.
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<R> {
+        let id = self.id.unwrap_or_else(|| {
+            let id = ui.next_auto_id();
+            ui.skip_ahead_auto_ids(1);
+            id
+        });
+
+        // a layer of its own, kept with the layer of the ui, so the shapes of the ui are left as they are
+        let layer_id = LayerId::new(ui.layer_id().order, id);
+        ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+
+        // a detached child, so nothing is allocated in the ui
+        let mut decoration_ui = ui.new_child(
+            UiBuilder::new()
+                .id_salt(id)
+                .max_rect(ui.max_rect())
+        );
+        decoration_ui.multiply_opacity(self.opacity);
+        decoration_ui.style_mut().interaction.selectable_labels = false;
+
+        compat::scope_with_layer_id(&mut decoration_ui, layer_id, |ui| {
+            WidgetAligner::from_align(self.align)
+                .id(id)
+                .bounds(Bounds::MaxRect(self.margin))
+                .allocate_type(AllocateType::None)
+                .show(ui, add_contents)
+        }).inner
+    }
+}
+
+#[inline]
+/// Paint a decoration aligned within the max rect of the ui, on a layer above it
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::background_aligned;
+///
+/// # egui::__run_test_ui(|ui| {
+/// background_aligned(ui, Align2::CENTER_CENTER, |ui| {
+///     ui.weak("Nothing here yet");
+/// });
+/// # });
+/// ```
+pub fn background_aligned<R>(
+    ui: &mut Ui,
+    align: Align2,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    BackgroundDecoration::new(align).show(ui, add_contents).into()
+}
@@ -0,0 +1,276 @@
+use std::hash::Hash;
+
+use egui::{Align, Context, Frame, Id, Label, Layout, Pos2, Rect, Response, Vec2};
+
+use crate::{overlay::ModalArea, row, DimOverlay, Edge};
+
+/// Place a rect of the given size next to a target rect, `gap` away from it,
+/// on the preferred side if it fits in the bounds there,
+/// otherwise on the opposite side, otherwise on the first other side it fits on.
+///
+/// The rect is centered along the target and then shifted to stay within the bounds.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{pos2, vec2, Rect};
+/// use egui_alignments::{place_beside, Edge};
+///
+/// let screen = Rect::from_min_size(pos2(0.0, 0.0), vec2(800.0, 600.0));
+/// // a button at the bottom of the screen, no room below it
+/// let target = Rect::from_min_size(pos2(10.0, 570.0), vec2(80.0, 20.0));
+/// let bubble = place_beside(target, vec2(200.0, 60.0), screen, 8.0, Edge::Bottom);
+/// assert_eq!(bubble.bottom(), target.top() - 8.0);
+/// assert_eq!(bubble.left(), 0.0);
+/// ```
+pub fn place_beside(target: Rect, size: Vec2, bounds: Rect, gap: f32, preferred: Edge) -> Rect {
+    let sides = [
+        preferred,
+        preferred.opposite(),
+        Edge::Bottom,
+        Edge::Top,
+        Edge::Right,
+        Edge::Left,
+    ];
+    let fits = |side: Edge| {
+        let rect = rect_beside(side, target, size, gap);
+        match side {
+            Edge::Top => rect.top() >= bounds.top(),
+            Edge::Bottom => rect.bottom() <= bounds.bottom(),
+            Edge::Left => rect.left() >= bounds.left(),
+            Edge::Right => rect.right() <= bounds.right(),
+        }
+    };
+    let side = sides.into_iter().find(|side| fits(*side)).unwrap_or(preferred);
+    let rect = rect_beside(side, target, size, gap);
+
+    crate::keep_within(rect, bounds)
+}
+
+// the rect of the given size on the side of the target, centered along it
+pub(crate) fn rect_beside(side: Edge, target: Rect, size: Vec2, gap: f32) -> Rect {
+    let min = match side {
+        Edge::Top => Pos2::new(target.center().x - size.x / 2.0, target.top() - gap - size.y),
+        Edge::Bottom => Pos2::new(target.center().x - size.x / 2.0, target.bottom() + gap),
+        Edge::Left => Pos2::new(target.left() - gap - size.x, target.center().y - size.y / 2.0),
+        Edge::Right => Pos2::new(target.right() + gap, target.center().y - size.y / 2.0),
+    };
+    Rect::from_min_size(min, size)
+}
+
+/// A step of [`CoachMarks`]: an explanation of the widget registered with the target id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoachStep {
+    /// The id the target rect was registered with, see [`CoachMarks::register`].
+    pub target: Id,
+
+    /// The explanation shown in the bubble.
+    pub text: String,
+}
+
+impl CoachStep {
+    #[inline]
+    /// Create a new step explaining the target registered with the given id salt.
+    pub fn new(target_salt: impl Hash, text: impl Into<String>) -> Self {
+        Self {
+            target: Id::new(target_salt),
+            text: text.into(),
+        }
+    }
+}
+
+/// What happened to [`CoachMarks`] this frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoachEvent {
+    /// The next button was clicked, moving to the step with the given index.
+    Next(usize),
+
+    /// The skip button was clicked.
+    Skipped,
+
+    /// The done button of the last step was clicked.
+    Finished,
+}
+
+/// A sequence of onboarding bubbles, each placed next to the widget it explains,
+/// which is left bright in a cut-out of the dimmed screen.
+///
+/// The rects of the targets are registered by id with [`CoachMarks::register`]
+/// when the widgets are shown, and the marks are shown afterwards, once per frame.
+/// The bubble of a step goes on the preferred side of its target,
+/// or on another side if there is no room there.
+/// The screen does not take the pointer input while the marks are running.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{CoachMarks, CoachStep};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.button("New");
+/// CoachMarks::register_response(ui.ctx(), "new_button", &response);
+/// let response = ui.button("Share");
+/// CoachMarks::register_response(ui.ctx(), "share_button", &response);
+///
+/// let tour = CoachMarks::new("tour", vec![
+///     CoachStep::new("new_button", "Create a document here."),
+///     CoachStep::new("share_button", "Then invite your team."),
+/// ]);
+/// if ui.button("Take the tour").clicked() {
+///     tour.start(ui.ctx());
+/// }
+/// tour.show(ui.ctx());
+/// # });
+/// ```
+pub struct CoachMarks {
+    /// Used to memorize the current step.
+    pub id: Id,
+
+    /// The steps in order.
+    pub steps: Vec<CoachStep>,
+
+    /// The side of the targets on which the bubbles are placed if there is room.
+    pub side: Edge,
+
+    /// The space between a target and the edge of its cut-out.
+    pub padding: f32,
+
+    /// The space between a cut-out and its bubble.
+    pub gap: f32,
+
+    /// The maximum width of the bubbles.
+    pub max_width: f32,
+}
+
+impl CoachMarks {
+    #[inline]
+    /// Create new coach marks with the given steps, placing the bubbles below their targets.
+    pub fn new(id_salt: impl Hash, steps: Vec<CoachStep>) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            steps,
+            side: Edge::Bottom,
+            padding: 4.0,
+            gap: 8.0,
+            max_width: 280.0,
+        }
+    }
+
+    #[inline]
+    /// Set the side of the targets on which the bubbles are placed if there is room.
+    pub fn side(mut self, side: Edge) -> Self {
+        self.side = side;
+        self
+    }
+
+    #[inline]
+    /// Set the space between a target and the edge of its cut-out.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    #[inline]
+    /// Set the space between a cut-out and its bubble.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    #[inline]
+    /// Set the maximum width of the bubbles.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+}
+
+impl CoachMarks {
+    /// Register the screen rect of a target with the given id salt.
+    pub fn register(ctx: &Context, target_salt: impl Hash, rect: Rect) {
+        let id = Id::new(target_salt).with("coach_target");
+        ctx.data_mut(|data| data.insert_temp(id, rect));
+    }
+
+    /// Register the rect of a widget as a target with the given id salt.
+    pub fn register_response(ctx: &Context, target_salt: impl Hash, response: &Response) {
+        Self::register(ctx, target_salt, response.rect);
+    }
+
+    /// Start from the first step.
+    pub fn start(&self, ctx: &Context) {
+        ctx.data_mut(|data| data.insert_temp(self.id, 0_usize));
+    }
+
+    /// Stop showing the marks.
+    pub fn stop(&self, ctx: &Context) {
+        ctx.data_mut(|data| data.remove::<usize>(self.id));
+    }
+
+    /// The index of the current step, if the marks are running.
+    pub fn current_step(&self, ctx: &Context) -> Option<usize> {
+        ctx.data(|data| data.get_temp::<usize>(self.id))
+            .filter(|step| *step < self.steps.len())
+    }
+
+    /// Show the bubble of the current step, if the marks are running.
+    /// Call this once per frame, after the targets are registered.
+    pub fn show(&self, ctx: &Context) -> Option<CoachEvent> {
+        let index = self.current_step(ctx)?;
+        let step = &self.steps[index];
+        let last = index + 1 == self.steps.len();
+
+        let screen_rect = ctx.screen_rect();
+        let target = ctx.data(|data| data.get_temp::<Rect>(step.target.with("coach_target")))
+            .map(|rect| rect.expand(self.padding));
+
+        let size_id = self.id.with("bubble_size");
+        let bubble_size = ctx.data(|data| data.get_temp::<Vec2>(size_id));
+
+        let mut dim = DimOverlay::new(screen_rect);
+        dim.cutout = target;
+
+        // without a target, center the bubble on the screen
+        let bubble_rect = match (target, bubble_size) {
+            (Some(target), Some(size)) => {
+                place_beside(target, size, screen_rect.shrink(self.gap), self.gap, self.side)
+            },
+            _ => screen_rect,
+        };
+
+        let mut event = None;
+        let (_, bubble) = ModalArea::new(self.id, dim)
+            .show(ctx, bubble_rect, Layout::top_down(Align::Min), |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_max_width(self.max_width);
+                    ui.add(Label::new(&step.text).wrap());
+                    row(ui, Align::Center, |ui| {
+                        ui.weak(format!("{}/{}", index + 1, self.steps.len()));
+                        if !last && ui.button("Skip").clicked() {
+                            event = Some(CoachEvent::Skipped);
+                        }
+                        if ui.button(if last { "Done" } else { "Next" }).clicked() {
+                            event = Some(if last { CoachEvent::Finished } else { CoachEvent::Next(index + 1) });
+                        }
+                    });
+                })
+                .response
+                .rect
+                .size()
+            })
+            .inner;
+
+        if !bubble.sizing_pass && bubble_size != Some(bubble.inner) {
+            ctx.data_mut(|data| data.insert_temp(size_id, bubble.inner));
+            crate::request_discard(ctx, "new CoachMarks bubble");
+        }
+
+        match event {
+            Some(CoachEvent::Next(next)) => ctx.data_mut(|data| data.insert_temp(self.id, next)),
+            Some(CoachEvent::Skipped | CoachEvent::Finished) => self.stop(ctx),
+            None => {},
+        }
+
+        event
+    }
+}
@@ -0,0 +1,127 @@
+use std::hash::Hash;
+
+use egui::{Align2, Area, Context, Id, InnerResponse, Margin, Order, Ui, Vec2};
+
+/// Aligns contents relative to the pointer, e.g. a drag preview or a hint following the cursor.
+///
+/// The point of the contents given by the pivot is placed at the pointer position plus the offset,
+/// and the contents are kept within the screen, minus a margin.
+/// The contents are shown on a layer above the others and do not take the pointer input,
+/// so the widgets underneath are still hovered, e.g. drop targets.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::CursorAligner;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.label("Drag me");
+/// if response.dragged() {
+///     CursorAligner::new("drag_preview")
+///         .pivot(Align2::LEFT_TOP)
+///         .offset([12.0, 12.0])
+///         .show(ui.ctx(), |ui| {
+///             ui.label("📄 report.pdf");
+///         });
+/// }
+/// # });
+/// ```
+pub struct CursorAligner {
+    /// Used to store the area of the contents.
+    pub id: Id,
+
+    /// The point of the contents placed at the pointer.
+    pub pivot: Align2,
+
+    /// The offset from the pointer position.
+    pub offset: Vec2,
+
+    /// The space kept between the contents and the screen edges.
+    pub margin: Margin,
+
+    /// The layer order of the contents.
+    /// Default: [`Order::Tooltip`].
+    pub order: Order,
+}
+
+impl CursorAligner {
+    #[inline]
+    /// Create a new aligner placing the top left corner of the contents at the pointer.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            pivot: Align2::LEFT_TOP,
+            offset: Vec2::ZERO,
+            margin: Margin::ZERO,
+            order: Order::Tooltip,
+        }
+    }
+
+    #[inline]
+    /// Set the point of the contents placed at the pointer.
+    pub fn pivot(mut self, pivot: Align2) -> Self {
+        self.pivot = pivot;
+        self
+    }
+
+    #[inline]
+    /// Set the offset from the pointer position.
+    pub fn offset(mut self, offset: impl Into<Vec2>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    #[inline]
+    /// Set the space kept between the contents and the screen edges.
+    pub fn margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the layer order of the contents.
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+impl CursorAligner {
+    /// Show the contents next to the pointer.
+    ///
+    /// Returns `None` if the pointer position is unknown, e.g. the pointer left the window.
+    pub fn show<R>(&self, ctx: &Context, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+        let pointer = ctx.input(|input| input.pointer.latest_pos())?;
+
+        let response = Area::new(self.id)
+            .order(self.order)
+            .pivot(self.pivot)
+            .fixed_pos(pointer + self.offset)
+            .constrain_to(ctx.screen_rect() - self.margin)
+            .interactable(false)
+            .show(ctx, add_contents);
+
+        Some(response)
+    }
+}
+
+#[inline]
+/// Show the contents below and to the right of the pointer, within the screen
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::follow_cursor;
+///
+/// # egui::__run_test_ui(|ui| {
+/// follow_cursor(ui.ctx(), |ui| {
+///     ui.label("Drop to upload");
+/// });
+/// # });
+/// ```
+pub fn follow_cursor<R>(ctx: &Context, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+    CursorAligner::new("egui_alignments::follow_cursor")
+        .offset([16.0, 16.0])
+        .show(ctx, add_contents)
+}
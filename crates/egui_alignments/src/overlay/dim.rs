@@ -0,0 +1,171 @@
+use egui::{epaint::Mesh, pos2, vec2, Color32, Id, LayerId, Painter, Pos2, Rect, Shape, Ui};
+
+/// A dim layer painted over some bounds,
+/// optionally leaving a cut-out rect bright to focus attention on it.
+///
+/// The layer is painted on a sublayer directly above the layer of the ui,
+/// so it covers the contents of that layer only,
+/// and it is clipped to the clip rect of the ui.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::DimOverlay;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.button("Look at me");
+/// DimOverlay::new(ui.clip_rect())
+///     .cutout(response.rect.expand(4.0))
+///     .paint(ui);
+/// # });
+/// ```
+pub struct DimOverlay {
+    /// The bounds to dim.
+    pub bounds: Rect,
+
+    /// The rect left bright inside the bounds.
+    pub cutout: Option<Rect>,
+
+    /// The corner radius of the cut-out.
+    pub cutout_radius: f32,
+
+    /// The color of the dim layer.
+    pub color: Color32,
+}
+
+impl DimOverlay {
+    #[inline]
+    /// Create a new dim layer over the given bounds.
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            cutout: None,
+            cutout_radius: 0.0,
+            color: Color32::from_black_alpha(160),
+        }
+    }
+
+    #[inline]
+    /// Set the rect left bright inside the bounds.
+    pub fn cutout(mut self, cutout: Rect) -> Self {
+        self.cutout = Some(cutout);
+        self
+    }
+
+    #[inline]
+    /// Set the corner radius of the cut-out.
+    pub fn cutout_radius(mut self, radius: f32) -> Self {
+        self.cutout_radius = radius;
+        self
+    }
+
+    #[inline]
+    /// Set the opacity of the dim layer, from `0.0` to `1.0`.
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.color = Color32::from_black_alpha((alpha.clamp(0.0, 1.0) * 255.0).round() as u8);
+        self
+    }
+
+    #[inline]
+    /// Set the color of the dim layer.
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl DimOverlay {
+    /// Paint the dim layer above the contents of the given ui.
+    pub fn paint(&self, ui: &Ui) {
+        let layer_id = LayerId::new(ui.layer_id().order, ui.id().with(Id::new("dim_overlay")));
+        ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+
+        let painter = ui.ctx()
+            .layer_painter(layer_id)
+            .with_clip_rect(ui.clip_rect().intersect(self.bounds));
+
+        self.paint_with(&painter);
+    }
+
+    /// Paint the dim layer with the given painter.
+    pub fn paint_with(&self, painter: &Painter) {
+        let bounds = self.bounds;
+        let Some(cutout) = self.cutout.map(|cutout| cutout.intersect(bounds)).filter(|c| c.is_positive()) else {
+            painter.rect_filled(bounds, 0.0, self.color);
+            return;
+        };
+
+        // the four bands around the cut-out
+        let bands = [
+            Rect::from_min_max(bounds.min, pos2(bounds.max.x, cutout.min.y)),
+            Rect::from_min_max(pos2(bounds.min.x, cutout.max.y), bounds.max),
+            Rect::from_min_max(pos2(bounds.min.x, cutout.min.y), pos2(cutout.min.x, cutout.max.y)),
+            Rect::from_min_max(pos2(cutout.max.x, cutout.min.y), pos2(bounds.max.x, cutout.max.y)),
+        ];
+        for band in bands {
+            if band.is_positive() {
+                painter.rect_filled(band, 0.0, self.color);
+            }
+        }
+
+        let radius = self.cutout_radius.min(cutout.width() / 2.0).min(cutout.height() / 2.0);
+        if radius > 0.0 {
+            self.paint_corners(painter, cutout, radius);
+        }
+    }
+
+    // dim the corners of the cut-out outside of its rounded edges,
+    // each as a fan of triangles from the corner to the arc
+    fn paint_corners(&self, painter: &Painter, cutout: Rect, radius: f32) {
+        const SEGMENTS: usize = 8;
+
+        let corners = [
+            (cutout.left_top(), vec2(1.0, 1.0)),
+            (cutout.right_top(), vec2(-1.0, 1.0)),
+            (cutout.left_bottom(), vec2(1.0, -1.0)),
+            (cutout.right_bottom(), vec2(-1.0, -1.0)),
+        ];
+        let mut mesh = Mesh::default();
+        for (corner, inward) in corners {
+            let center = corner + inward * radius;
+            let start = mesh.vertices.len() as u32;
+            mesh.colored_vertex(corner, self.color);
+            for i in 0..=SEGMENTS {
+                let angle = std::f32::consts::FRAC_PI_2 * i as f32 / SEGMENTS as f32;
+                let point: Pos2 = center - vec2(inward.x * angle.cos(), inward.y * angle.sin()) * radius;
+                mesh.colored_vertex(point, self.color);
+            }
+            for i in 0..SEGMENTS as u32 {
+                mesh.add_triangle(start, start + 1 + i, start + 2 + i);
+            }
+        }
+        painter.add(Shape::mesh(mesh));
+    }
+}
+
+#[inline]
+/// Dim the given bounds of the ui with the given opacity
+pub fn overlay_dim(ui: &Ui, bounds: Rect, alpha: f32) {
+    DimOverlay::new(bounds)
+        .alpha(alpha)
+        .paint(ui);
+}
+
+#[inline]
+/// Dim the visible part of the ui except the given rect
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::dim_except;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.label("Focused");
+/// dim_except(ui, response.rect);
+/// # });
+/// ```
+pub fn dim_except(ui: &Ui, rect: Rect) {
+    DimOverlay::new(ui.clip_rect())
+        .cutout(rect)
+        .paint(ui);
+}
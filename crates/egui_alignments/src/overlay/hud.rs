@@ -0,0 +1,170 @@
+use std::hash::Hash;
+
+use egui::{Align, Context, Frame, Id, LayerId, Layout, Order, ProgressBar, Rect, Spinner, Ui};
+
+use crate::{overlay::ModalArea, DimOverlay};
+
+/// A small card with a spinner or a progress bar and a label,
+/// centered over a ui or the whole screen, which is dimmed underneath.
+///
+/// The HUD takes the pointer input over its bounds,
+/// so the widgets underneath are not hovered or clicked while it is visible.
+/// It fades in when it becomes visible and fades out when it is hidden,
+/// so keep showing it with [`ProgressHud::visible`] set to `false` once the work is over.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::ProgressHud;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let saving = true;
+/// let cancel = ProgressHud::new("saving")
+///     .visible(saving)
+///     .progress(0.4)
+///     .text("Saving…")
+///     .cancelable(true)
+///     .show(ui);
+/// if cancel {
+///     // stop saving
+/// }
+/// # });
+/// ```
+pub struct ProgressHud {
+    /// Used to animate the fading.
+    pub id: Id,
+
+    /// If the HUD is visible, or fading out otherwise.
+    pub visible: bool,
+
+    /// The progress from `0.0` to `1.0`.
+    /// If `None`, show a spinner instead of a progress bar.
+    pub progress: Option<f32>,
+
+    /// The label below the spinner or the progress bar.
+    pub text: String,
+
+    /// If `true`, show a cancel button on the card.
+    pub cancelable: bool,
+
+    /// The opacity of the dim layer, from `0.0` to `1.0`.
+    pub dim_alpha: f32,
+
+    /// The duration of the fade-in and fade-out, in seconds.
+    pub fade_duration: f32,
+}
+
+impl ProgressHud {
+    #[inline]
+    /// Create a new visible HUD with a spinner and no label.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            visible: true,
+            progress: None,
+            text: String::new(),
+            cancelable: false,
+            dim_alpha: 0.5,
+            fade_duration: 0.2,
+        }
+    }
+
+    #[inline]
+    /// Set whether the HUD is visible, or fading out.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    #[inline]
+    /// Show a progress bar with the given progress, from `0.0` to `1.0`.
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    #[inline]
+    /// Set the label below the spinner or the progress bar.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    #[inline]
+    /// Set whether to show a cancel button.
+    pub fn cancelable(mut self, cancelable: bool) -> Self {
+        self.cancelable = cancelable;
+        self
+    }
+
+    #[inline]
+    /// Set the opacity of the dim layer, from `0.0` to `1.0`.
+    pub fn dim_alpha(mut self, alpha: f32) -> Self {
+        self.dim_alpha = alpha;
+        self
+    }
+
+    #[inline]
+    /// Set the duration of the fade-in and fade-out, in seconds.
+    pub fn fade_duration(mut self, fade_duration: f32) -> Self {
+        self.fade_duration = fade_duration;
+        self
+    }
+}
+
+impl ProgressHud {
+    /// Show the HUD over the visible part of the ui.
+    ///
+    /// Returns `true` if the cancel button was clicked.
+    pub fn show(&self, ui: &Ui) -> bool {
+        let bounds = ui.max_rect().intersect(ui.clip_rect());
+        let area_layer = self.show_in(ui.ctx(), ui.layer_id().order, bounds);
+        if let Some((layer_id, _)) = area_layer {
+            ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+        }
+        area_layer.is_some_and(|(_, cancel)| cancel)
+    }
+
+    /// Show the HUD over the whole screen, above all the other layers.
+    ///
+    /// Returns `true` if the cancel button was clicked.
+    pub fn show_screen(&self, ctx: &Context) -> bool {
+        self.show_in(ctx, Order::Foreground, ctx.screen_rect())
+            .is_some_and(|(_, cancel)| cancel)
+    }
+
+    fn show_in(&self, ctx: &Context, order: Order, bounds: Rect) -> Option<(LayerId, bool)> {
+        // start hidden to fade in
+        let opacity = crate::fade_in_from_hidden(ctx, self.id, self.visible, self.fade_duration);
+        if !self.visible && opacity <= 0.0 {
+            return None;
+        }
+
+        let area_response = ModalArea::new(self.id, DimOverlay::new(bounds).alpha(self.dim_alpha))
+            .order(order)
+            .opacity(opacity)
+            .show(ctx, bounds, Layout::top_down(Align::Center), |ui| {
+                Frame::popup(ui.style())
+                    .show(ui, |ui| self.card(ui))
+                    .inner
+            });
+
+        Some((area_response.response.layer_id, area_response.inner.1.inner))
+    }
+
+    // the contents of the card, returns if the cancel button was clicked
+    fn card(&self, ui: &mut Ui) -> bool {
+        match self.progress {
+            Some(progress) => {
+                ui.add(ProgressBar::new(progress).desired_width(160.0));
+            },
+            None => {
+                ui.add(Spinner::new().size(24.0));
+            },
+        }
+        if !self.text.is_empty() {
+            ui.label(&self.text);
+        }
+        self.cancelable && ui.button("Cancel").clicked()
+    }
+}
@@ -0,0 +1,146 @@
+use std::hash::Hash;
+
+use egui::{Align, Color32, Context, Id, InnerResponse, Layout, Spinner, Ui};
+
+use crate::{overlay::ModalArea, DimOverlay};
+
+/// A screen covering the whole viewport with the panel background,
+/// with a spinner and the given contents, such as a message, centered on it.
+///
+/// The screen is shown on the foreground and takes the pointer input,
+/// so the widgets underneath are not hovered or clicked while it is visible.
+/// It fades in when it becomes visible and fades out when it is hidden,
+/// so keep calling [`LoadingScreen::show`] with [`LoadingScreen::visible`] set to `false`
+/// once the loading is over.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::LoadingScreen;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let loading = true;
+/// LoadingScreen::new("loading")
+///     .visible(loading)
+///     .show(ui.ctx(), |ui| {
+///         ui.label("Loading the project…");
+///     });
+/// # });
+/// ```
+pub struct LoadingScreen {
+    /// Used to animate the fading.
+    pub id: Id,
+
+    /// If the screen is visible, or fading out otherwise.
+    pub visible: bool,
+
+    /// The duration of the fade-in and fade-out, in seconds.
+    pub fade_duration: f32,
+
+    /// The size of the spinner.
+    /// If `None`, no spinner is shown.
+    pub spinner_size: Option<f32>,
+
+    /// The color of the background.
+    /// If `None`, use the panel fill of the visuals.
+    pub fill: Option<Color32>,
+}
+
+impl LoadingScreen {
+    #[inline]
+    /// Create a new visible loading screen with a spinner.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            visible: true,
+            fade_duration: 0.2,
+            spinner_size: Some(32.0),
+            fill: None,
+        }
+    }
+
+    #[inline]
+    /// Set whether the screen is visible, or fading out.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    #[inline]
+    /// Set the duration of the fade-in and fade-out, in seconds.
+    pub fn fade_duration(mut self, fade_duration: f32) -> Self {
+        self.fade_duration = fade_duration;
+        self
+    }
+
+    #[inline]
+    /// Set the size of the spinner.
+    pub fn spinner_size(mut self, size: f32) -> Self {
+        self.spinner_size = Some(size);
+        self
+    }
+
+    #[inline]
+    /// Hide the spinner.
+    pub fn no_spinner(mut self) -> Self {
+        self.spinner_size = None;
+        self
+    }
+
+    #[inline]
+    /// Set the color of the background.
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+}
+
+impl LoadingScreen {
+    /// Show the screen, if visible or fading out.
+    ///
+    /// Returns `None` once the screen is hidden and faded out.
+    /// The response covers the whole viewport.
+    pub fn show<R>(&self, ctx: &Context, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+        // start hidden to fade in
+        let opacity = crate::fade_in_from_hidden(ctx, self.id, self.visible, self.fade_duration);
+        if !self.visible && opacity <= 0.0 {
+            return None;
+        }
+
+        let screen_rect = ctx.screen_rect();
+        let fill = self.fill.unwrap_or(ctx.style().visuals.panel_fill);
+        let (response, contents) = ModalArea::new(self.id, DimOverlay::new(screen_rect).color(fill))
+            .opacity(opacity)
+            .show(ctx, screen_rect, Layout::top_down(Align::Center), |ui| {
+                if let Some(size) = self.spinner_size {
+                    ui.add(Spinner::new().size(size));
+                }
+                add_contents(ui)
+            })
+            .inner;
+
+        Some(InnerResponse { inner: contents.inner, response })
+    }
+}
+
+#[inline]
+/// Cover the whole viewport with a loading screen,
+/// centering a spinner and the contents on it
+///
+/// The screen fades in when first shown.
+/// To fade it out when the loading is over, use [`LoadingScreen`].
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::loading_screen;
+///
+/// # egui::__run_test_ui(|ui| {
+/// loading_screen(ui.ctx(), |ui| {
+///     ui.label("Connecting…");
+/// });
+/// # });
+/// ```
+pub fn loading_screen<R>(ctx: &Context, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+    LoadingScreen::new("egui_alignments::loading_screen").show(ctx, add_contents)
+}
@@ -0,0 +1,210 @@
+use egui::{Align2, Area, AreaState, Id, InnerResponse, LayerId, Margin, Response, Ui, Widget};
+
+/// Aligns contents within the rect of an existing response,
+/// on a layer directly above the one of the response,
+/// e.g. a spinner centered over an image being loaded, or a badge at the corner of a button.
+///
+/// The contents do not affect the layout of the ui the response was shown in,
+/// and they take the pointer input over the widget underneath where they are.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align2, Spinner};
+/// use egui_alignments::ResponseOverlay;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.add_enabled(false, egui::Button::new("Thumbnail").min_size([120.0, 80.0].into()));
+/// ResponseOverlay::new(Align2::CENTER_CENTER).show(&response, |ui| {
+///     ui.add(Spinner::new());
+/// });
+/// # });
+/// ```
+pub struct ResponseOverlay {
+    /// Used to store the area of the contents.
+    /// If `None`, derived from the id of the response.
+    pub id: Option<Id>,
+
+    /// The alignment of the contents within the rect of the response.
+    pub align: Align2,
+
+    /// The space kept between the contents and the edges of the rect.
+    pub margin: Margin,
+
+    /// The opacity of the contents.
+    pub opacity: f32,
+
+    /// If the contents take the pointer input.
+    pub interactable: bool,
+
+    /// The duration of the fade-in and fade-out of [`ResponseOverlay::show_on_hover`], in seconds.
+    pub fade_duration: f32,
+}
+
+impl ResponseOverlay {
+    #[inline]
+    /// Create a new overlay with the given alignment.
+    pub fn new(align: Align2) -> Self {
+        Self {
+            id: None,
+            align,
+            margin: Margin::ZERO,
+            opacity: 1.0,
+            interactable: true,
+            fade_duration: 0.15,
+        }
+    }
+
+    #[inline]
+    /// Set the id of the overlay.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the space kept between the contents and the edges of the rect.
+    pub fn margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the opacity of the contents.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    #[inline]
+    /// Set whether the contents take the pointer input.
+    pub fn interactable(mut self, interactable: bool) -> Self {
+        self.interactable = interactable;
+        self
+    }
+
+    #[inline]
+    /// Set the duration of the fade-in and fade-out of [`ResponseOverlay::show_on_hover`], in seconds.
+    pub fn fade_duration(mut self, fade_duration: f32) -> Self {
+        self.fade_duration = fade_duration;
+        self
+    }
+}
+
+impl ResponseOverlay {
+    // the id of the area of the contents
+    fn area_id(&self, response: &Response) -> Id {
+        self.id.unwrap_or(response.id.with("egui_alignments::overlay"))
+    }
+
+    /// Show the contents aligned within the rect of the response.
+    pub fn show<R>(&self, response: &Response, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let id = self.area_id(response);
+        let bounds = response.rect - self.margin;
+        let opacity = self.opacity;
+
+        let area_response = Area::new(id)
+            .order(response.layer_id.order)
+            .pivot(self.align)
+            // the contents are measured within the rect the first time
+            .default_size(bounds.size())
+            .fixed_pos(self.align.pos_in_rect(&bounds))
+            .constrain(false)
+            .interactable(self.interactable)
+            .show(&response.ctx, |ui| {
+                ui.multiply_opacity(opacity);
+                add_contents(ui)
+            });
+
+        // keep the contents right above the widget, even if its layer is brought to the top
+        response.ctx.set_sublayer(response.layer_id, area_response.response.layer_id);
+        area_response
+    }
+
+    /// Show the contents aligned within the rect of the response
+    /// only while the response or the contents are hovered, fading them in and out.
+    ///
+    /// Returns `None` while the contents are hidden.
+    pub fn show_on_hover<R>(&self, response: &Response, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+        let ctx = &response.ctx;
+        let id = self.area_id(response);
+
+        // the contents are above the widget, which is not hovered while they are
+        let layer_id = LayerId::new(response.layer_id.order, id);
+        let contents_hovered = ctx.input(|input| input.pointer.hover_pos())
+            .is_some_and(|pos| ctx.layer_id_at(pos) == Some(layer_id));
+        // `contains_pointer` is also true over the children of the widget, e.g. the buttons of a card
+        let visible = response.contains_pointer() || contents_hovered;
+
+        let opacity = ctx.animate_bool_with_time(id.with("hover"), visible, self.fade_duration);
+        if opacity <= 0.0 {
+            // measure the contents while hidden, as the area is invisible the first time it is shown
+            if AreaState::load(ctx, id).and_then(|state| state.size).is_none() {
+                Self::new(self.align)
+                    .id(id)
+                    .margin(self.margin)
+                    .interactable(false)
+                    .show(response, add_contents);
+            }
+            return None;
+        }
+
+        let overlay = Self {
+            id: Some(id),
+            align: self.align,
+            margin: self.margin,
+            opacity: self.opacity * opacity,
+            // not clicked while fading out
+            interactable: self.interactable && visible,
+            fade_duration: self.fade_duration,
+        };
+        Some(overlay.show(response, add_contents))
+    }
+}
+
+#[inline]
+/// Show a widget aligned within the rect of a response, on a layer above it.
+/// See [`ResponseOverlay`].
+///
+/// Returns the response of the overlaid widget.
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui::{Align2, Button};
+/// use egui_alignments::overlay_on;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.button("Inbox");
+/// overlay_on(&response, Align2::RIGHT_TOP, Button::new("3").small());
+/// # });
+/// ```
+pub fn overlay_on(response: &Response, align: Align2, widget: impl Widget) -> Response {
+    ResponseOverlay::new(align).show(response, |ui| ui.add(widget)).inner
+}
+
+#[inline]
+/// Show contents aligned within the rect of a response only while it is hovered,
+/// e.g. a delete button at the top right of a card. See [`ResponseOverlay::show_on_hover`].
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::hover_overlay;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let card = ui.group(|ui| {
+///     ui.label("Groceries");
+///     ui.label("Milk, eggs, bread");
+/// }).response;
+/// hover_overlay(&card, Align2::RIGHT_TOP, |ui| {
+///     if ui.small_button("🗑").clicked() {
+///         // delete the card
+///     }
+/// });
+/// # });
+/// ```
+pub fn hover_overlay<R>(response: &Response, align: Align2, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+    ResponseOverlay::new(align).show_on_hover(response, add_contents)
+}
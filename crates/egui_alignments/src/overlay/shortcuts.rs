@@ -0,0 +1,165 @@
+use std::hash::Hash;
+
+use egui::{vec2, Align, Color32, Context, FontSelection, Frame, Id, Key, Layout, RichText, TextStyle, Ui};
+
+use crate::{overlay::ModalArea, DimOverlay};
+
+/// A cheat sheet of keyboard shortcuts centered over the whole screen,
+/// in two columns: the keys aligned to the right and the descriptions to the left.
+///
+/// The sheet is toggled by a key, `?` by default, unless a widget takes the keyboard input,
+/// and closed by `Escape` or a click outside of it.
+/// The screen underneath is dimmed and does not take the pointer input while the sheet is open.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::ShortcutOverlay;
+///
+/// # egui::__run_test_ui(|ui| {
+/// ShortcutOverlay::new("shortcuts")
+///     .title("Editor shortcuts")
+///     .show(ui.ctx(), &[
+///         ("Ctrl+S", "Save"),
+///         ("Ctrl+Shift+P", "Open the command palette"),
+///         ("?", "Show this sheet"),
+///     ]);
+/// # });
+/// ```
+pub struct ShortcutOverlay {
+    /// Used to memorize if the sheet is open.
+    pub id: Id,
+
+    /// The key toggling the sheet.
+    /// If `None`, the sheet is only opened by [`ShortcutOverlay::set_open`].
+    pub toggle_key: Option<Key>,
+
+    /// The title above the shortcuts.
+    pub title: String,
+}
+
+impl ShortcutOverlay {
+    #[inline]
+    /// Create a new cheat sheet toggled by `?`.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            toggle_key: Some(Key::Questionmark),
+            title: "Keyboard shortcuts".to_owned(),
+        }
+    }
+
+    #[inline]
+    /// Set the key toggling the sheet.
+    pub fn toggle_key(mut self, key: Key) -> Self {
+        self.toggle_key = Some(key);
+        self
+    }
+
+    #[inline]
+    /// Do not toggle the sheet with a key.
+    pub fn no_toggle_key(mut self) -> Self {
+        self.toggle_key = None;
+        self
+    }
+
+    #[inline]
+    /// Set the title above the shortcuts.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+}
+
+impl ShortcutOverlay {
+    /// If the sheet with the given id is open.
+    pub fn is_open(ctx: &Context, id: Id) -> bool {
+        ctx.data(|data| data.get_temp::<bool>(id))
+            .unwrap_or(false)
+    }
+
+    /// Open or close the sheet with the given id.
+    pub fn set_open(ctx: &Context, id: Id, open: bool) {
+        ctx.data_mut(|data| data.insert_temp(id, open));
+    }
+
+    /// Handle the toggle key and show the sheet if open.
+    /// Call this once per frame.
+    ///
+    /// Returns `true` if the sheet is open.
+    pub fn show(&self, ctx: &Context, shortcuts: &[(&str, &str)]) -> bool {
+        let mut open = Self::is_open(ctx, self.id);
+        if let Some(key) = self.toggle_key {
+            if !ctx.wants_keyboard_input() && ctx.input(|input| input.key_pressed(key)) {
+                open = !open;
+            }
+        }
+        if open && ctx.input(|input| input.key_pressed(Key::Escape)) {
+            open = false;
+        }
+
+        if open {
+            let screen_rect = ctx.screen_rect();
+            let (backdrop, sheet) = ModalArea::new(self.id, DimOverlay::new(screen_rect))
+                .show(ctx, screen_rect, Layout::top_down(Align::Center), |ui| {
+                    Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.heading(&self.title);
+                        ui.add_space(ui.spacing().item_spacing.y);
+                        shortcut_rows(ui, shortcuts);
+                    }).response.rect
+                })
+                .inner;
+
+            // close on a click outside of the sheet, the backdrop is under the sheet too
+            let outside = backdrop.interact_pointer_pos().is_some_and(|pos| !sheet.inner.contains(pos));
+            if backdrop.clicked() && outside {
+                open = false;
+            }
+        }
+
+        Self::set_open(ctx, self.id, open);
+        open
+    }
+}
+
+// the shortcuts in two columns, the width of the keys column is the width of the widest keys
+fn shortcut_rows(ui: &mut Ui, shortcuts: &[(&str, &str)]) {
+    let font_id = FontSelection::Style(TextStyle::Body).resolve(ui.style());
+    let keys_width = ui.fonts(|fonts| {
+        shortcuts
+            .iter()
+            .map(|(keys, _)| fonts.layout_no_wrap(keys.to_string(), font_id.clone(), Color32::PLACEHOLDER).size().x)
+            .fold(0.0, f32::max)
+    });
+    let row_height = ui.spacing().interact_size.y;
+
+    for (keys, description) in shortcuts {
+        ui.horizontal(|ui| {
+            ui.allocate_ui_with_layout(
+                vec2(keys_width, row_height),
+                Layout::right_to_left(Align::Center),
+                |ui| ui.label(RichText::new(*keys).strong()),
+            );
+            ui.add_space(ui.spacing().item_spacing.x);
+            ui.label(*description);
+        });
+    }
+}
+
+#[inline]
+/// Show a cheat sheet of the keyboard shortcuts over the whole screen, toggled by `?`
+///
+/// Returns `true` if the sheet is open.
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::shortcut_overlay;
+///
+/// # egui::__run_test_ui(|ui| {
+/// shortcut_overlay(ui.ctx(), &[("Ctrl+Z", "Undo"), ("Ctrl+Y", "Redo")]);
+/// # });
+/// ```
+pub fn shortcut_overlay(ctx: &Context, shortcuts: &[(&str, &str)]) -> bool {
+    ShortcutOverlay::new("egui_alignments::shortcut_overlay").show(ctx, shortcuts)
+}
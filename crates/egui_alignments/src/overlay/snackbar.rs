@@ -0,0 +1,228 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use egui::{Align, Align2, Area, Context, Frame, Id, Order, Vec2};
+
+use crate::row;
+
+/// A message shown by a [`Snackbar`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnackbarMessage {
+    /// The text of the message.
+    pub text: String,
+
+    /// The label of the action button.
+    /// If `None`, no action button is shown.
+    pub action: Option<String>,
+
+    /// The time in seconds the message stays visible while not hovered.
+    /// If `None`, use the duration of the [`Snackbar`].
+    pub duration: Option<f32>,
+}
+
+impl SnackbarMessage {
+    #[inline]
+    /// Create a new message without an action.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            action: None,
+            duration: None,
+        }
+    }
+
+    #[inline]
+    /// Set the label of the action button.
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    #[inline]
+    /// Set the time in seconds the message stays visible while not hovered.
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+/// What happened to the visible message of a [`Snackbar`] this frame.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SnackbarEvent {
+    /// The action button of the message was clicked.
+    Action(SnackbarMessage),
+
+    /// The message was dismissed, either by timeout or by the close button.
+    Dismissed(SnackbarMessage),
+}
+
+#[derive(Clone, Debug, Default)]
+struct SnackbarState {
+    queue: VecDeque<SnackbarMessage>,
+    elapsed: f32,
+}
+
+/// A queue of messages shown one at a time, aligned to the screen.
+/// Each message may carry an action button and is dismissed automatically
+/// after its duration, which is paused while the message is hovered.
+///
+/// The queue is stored in egui memory,
+/// so messages can be pushed from anywhere with access to the [`Context`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{Snackbar, SnackbarEvent, SnackbarMessage};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let snackbar = Snackbar::new("snackbar");
+/// snackbar.push(ui.ctx(), SnackbarMessage::new("Saved").action("Open"));
+/// if let Some(SnackbarEvent::Action(message)) = snackbar.show(ui.ctx()) {
+///     println!("{} clicked", message.action.unwrap());
+/// }
+/// # });
+/// ```
+pub struct Snackbar {
+    /// Used to store the message queue.
+    pub id: Id,
+
+    /// The alignment of the visible message relative to the screen.
+    /// Default: [`Align2::CENTER_BOTTOM`].
+    /// At the bottom, the message stays above the on-screen keyboard, see [`crate::keyboard`].
+    pub anchor: Align2,
+
+    /// The distance between the message and the screen edges it is aligned to.
+    pub margin: Vec2,
+
+    /// The default time in seconds a message stays visible while not hovered.
+    pub duration: f32,
+
+    /// If `true`, show a close button next to the action button.
+    pub closable: bool,
+}
+
+impl Snackbar {
+    #[inline]
+    /// Create a new snackbar aligned to the bottom center of the screen.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            anchor: Align2::CENTER_BOTTOM,
+            margin: Vec2::splat(16.0),
+            duration: 4.0,
+            closable: false,
+        }
+    }
+
+    #[inline]
+    /// Set the alignment of the visible message relative to the screen.
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    #[inline]
+    /// Set the distance between the message and the screen edges.
+    pub fn margin(mut self, margin: impl Into<Vec2>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the default time in seconds a message stays visible.
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    #[inline]
+    /// Set whether to show a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+impl Snackbar {
+    /// Append a message to the end of the queue.
+    pub fn push(&self, ctx: &Context, message: SnackbarMessage) {
+        ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<SnackbarState>(self.id)
+                .queue
+                .push_back(message)
+        });
+        ctx.request_repaint();
+    }
+
+    /// Remove all the messages, including the visible one.
+    pub fn clear(&self, ctx: &Context) {
+        ctx.data_mut(|data| data.remove::<SnackbarState>(self.id));
+    }
+
+    /// The number of messages waiting in the queue, including the visible one.
+    pub fn len(&self, ctx: &Context) -> usize {
+        ctx.data(|data| data.get_temp::<SnackbarState>(self.id))
+            .map_or(0, |state| state.queue.len())
+    }
+
+    /// If there is no message to show.
+    pub fn is_empty(&self, ctx: &Context) -> bool {
+        self.len(ctx) == 0
+    }
+
+    /// Show the first message of the queue.
+    /// Call this once per frame after all the messages of the frame are pushed.
+    pub fn show(&self, ctx: &Context) -> Option<SnackbarEvent> {
+        let mut state: SnackbarState = ctx.data(|data| data.get_temp(self.id))?;
+        let message = state.queue.front()?.clone();
+
+        let mut offset = -self.anchor.to_sign() * self.margin;
+        if self.anchor.y() == Align::Max {
+            offset.y -= crate::keyboard_inset(ctx);
+        }
+        let mut action_clicked = false;
+        let mut close_clicked = false;
+
+        let response = Area::new(self.id.with("area"))
+            .order(Order::Foreground)
+            .anchor(self.anchor, offset)
+            .interactable(true)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    row(ui, Align::Center, |ui| {
+                        ui.label(&message.text);
+                        if let Some(action) = &message.action {
+                            action_clicked = ui.button(action).clicked();
+                        }
+                        if self.closable {
+                            close_clicked = ui.small_button("✖").clicked();
+                        }
+                    });
+                });
+            })
+            .response;
+
+        // pause the timer while hovered
+        if !response.contains_pointer() {
+            state.elapsed += ctx.input(|input| input.stable_dt);
+        }
+
+        let duration = message.duration.unwrap_or(self.duration);
+        let event = if action_clicked {
+            Some(SnackbarEvent::Action(message))
+        } else if close_clicked || state.elapsed >= duration {
+            Some(SnackbarEvent::Dismissed(message))
+        } else {
+            ctx.request_repaint();
+            None
+        };
+
+        if event.is_some() {
+            state.queue.pop_front();
+            state.elapsed = 0.0;
+            ctx.request_repaint();
+        }
+        ctx.data_mut(|data| data.insert_temp(self.id, state));
+
+        event
+    }
+}
@@ -0,0 +1,152 @@
+use egui::{Context, FontSelection, Id, LayerId, Order, Rect, TextStyle, Vec2};
+
+use crate::{place_beside, Edge, DimOverlay};
+
+/// A highlight of a target rect: the whole screen is dimmed
+/// except a rounded cut-out around the target,
+/// with an optional hint placed next to the cut-out.
+///
+/// The spotlight is only painted, on the foreground,
+/// so it does not take the pointer input.
+/// For a sequence of explanations with controls, see [`crate::CoachMarks`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{Edge, Spotlight};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.button("Sync");
+/// Spotlight::new(response.rect)
+///     .hint("New: sync your settings across devices")
+///     .hint_side(Edge::Right)
+///     .show(ui.ctx());
+/// # });
+/// ```
+pub struct Spotlight {
+    /// The rect to highlight, in screen space.
+    pub rect: Rect,
+
+    /// The space between the target and the edge of the cut-out.
+    pub padding: f32,
+
+    /// The corner radius of the cut-out.
+    pub corner_radius: f32,
+
+    /// The opacity of the dim layer, from `0.0` to `1.0`.
+    pub alpha: f32,
+
+    /// The hint placed next to the cut-out.
+    pub hint: Option<String>,
+
+    /// The side of the cut-out on which the hint is placed if there is room.
+    pub hint_side: Edge,
+}
+
+impl Spotlight {
+    #[inline]
+    /// Create a new spotlight over the given rect, without a hint.
+    pub fn new(rect: Rect) -> Self {
+        Self {
+            rect,
+            padding: 4.0,
+            corner_radius: 6.0,
+            alpha: 0.6,
+            hint: None,
+            hint_side: Edge::Bottom,
+        }
+    }
+
+    #[inline]
+    /// Set the space between the target and the edge of the cut-out.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    #[inline]
+    /// Set the corner radius of the cut-out.
+    pub fn corner_radius(mut self, corner_radius: f32) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    #[inline]
+    /// Set the opacity of the dim layer, from `0.0` to `1.0`.
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    #[inline]
+    /// Set the hint placed next to the cut-out.
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    #[inline]
+    /// Set the side of the cut-out on which the hint is placed if there is room.
+    pub fn hint_side(mut self, side: Edge) -> Self {
+        self.hint_side = side;
+        self
+    }
+}
+
+impl Spotlight {
+    /// Paint the spotlight over the whole screen.
+    pub fn show(&self, ctx: &Context) {
+        let screen_rect = ctx.screen_rect();
+        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("egui_alignments::spotlight")));
+        let cutout = self.rect.expand(self.padding);
+
+        DimOverlay::new(screen_rect)
+            .cutout(cutout)
+            .cutout_radius(self.corner_radius)
+            .alpha(self.alpha)
+            .paint_with(&painter);
+
+        let Some(hint) = &self.hint else {
+            return;
+        };
+
+        let style = ctx.style();
+        let margin = Vec2::splat(6.0);
+        let gap = 8.0;
+        let galley = painter.layout(
+            hint.clone(),
+            FontSelection::Style(TextStyle::Body).resolve(&style),
+            style.visuals.text_color(),
+            240.0,
+        );
+        let hint_rect = place_beside(
+            cutout,
+            galley.size() + 2.0 * margin,
+            screen_rect.shrink(gap),
+            gap,
+            self.hint_side,
+        );
+
+        painter.rect_filled(hint_rect, self.corner_radius, style.visuals.window_fill);
+        painter.galley(hint_rect.min + margin, galley, style.visuals.text_color());
+    }
+}
+
+#[inline]
+/// Dim the whole screen except a rounded cut-out around the rect, `padding` away from it
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui_alignments::spotlight;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.label("Look here");
+/// spotlight(ui.ctx(), response.rect, 6.0);
+/// # });
+/// ```
+pub fn spotlight(ctx: &Context, rect: Rect, padding: f32) {
+    Spotlight::new(rect)
+        .padding(padding)
+        .show(ctx);
+}
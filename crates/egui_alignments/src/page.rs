@@ -0,0 +1,122 @@
+use egui::{vec2, Align, InnerResponse, Layout, Margin, Rect, Sense, Ui, UiBuilder, UiStackInfo, Vec2};
+
+// the tag of the uis of the pages, holding the rect of the page
+const PAGE_TAG: &str = "egui_alignments::page";
+
+/// A fixed logical page, e.g. A4 at a given DPI, which the contents are laid out against
+/// instead of the live window, to render reports for an image or PDF exporter.
+///
+/// Within a page, the alignments ignore the screen:
+/// [`Bounds::SafeArea`](crate::Bounds::SafeArea) is the same as [`Bounds::MaxRect`](crate::Bounds::MaxRect),
+/// the on-screen keyboard is not avoided, and the [`units`](crate::units) are fractions of the page.
+///
+/// The page is allocated at its exact size, even if it does not fit in the ui,
+/// so the layout is the same whatever the size of the window.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{Page, WidgetAligner};
+///
+/// # egui::__run_test_ui(|ui| {
+/// Page::a4(96.0).margin(48.0).show(ui, |ui| {
+///     ui.heading("Monthly report");
+///     WidgetAligner::right_bottom().show(ui, |ui| {
+///         ui.label("Page 1");
+///     });
+/// });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Page {
+    /// The size of the page, in points.
+    pub size: Vec2,
+
+    /// The margin between the edges of the page and the contents.
+    pub margin: Margin,
+
+    /// The layout of the contents.
+    pub layout: Layout,
+}
+
+// millimeters per inch
+const MM_PER_INCH: f32 = 25.4;
+
+impl Page {
+    #[inline]
+    /// Create a new page of the given size, in points.
+    pub fn new(size: impl Into<Vec2>) -> Self {
+        Self {
+            size: size.into(),
+            margin: Margin::ZERO,
+            layout: Layout::top_down(Align::Min),
+        }
+    }
+
+    #[inline]
+    /// Create a new page of the given size in millimeters, one point being one dot at the given DPI.
+    pub fn from_mm(width: f32, height: f32, dpi: f32) -> Self {
+        Self::new(vec2(width, height) * (dpi / MM_PER_INCH))
+    }
+
+    #[inline]
+    /// Create a new portrait A4 page, 210 × 297 mm, at the given DPI.
+    pub fn a4(dpi: f32) -> Self {
+        Self::from_mm(210.0, 297.0, dpi)
+    }
+
+    #[inline]
+    /// Create a new portrait US Letter page, 8.5 × 11 in, at the given DPI.
+    pub fn letter(dpi: f32) -> Self {
+        Self::new(vec2(8.5, 11.0) * dpi)
+    }
+
+    #[inline]
+    /// Turn the page so its width is its larger side.
+    pub fn landscape(mut self) -> Self {
+        if self.size.x < self.size.y {
+            self.size = vec2(self.size.y, self.size.x);
+        }
+        self
+    }
+
+    #[inline]
+    /// Set the margin between the edges of the page and the contents.
+    pub fn margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    #[inline]
+    /// Set the layout of the contents.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Show the page at the next widget position of the ui, with the contents laid out in it.
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let (rect, response) = ui.allocate_exact_size(self.size, Sense::hover());
+        let mut page_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(rect - self.margin)
+                .layout(self.layout)
+                .ui_stack_info(UiStackInfo::default().with_tag_value(PAGE_TAG, rect))
+        );
+        page_ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+        let inner = add_contents(&mut page_ui);
+        InnerResponse { inner, response }
+    }
+}
+
+/// The rect of the [`Page`] the ui is laid out in, if any.
+pub fn page_rect(ui: &Ui) -> Option<Rect> {
+    ui.stack()
+        .iter()
+        .find_map(|frame| frame.info.tags.get_downcast::<Rect>(PAGE_TAG).copied())
+}
+
+/// The rect the ui is shown on: the [`Page`] it is laid out in, or the screen otherwise.
+pub fn screen_or_page_rect(ui: &Ui) -> Rect {
+    page_rect(ui).unwrap_or(ui.ctx().screen_rect())
+}
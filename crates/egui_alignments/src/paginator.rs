@@ -0,0 +1,178 @@
+use std::{hash::Hash, ops::Range};
+
+use egui::{Align, Button, Context, Id, Rect, Response, Ui, UiBuilder};
+
+use crate::{measure::MEASURE_ID_SALT, Page, Row};
+
+/// Splits a long sequence of items into [`Page`]s, never splitting an item,
+/// and shows one page at a time, e.g. for a print preview or a kiosk slideshow.
+///
+/// The height of each item is memorized. The items never shown are measured
+/// in an invisible pass at the width of the page, so the pages are known from the first frame.
+/// An item taller than a page is alone on its page.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::{Page, Paginator};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let paginator = Paginator::new("report", Page::a4(96.0).margin(48.0));
+/// paginator.show_navigation(ui);
+/// let response = paginator.show(ui, 200, |ui, index| {
+///     ui.label(format!("Line {}", index + 1));
+/// });
+/// if response.page + 1 == response.page_count {
+///     // e.g. enable the print button
+/// }
+/// # });
+/// ```
+pub struct Paginator {
+    /// Used to memorize the current page and the heights of the items.
+    pub id: Id,
+
+    /// The page the items are laid out in.
+    pub page: Page,
+}
+
+/// The response of [`Paginator::show`].
+pub struct PaginatorResponse {
+    /// The index of the page shown.
+    pub page: usize,
+
+    /// The number of pages.
+    pub page_count: usize,
+
+    /// The indices of the items on the page shown.
+    pub items: Range<usize>,
+
+    /// The response of the page.
+    pub response: Response,
+}
+
+// the indices of the first items of the pages, given the heights of the items
+fn page_starts(heights: &[f32], max_height: f32, spacing: f32) -> Vec<usize> {
+    let mut starts = vec![0];
+    let mut used = 0.0;
+    for (index, height) in heights.iter().enumerate() {
+        if index == *starts.last().unwrap() {
+            used = *height;
+        } else if used + spacing + height > max_height {
+            starts.push(index);
+            used = *height;
+        } else {
+            used += spacing + height;
+        }
+    }
+    starts
+}
+
+impl Paginator {
+    #[inline]
+    /// Create a new paginator laying out the items in the given page.
+    pub fn new(id_salt: impl Hash, page: Page) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            page,
+        }
+    }
+
+    /// The index of the page shown by the paginator with the given id.
+    pub fn current_page(ctx: &Context, id: Id) -> usize {
+        ctx.data(|data| data.get_temp::<usize>(id.with("page"))).unwrap_or(0)
+    }
+
+    /// Set the page shown by the paginator with the given id.
+    /// It is clamped to the last page when shown.
+    pub fn set_page(ctx: &Context, id: Id, page: usize) {
+        ctx.data_mut(|data| data.insert_temp(id.with("page"), page));
+    }
+
+    /// The number of pages of the paginator with the given id, when it was last shown.
+    pub fn page_count(ctx: &Context, id: Id) -> Option<usize> {
+        ctx.data(|data| data.get_temp::<usize>(id.with("page_count")))
+    }
+
+    /// Show the items of the current page, `add_item` being called with the index of each item.
+    pub fn show(&self, ui: &mut Ui, count: usize, mut add_item: impl FnMut(&mut Ui, usize)) -> PaginatorResponse {
+        let ctx = ui.ctx().clone();
+        let heights_id = self.id.with("item_heights");
+        let mut heights: Vec<Option<f32>> = ctx.data(|data| data.get_temp(heights_id)).unwrap_or_default();
+        heights.resize(count, None);
+        let content_size = self.page.size - self.page.margin.sum();
+        let spacing = ui.spacing().item_spacing.y;
+
+        // measure the items never shown, at the width of the page
+        if heights.iter().any(Option::is_none) {
+            let mut measure_ui = ui.new_child(
+                UiBuilder::new()
+                    .id_salt(MEASURE_ID_SALT)
+                    .max_rect(Rect::from_min_size(ui.next_widget_position(), content_size))
+                    .layout(self.page.layout)
+                    .sizing_pass()
+                    .invisible()
+            );
+            for (index, height) in heights.iter_mut().enumerate() {
+                if height.is_none() {
+                    let response = measure_ui.scope(|ui| add_item(ui, index)).response;
+                    *height = Some(response.rect.height());
+                }
+            }
+        }
+
+        let mut heights: Vec<f32> = heights.into_iter().map(|height| height.unwrap_or(0.0)).collect();
+        let starts = page_starts(&heights, content_size.y, spacing);
+        let page_count = starts.len();
+        let page = Self::current_page(&ctx, self.id).min(page_count - 1);
+        let items = starts[page]..starts.get(page + 1).copied().unwrap_or(count);
+
+        let response = self.page.show(ui, |ui| {
+            let mut changed = false;
+            for index in items.clone() {
+                let height = ui.scope(|ui| add_item(ui, index)).response.rect.height();
+                if (height - heights[index]).abs() > 0.5 {
+                    heights[index] = height;
+                    changed = true;
+                }
+            }
+            changed
+        });
+        if response.inner {
+            // the page breaks moved
+            crate::request_discard(&ctx, "Paginator item resized");
+        }
+
+        ctx.data_mut(|data| {
+            data.insert_temp(heights_id, heights.into_iter().map(Some).collect::<Vec<_>>());
+            data.insert_temp(self.id.with("page"), page);
+            data.insert_temp(self.id.with("page_count"), page_count);
+        });
+
+        PaginatorResponse {
+            page,
+            page_count,
+            items,
+            response: response.response,
+        }
+    }
+
+    /// Show buttons to go to the previous and the next page, and the current page number.
+    /// The number of pages is the one of the last time the paginator was shown.
+    pub fn show_navigation(&self, ui: &mut Ui) -> Response {
+        let ctx = ui.ctx().clone();
+        let page_count = Self::page_count(&ctx, self.id).unwrap_or(1);
+        let page = Self::current_page(&ctx, self.id).min(page_count - 1);
+        Row::new(Align::Center)
+            .id(self.id.with("navigation"))
+            .show(ui, |ui| {
+                if ui.add_enabled(page > 0, Button::new("⏴")).clicked() {
+                    Self::set_page(&ctx, self.id, page - 1);
+                }
+                ui.label(format!("{} / {}", page + 1, page_count));
+                if ui.add_enabled(page + 1 < page_count, Button::new("⏵")).clicked() {
+                    Self::set_page(&ctx, self.id, page + 1);
+                }
+            })
+            .response
+    }
+}
@@ -0,0 +1,114 @@
+//! Centering within the panels of the context
+
+use egui::{Align, Align2, InnerResponse, Layout, Rect, Sense, Ui, UiBuilder, UiKind};
+
+use crate::{Bounds, WidgetAligner};
+
+/// Center the contents vertically in the side panel the ui belongs to.
+///
+/// The contents are centered within the whole height of the panel,
+/// not within the inner margins of its frame, so they stay centered
+/// if the margins are not symmetric.
+/// Horizontally, they are aligned according to the layout of the ui.
+///
+/// Outside of a side panel, the contents are centered vertically in the ui.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::center_in_side_panel;
+///
+/// # let ctx = egui::Context::default();
+/// # let _ = ctx.run(Default::default(), |ctx| {
+/// egui::SidePanel::left("nav").show(ctx, |ui| {
+///     center_in_side_panel(ui, |ui| {
+///         let _ = ui.selectable_label(true, "Home");
+///         let _ = ui.selectable_label(false, "Settings");
+///     });
+/// });
+/// # });
+/// ```
+pub fn center_in_side_panel<R>(
+    ui: &mut Ui,
+    add_contents: impl FnOnce(&mut Ui) -> R
+) -> InnerResponse<R> {
+    let max_rect = ui.max_rect();
+    let panel_rect = panel_rect(ui, &[UiKind::LeftPanel, UiKind::RightPanel]).unwrap_or(max_rect);
+    let rect = Rect::from_x_y_ranges(max_rect.x_range(), panel_rect.y_range());
+    let layout = *ui.layout();
+    let align = Align2([layout.horizontal_align(), Align::Center]);
+
+    center_in_rect(ui, rect, align, layout, add_contents)
+}
+
+/// Center the contents in the top or bottom panel the ui belongs to,
+/// laid out left to right.
+///
+/// The contents are centered within the whole panel, not within the inner margins of its frame,
+/// leaving out the separator line between the panel and the central panel.
+///
+/// Outside of a top or bottom panel, the contents are centered in the ui.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::center_in_top_panel;
+///
+/// # let ctx = egui::Context::default();
+/// # let _ = ctx.run(Default::default(), |ctx| {
+/// egui::TopBottomPanel::top("header").show(ctx, |ui| {
+///     center_in_top_panel(ui, |ui| {
+///         ui.heading("Inbox");
+///         ui.label("(3)");
+///     });
+/// });
+/// # });
+/// ```
+pub fn center_in_top_panel<R>(
+    ui: &mut Ui,
+    add_contents: impl FnOnce(&mut Ui) -> R
+) -> InnerResponse<R> {
+    let separator = ui.visuals().widgets.noninteractive.bg_stroke.width;
+    let mut rect = ui.max_rect();
+    if let Some(panel_rect) = panel_rect(ui, &[UiKind::TopPanel]) {
+        rect = panel_rect;
+        rect.max.y -= separator;
+    } else if let Some(panel_rect) = panel_rect(ui, &[UiKind::BottomPanel]) {
+        rect = panel_rect;
+        rect.min.y += separator;
+    }
+    let layout = if ui.layout().prefer_right_to_left() {
+        Layout::right_to_left(Align::Center)
+    } else {
+        Layout::left_to_right(Align::Center)
+    };
+
+    center_in_rect(ui, rect, Align2::CENTER_CENTER, layout, add_contents)
+}
+
+// the rect of the closest panel of the given kinds containing the ui
+fn panel_rect(ui: &Ui, kinds: &[UiKind]) -> Option<Rect> {
+    ui.stack()
+        .iter()
+        .find(|frame| frame.kind().is_some_and(|kind| kinds.contains(&kind)))
+        .map(|frame| frame.max_rect)
+}
+
+fn center_in_rect<R>(
+    ui: &mut Ui,
+    rect: Rect,
+    align: Align2,
+    layout: Layout,
+    add_contents: impl FnOnce(&mut Ui) -> R
+) -> InnerResponse<R> {
+    let mut panel_ui = ui.new_child(UiBuilder::new().max_rect(rect).layout(layout));
+    let inner = WidgetAligner::from_align(align)
+        .bounds(Bounds::max_rect())
+        .layout(layout)
+        .show(&mut panel_ui, add_contents)
+        .inner;
+
+    // only allocate within the ui, or the panel would grow by its margins every frame
+    let response = ui.allocate_rect(rect.intersect(ui.max_rect()), Sense::hover());
+    InnerResponse { inner, response }
+}
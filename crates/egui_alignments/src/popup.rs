@@ -0,0 +1,108 @@
+//! Popups anchored to aligned widgets
+//!
+//! A widget inside an aligner or container is not at its final place during a sizing pass,
+//! so a popup anchored to it would show up at the wrong place.
+//! The sizing pass is usually discarded and run again in the same frame,
+//! but if the pass limit of the frame is reached, its output is shown.
+//! These helpers do not show the popups in such a pass, and keep them open for the next one.
+
+use std::hash::Hash;
+
+use egui::{ComboBox, Id, InnerResponse, PopupCloseBehavior, Response, Ui, WidgetText};
+
+// whether the contents of the ui are shown in a pass whose output is not discarded,
+// before they are at their final place
+fn is_misplaced(ui: &Ui) -> bool {
+    ui.is_sizing_pass() && !ui.ctx().will_discard()
+}
+
+/// Show a popup below a widget, as wide as the widget,
+/// once the widget is at its final aligned place.
+///
+/// Open the popup with `ui.memory_mut(|mem| mem.toggle_popup(popup_id))`.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, PopupCloseBehavior};
+/// use egui_alignments::{popup_below_aligned, row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// row(ui, Align::Center, |ui| {
+///     let popup_id = ui.make_persistent_id("actions");
+///     let response = ui.button("Actions");
+///     if response.clicked() {
+///         ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+///     }
+///     popup_below_aligned(ui, popup_id, &response, PopupCloseBehavior::CloseOnClick, |ui| {
+///         ui.label("Rename");
+///         ui.label("Delete");
+///     });
+/// });
+/// # });
+/// ```
+pub fn popup_below_aligned<R>(
+    ui: &Ui,
+    popup_id: Id,
+    anchor: &Response,
+    close_behavior: PopupCloseBehavior,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    if is_misplaced(ui) {
+        return None;
+    }
+
+    egui::popup_below_widget(ui, popup_id, anchor, close_behavior, add_contents)
+}
+
+/// Show a [`ComboBox`] whose popup is anchored to the final aligned place of the combo box.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{aligned_combo_box, row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let mut unit = "cm";
+/// row(ui, Align::Center, |ui| {
+///     ui.label("Unit");
+///     aligned_combo_box(ui, "unit", unit, |ui| {
+///         for option in ["mm", "cm", "m"] {
+///             ui.selectable_value(&mut unit, option, option);
+///         }
+///     });
+/// });
+/// # });
+/// ```
+pub fn aligned_combo_box<R>(
+    ui: &mut Ui,
+    id_salt: impl Hash,
+    selected_text: impl Into<WidgetText>,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<Option<R>> {
+    let button_id = ui.make_persistent_id(&id_salt);
+    let hidden = is_misplaced(ui) && ComboBox::is_open(ui.ctx(), button_id);
+    if hidden {
+        // close the popup for this pass only
+        ui.memory_mut(|mem| mem.close_popup());
+    }
+
+    let response = ComboBox::from_id_salt(id_salt)
+        .selected_text(selected_text)
+        .show_ui(ui, add_contents);
+
+    if hidden {
+        ui.memory_mut(|mem| {
+            if response.response.clicked() {
+                // the click meant to close the popup
+                mem.close_popup();
+            } else {
+                // the popup id of the combo box
+                mem.open_popup(button_id.with("popup"));
+            }
+        });
+    }
+
+    response
+}
@@ -0,0 +1,27 @@
+//! The traits, common aligners and containers, and alignment constants,
+//! to import them all at once without importing everything at the crate root.
+//!
+//! # Example
+//! ```
+//! # use egui_alignments::egui;
+//! use egui_alignments::prelude::*;
+//!
+//! # egui::__run_test_ui(|ui| {
+//! ui.row(Align::Center, |ui| {
+//!     ui.label("Volume");
+//!     egui::Button::new("Mute").align_self(Align::Max).ui(ui);
+//! });
+//! WidgetAligner::from_align(Align2::RIGHT_BOTTOM).show(ui, |ui| {
+//!     ui.button("Apply");
+//! });
+//! # });
+//! ```
+
+pub use egui::{Align, Align2, Widget};
+
+pub use crate::{
+    center_horizontal, center_vertical, column, row, AlignedResponse, AlignedWidget, Aligner, Alignment,
+    AllocateType, Bounds, Column, GenericContainer, Row, UiAlignExt, WidgetAligner,
+};
+
+pub use crate::presets::Compass;
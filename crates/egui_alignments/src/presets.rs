@@ -0,0 +1,141 @@
+//! Ready-made aligners for common art-directed placements
+//!
+//! - [`FocalPoint`]s on the rule-of-thirds intersections and on the golden-ratio points of the bounds.
+//! - [`Compass`] positions, which can be moved away from the edges with [`Compass::inset`].
+//!
+//! # Example
+//! ```
+//! # use egui_alignments::egui;
+//! use egui_alignments::{presets, WidgetAligner};
+//!
+//! # egui::__run_test_ui(|ui| {
+//! WidgetAligner::from_align(presets::THIRDS_LEFT_TOP)
+//!     .show(ui, |ui| {
+//!         ui.heading("Summer sale");
+//!     });
+//!
+//! WidgetAligner::from_align(presets::Compass::SE.inset(16.0))
+//!     .show(ui, |ui| {
+//!         ui.label("v1.2.0");
+//!     });
+//! # });
+//! ```
+
+use egui::{Align2, Margin, Rect, Vec2};
+
+use crate::Aligner;
+
+/// Centers the item on a point of the bounds, given as fractions of their size,
+/// `(0, 0)` being the left top and `(1, 1)` the right bottom,
+/// and keeps it within the bounds.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FocalPoint(pub Vec2);
+
+impl Aligner for FocalPoint {
+    fn align(self, item_size: Vec2, bounds: Rect) -> Rect {
+        let center = bounds.min + bounds.size() * self.0;
+        crate::keep_within(Rect::from_center_size(center, item_size), bounds)
+    }
+}
+
+const THIRD: f32 = 1.0 / 3.0;
+
+/// The left top intersection of the rule of thirds.
+pub const THIRDS_LEFT_TOP: FocalPoint = FocalPoint(Vec2::new(THIRD, THIRD));
+
+/// The right top intersection of the rule of thirds.
+pub const THIRDS_RIGHT_TOP: FocalPoint = FocalPoint(Vec2::new(1.0 - THIRD, THIRD));
+
+/// The left bottom intersection of the rule of thirds.
+pub const THIRDS_LEFT_BOTTOM: FocalPoint = FocalPoint(Vec2::new(THIRD, 1.0 - THIRD));
+
+/// The right bottom intersection of the rule of thirds.
+pub const THIRDS_RIGHT_BOTTOM: FocalPoint = FocalPoint(Vec2::new(1.0 - THIRD, 1.0 - THIRD));
+
+// the smaller part of a length divided in the golden ratio
+const GOLDEN: f32 = 0.381_966;
+
+/// The left top point dividing the bounds in the golden ratio.
+pub const GOLDEN_LEFT_TOP: FocalPoint = FocalPoint(Vec2::new(GOLDEN, GOLDEN));
+
+/// The right top point dividing the bounds in the golden ratio.
+pub const GOLDEN_RIGHT_TOP: FocalPoint = FocalPoint(Vec2::new(1.0 - GOLDEN, GOLDEN));
+
+/// The left bottom point dividing the bounds in the golden ratio.
+pub const GOLDEN_LEFT_BOTTOM: FocalPoint = FocalPoint(Vec2::new(GOLDEN, 1.0 - GOLDEN));
+
+/// The right bottom point dividing the bounds in the golden ratio.
+pub const GOLDEN_RIGHT_BOTTOM: FocalPoint = FocalPoint(Vec2::new(1.0 - GOLDEN, 1.0 - GOLDEN));
+
+/// The positions of a compass, north being the top of the bounds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+    Center,
+}
+
+impl Compass {
+    /// The [`Align2`] of the position.
+    pub fn align2(self) -> Align2 {
+        match self {
+            Compass::N => Align2::CENTER_TOP,
+            Compass::NE => Align2::RIGHT_TOP,
+            Compass::E => Align2::RIGHT_CENTER,
+            Compass::SE => Align2::RIGHT_BOTTOM,
+            Compass::S => Align2::CENTER_BOTTOM,
+            Compass::SW => Align2::LEFT_BOTTOM,
+            Compass::W => Align2::LEFT_CENTER,
+            Compass::NW => Align2::LEFT_TOP,
+            Compass::Center => Align2::CENTER_CENTER,
+        }
+    }
+
+    #[inline]
+    /// Keep the item the given margin away from the edges of the bounds.
+    pub fn inset(self, inset: impl Into<Margin>) -> Inset<Compass> {
+        Inset::new(self, inset)
+    }
+}
+
+impl Aligner for Compass {
+    fn align(self, item_size: Vec2, bounds: Rect) -> Rect {
+        self.align2().align(item_size, bounds)
+    }
+}
+
+/// Aligns within the bounds shrunk by a margin, with another aligner.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inset<T> {
+    /// The aligner used within the shrunk bounds.
+    pub aligner: T,
+
+    /// The margin removed from the bounds.
+    pub margin: Margin,
+}
+
+impl<T: Aligner> Inset<T> {
+    #[inline]
+    /// Align with the given aligner, the given margin away from the edges of the bounds.
+    pub fn new(aligner: T, margin: impl Into<Margin>) -> Self {
+        Self {
+            aligner,
+            margin: margin.into(),
+        }
+    }
+}
+
+impl<T: Aligner> Aligner for Inset<T> {
+    fn align(self, item_size: Vec2, bounds: Rect) -> Rect {
+        self.aligner.align(item_size, bounds - self.margin)
+    }
+}
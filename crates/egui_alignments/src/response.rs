@@ -0,0 +1,255 @@
+//! Responses of the aligners and containers
+
+use egui::{InnerResponse, Margin, Rect, Response, Vec2};
+
+/// An axis of the layout.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// The horizontal axis.
+    X,
+
+    /// The vertical axis.
+    Y,
+}
+
+/// A callback invoked with the axis and the amount by which
+/// the contents exceeded their bounds.
+/// See [`AlignedResponse::overflow`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::Column;
+///
+/// # egui::__run_test_ui(|ui| {
+/// Column::new(Align::Min)
+///     .max_width(100.0)
+///     .on_overflow(|axis, amount| {
+///         if cfg!(debug_assertions) {
+///             eprintln!("sidebar overflowed on {axis:?} by {amount}");
+///         }
+///     })
+///     .show(ui, |ui| {
+///         ui.label("A label that is a bit too long for the sidebar");
+///     });
+/// # });
+/// ```
+pub type OverflowCallback = Box<dyn Fn(Axis, f32)>;
+
+/// A callback invoked with the old and the new size of the contents
+/// when the memorized size of an aligner or a container changes.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::Row;
+///
+/// # egui::__run_test_ui(|ui| {
+/// Row::new(Align::Center)
+///     .on_resize(|old, new| {
+///         if cfg!(debug_assertions) {
+///             eprintln!("toolbar resized from {old:?} to {new:?}");
+///         }
+///     })
+///     .show(ui, |ui| {
+///         ui.button("Open");
+///         ui.button("Save");
+///     });
+/// # });
+/// ```
+pub type ResizeCallback = Box<dyn Fn(Vec2, Vec2)>;
+
+/// The result of showing a [`crate::WidgetAligner`] or a container.
+/// Like [`InnerResponse`], with details about the layout.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui_alignments::WidgetAligner;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let aligned = WidgetAligner::center().show(ui, |ui| ui.button("Buy"));
+/// if !aligned.sizing_pass && aligned.inner.clicked() {
+///     // only count real clicks
+/// }
+/// # });
+/// ```
+///
+/// Switch to a compact presentation when the contents do not fit:
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::Row;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let id = ui.id().with("toolbar");
+/// let compact = ui.data(|data| data.get_temp(id).unwrap_or(false));
+/// let toolbar = Row::new(Align::Center)
+///     .max_width(200.0)
+///     .show(ui, |ui| {
+///         for label in ["Cut", "Copy", "Paste", "Select all"] {
+///             if compact {
+///                 ui.small_button(&label[..1]);
+///             } else {
+///                 ui.button(label);
+///             }
+///         }
+///     });
+/// if !compact && toolbar.overflowed_x() {
+///     ui.data_mut(|data| data.insert_temp(id, true));
+/// }
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AlignedResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the allocated space.
+    pub response: Response,
+
+    /// If the contents were shown in a sizing pass this frame,
+    /// i.e. invisibly, only to measure them.
+    /// The frame will be discarded, so expensive side effects
+    /// (loading images, analytics...) should wait until this is `false`.
+    pub sizing_pass: bool,
+
+    /// The rect taken by the contents, including the padding of containers.
+    pub content_rect: Rect,
+
+    /// The bounds the contents were laid out in,
+    /// i.e. the available space limited by the maximum size of containers.
+    pub bounds: Rect,
+}
+
+impl<R> AlignedResponse<R> {
+    /// How much the contents exceed the bounds on each axis, zero if they fit.
+    pub fn overflow(&self) -> Vec2 {
+        (self.content_rect.size() - self.bounds.size()).max(Vec2::ZERO)
+    }
+
+    #[inline]
+    /// If the contents are wider than the bounds.
+    pub fn overflowed_x(&self) -> bool {
+        self.overflow().x > 0.0
+    }
+
+    #[inline]
+    /// If the contents are taller than the bounds.
+    pub fn overflowed_y(&self) -> bool {
+        self.overflow().y > 0.0
+    }
+
+    // call the callback for each overflowed axis, unless it is a sizing pass
+    pub(crate) fn report_overflow(&self, on_overflow: Option<&OverflowCallback>) {
+        let Some(on_overflow) = on_overflow else {
+            return;
+        };
+        if self.sizing_pass {
+            return;
+        }
+
+        let overflow = self.overflow();
+        if overflow.x > 0.0 {
+            on_overflow(Axis::X, overflow.x);
+        }
+        if overflow.y > 0.0 {
+            on_overflow(Axis::Y, overflow.y);
+        }
+    }
+
+    #[inline]
+    /// Convert to a plain [`InnerResponse`].
+    pub fn into_inner_response(self) -> InnerResponse<R> {
+        InnerResponse {
+            inner: self.inner,
+            response: self.response,
+        }
+    }
+}
+
+impl<R> From<AlignedResponse<R>> for InnerResponse<R> {
+    #[inline]
+    fn from(response: AlignedResponse<R>) -> Self {
+        response.into_inner_response()
+    }
+}
+
+/// A configuration which cannot be laid out, returned by the `try_show` methods
+/// of the aligners and containers instead of a broken layout.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{Column, LayoutError};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let result = Column::new(Align::Min)
+///     .min_width(200.0)
+///     .max_width(100.0)
+///     .try_show(ui, |ui| {
+///         ui.label("Never shown");
+///     });
+/// assert!(matches!(result, Err(LayoutError::UnsatisfiableSize { .. })));
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LayoutError {
+    /// The bounds have no area, or are not a number.
+    EmptyBounds(Rect),
+
+    /// The minimum size is larger than the maximum size on some axis.
+    UnsatisfiableSize {
+        /// The minimum size.
+        min: Vec2,
+
+        /// The maximum size.
+        max: Vec2,
+    },
+
+    /// A margin or padding is negative, or larger than the maximum size.
+    InvalidMargin(Margin),
+
+    /// The bounds are infinite along an axis which is divided,
+    /// e.g. the height in a vertical scroll area, even cut at the clip rect.
+    InfiniteBounds(Rect),
+}
+
+impl std::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayoutError::EmptyBounds(bounds) => write!(f, "the bounds {bounds:?} are empty"),
+            LayoutError::UnsatisfiableSize { min, max } => {
+                write!(f, "the minimum size {min:?} is larger than the maximum size {max:?}")
+            },
+            LayoutError::InvalidMargin(margin) => write!(f, "the margin {margin:?} is invalid"),
+            LayoutError::InfiniteBounds(bounds) => write!(f, "the bounds {bounds:?} are infinite"),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+impl LayoutError {
+    // check that the bounds have an area
+    pub(crate) fn check_bounds(bounds: Rect) -> Result<(), LayoutError> {
+        if bounds.width() > 0.0 && bounds.height() > 0.0 {
+            Ok(())
+        } else {
+            Err(LayoutError::EmptyBounds(bounds))
+        }
+    }
+
+    // check that the margin is not negative and fits in the maximum size
+    pub(crate) fn check_margin(margin: Margin, max_size: Vec2) -> Result<(), LayoutError> {
+        let negative = margin.left_top().min_elem() < 0.0 || margin.right_bottom().min_elem() < 0.0;
+        if negative || margin.sum().x > max_size.x || margin.sum().y > max_size.y {
+            Err(LayoutError::InvalidMargin(margin))
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Ribbons across the corners of a rect
+
+use egui::{emath::Rot2, epaint::TextShape, vec2, Color32, Pos2, Rect, Shape, Stroke, TextStyle, TextWrapMode, Ui, Vec2, WidgetText};
+
+/// A corner of a rect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top left corner.
+    LeftTop,
+
+    /// The top right corner.
+    RightTop,
+
+    /// The bottom left corner.
+    LeftBottom,
+
+    /// The bottom right corner.
+    RightBottom,
+}
+
+impl Corner {
+    #[inline]
+    /// The position of the corner of the rect.
+    pub fn pos_in_rect(self, rect: Rect) -> Pos2 {
+        match self {
+            Corner::LeftTop => rect.left_top(),
+            Corner::RightTop => rect.right_top(),
+            Corner::LeftBottom => rect.left_bottom(),
+            Corner::RightBottom => rect.right_bottom(),
+        }
+    }
+
+    // the unit diagonal from the corner toward the inside of the rect
+    fn inward(self) -> Vec2 {
+        let diagonal = match self {
+            Corner::LeftTop => vec2(1.0, 1.0),
+            Corner::RightTop => vec2(-1.0, 1.0),
+            Corner::LeftBottom => vec2(1.0, -1.0),
+            Corner::RightBottom => vec2(-1.0, -1.0),
+        };
+        diagonal.normalized()
+    }
+}
+
+/// The style of a ribbon drawn by [`ribbon`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RibbonStyle {
+    /// The color of the band.
+    /// If `None`, use the selection color of the visuals.
+    pub fill: Option<Color32>,
+
+    /// The color of the text, unless set by the text itself.
+    /// If `None`, use the selection stroke color of the visuals.
+    pub text_color: Option<Color32>,
+
+    /// The space between the text and the edges of the band, across it.
+    pub padding: f32,
+
+    /// The distance from the corner to the middle of the band.
+    /// If `None`, the band is just far enough from the corner for the text to fit in the rect.
+    pub offset: Option<f32>,
+}
+
+impl Default for RibbonStyle {
+    fn default() -> Self {
+        Self {
+            fill: None,
+            text_color: None,
+            padding: 2.0,
+            offset: None,
+        }
+    }
+}
+
+impl RibbonStyle {
+    #[inline]
+    /// Set the color of the band.
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    #[inline]
+    /// Set the color of the text.
+    pub fn text_color(mut self, text_color: Color32) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    #[inline]
+    /// Set the space between the text and the edges of the band.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    #[inline]
+    /// Set the distance from the corner to the middle of the band.
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// Draw a band at 45° across a corner of a rect, with the text centered on it, e.g. "BETA".
+/// The band is clipped to the rect.
+///
+/// The rect is usually the final rect of a container,
+/// e.g. the rect of its response, or [`Ui::min_rect`] once its contents are shown.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::RichText;
+/// use egui_alignments::{ribbon, Corner, RibbonStyle};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let card = ui.group(|ui| {
+///     ui.heading("Sync");
+///     ui.label("Keep your devices up to date.");
+/// }).response;
+/// ribbon(ui, card.rect, Corner::RightTop, RichText::new("BETA").strong(), RibbonStyle::default());
+/// # });
+/// ```
+pub fn ribbon(ui: &Ui, rect: Rect, corner: Corner, text: impl Into<WidgetText>, style: RibbonStyle) {
+    let galley = text.into().into_galley(ui, Some(TextWrapMode::Extend), f32::INFINITY, TextStyle::Small);
+    let thickness = galley.size().y + 2.0 * style.padding;
+
+    // the corner triangle is as wide as twice the distance to the corner,
+    // so the text fits at the edge of the band closer to the corner, with half the band to spare on each side
+    let offset = style.offset.unwrap_or(galley.size().x / 2.0 + thickness);
+
+    let inward = corner.inward();
+    // the direction of the text, left to right
+    let along = vec2(-inward.y, inward.x);
+    let along = if along.x < 0.0 { -along } else { along };
+    let center = corner.pos_in_rect(rect) + inward * offset;
+
+    // long enough to reach the edges of the rect, the painter clips the rest
+    let half_length = offset + thickness;
+    let half_thickness = thickness / 2.0;
+    let band = vec![
+        center - along * half_length - inward * half_thickness,
+        center + along * half_length - inward * half_thickness,
+        center + along * half_length + inward * half_thickness,
+        center - along * half_length + inward * half_thickness,
+    ];
+
+    let fill = style.fill.unwrap_or(ui.visuals().selection.bg_fill);
+    let text_color = style.text_color.unwrap_or(ui.visuals().selection.stroke.color);
+    let painter = ui.painter_at(rect);
+    painter.add(Shape::convex_polygon(band, fill, Stroke::NONE));
+
+    // the text is rotated around its top left corner
+    let angle = along.y.atan2(along.x);
+    let pos = center - Rot2::from_angle(angle) * (galley.size() / 2.0);
+    painter.add(TextShape::new(pos, galley, text_color).with_angle(angle));
+}
@@ -0,0 +1,293 @@
+//! Roving focus for groups of widgets
+
+use std::hash::Hash;
+
+use egui::{vec2, Context, Event, EventFilter, Id, InnerResponse, Key, Modifiers, Rangef, Rect, Response, Ui, Vec2, Widget};
+
+#[derive(Clone, Debug, Default)]
+struct RovingFocusState {
+    /// The item taking the focus when Tab enters the group.
+    active: Option<Id>,
+
+    /// The items of the last pass, in order.
+    items: Vec<(Id, Rect)>,
+
+    /// If an item had the focus at the end of the last pass.
+    had_focus: bool,
+
+    /// The last pass Tab was pressed in.
+    tab_pass: u64,
+
+    /// The item with the focus at the end of the last pass, if egui keeps the arrows from moving it.
+    locked: Option<Id>,
+
+    /// The item egui moved the focus from with the arrows in the last pass.
+    unlocked_from: Option<Id>,
+}
+
+/// Makes a group of widgets, e.g. the buttons of a toolbar or the options of a radio-like group,
+/// a single Tab stop, the arrow keys moving the focus between them.
+///
+/// Tab enters the group at its active item, the last one focused, or the first one by default,
+/// and leaves it from there. The arrow keys move the focus to the nearest item in their direction,
+/// so the group may be laid out in a row, a column or a grid, and Home and End to the first and the last items.
+///
+/// The items are registered with [`RovingItems::item`] in the order of the group,
+/// or added with [`RowItems::roving_item`](crate::RowItems::roving_item) to a [`Row`](crate::Row).
+/// They should not use the arrow keys themselves, like sliders or text edits.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{RovingFocus, Row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// let mut tool = 0;
+/// RovingFocus::new("tools").show(ui, |ui, roving| {
+///     Row::new(Align::Center).show_items(ui, |row| {
+///         for (index, name) in ["Select", "Draw", "Erase"].into_iter().enumerate() {
+///             let response = row.roving_item(roving, |ui| ui.selectable_label(tool == index, name));
+///             if response.clicked() || response.gained_focus() {
+///                 tool = index;
+///             }
+///         }
+///     });
+/// });
+/// # });
+/// ```
+pub struct RovingFocus {
+    /// Used to memorize the items and the active one.
+    pub id: Id,
+
+    /// If `true`, an arrow key with no item in its direction
+    /// moves the focus to the farthest item in the opposite one.
+    pub wrap: bool,
+}
+
+impl RovingFocus {
+    #[inline]
+    /// Create a new roving focus group.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            wrap: false,
+        }
+    }
+
+    #[inline]
+    /// Set whether the arrow keys wrap around at the ends of the group.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// The item taking the focus when Tab enters the group with the given id.
+    pub fn active(ctx: &Context, id: Id) -> Option<Id> {
+        ctx.data(|data| data.get_temp::<RovingFocusState>(id)).and_then(|state| state.active)
+    }
+
+    /// Show the contents, registering the items of the group with the given [`RovingItems`].
+    pub fn show<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui, &mut RovingItems) -> R) -> InnerResponse<R> {
+        let ctx = ui.ctx().clone();
+        let pass = ctx.cumulative_pass_nr();
+        let mut state = ctx.data(|data| data.get_temp::<RovingFocusState>(self.id)).unwrap_or_default();
+        let mut focused = ctx.memory(|memory| memory.focused());
+
+        // bring back the focus if egui moved it out of the group
+        if let Some(item) = state.unlocked_from.take() {
+            if focused.is_some_and(|focused| !state.items.iter().any(|(id, _)| *id == focused)) {
+                ctx.memory_mut(|memory| memory.request_focus(item));
+                focused = Some(item);
+            }
+        }
+
+        // like egui, use the modifiers of the key events
+        let (tab, shift_tab) = ctx.input(|input| {
+            input.events.iter().fold((false, false), |(tab, shift_tab), event| match event {
+                Event::Key { key: Key::Tab, pressed: true, modifiers, .. } => (tab || !modifiers.shift, shift_tab || modifiers.shift),
+                _ => (tab, shift_tab),
+            })
+        });
+        let entering = !state.had_focus && (tab || shift_tab || state.tab_pass + 1 >= pass);
+        if tab || shift_tab {
+            state.tab_pass = pass;
+        }
+
+        let index = focused.and_then(|focused| state.items.iter().position(|(id, _)| *id == focused));
+        if let Some(index) = index {
+            let target = if tab || shift_tab {
+                // leave the group from the last item, or the first one with Shift+Tab,
+                // egui then gives the focus to the widget after or before it
+                if shift_tab { Some(0) } else { Some(state.items.len() - 1) }
+            } else {
+                // egui moves the focus with the arrows itself until the lock filter of the item applies
+                let locked = state.locked == focused;
+                if !locked && ctx.input(|input| ARROWS.iter().any(|key| input.key_pressed(*key))) {
+                    state.unlocked_from = focused;
+                }
+                self.key_target(&ctx, &state.items, index, locked)
+            };
+            if let Some(target) = target.filter(|target| *target != index) {
+                let target = state.items[target].0;
+                ctx.memory_mut(|memory| memory.request_focus(target));
+            }
+        }
+
+        let mut items = RovingItems {
+            ctx: ctx.clone(),
+            active: state.active,
+            last_items: std::mem::take(&mut state.items),
+            items: Vec::new(),
+            entering,
+            leaving: index.is_some() && (tab || shift_tab),
+        };
+        let response = ui.scope(|ui| add_contents(ui, &mut items));
+
+        let focused = ctx.memory(|memory| memory.focused())
+            .filter(|focused| items.items.iter().any(|(id, _)| id == focused));
+        if let Some(focused) = focused {
+            // keep egui from moving the focus out of the group with the arrows
+            let filter = EventFilter {
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                ..Default::default()
+            };
+            ctx.memory_mut(|memory| memory.set_focus_lock_filter(focused, filter));
+        }
+        // egui only locks the focus of the widgets which had it in the last pass
+        state.locked = focused.filter(|focused| ctx.memory(|memory| memory.had_focus_last_frame(*focused)));
+
+        state.had_focus = focused.is_some();
+        state.active = items.active
+            .filter(|active| items.items.iter().any(|(id, _)| id == active))
+            .or(items.items.first().map(|(id, _)| *id));
+        state.items = items.items;
+        ctx.data_mut(|data| data.insert_temp(self.id, state));
+
+        response
+    }
+
+    // the index of the item the pressed arrow, Home or End key moves the focus to
+    fn key_target(&self, ctx: &Context, items: &[(Id, Rect)], from: usize, arrows: bool) -> Option<usize> {
+        let pressed = |key| ctx.input_mut(|input| input.consume_key(Modifiers::NONE, key));
+        if pressed(Key::Home) {
+            return Some(0);
+        }
+        if pressed(Key::End) {
+            return Some(items.len() - 1);
+        }
+        if !arrows {
+            return None;
+        }
+        let direction = [Vec2::LEFT, Vec2::RIGHT, Vec2::UP, Vec2::DOWN]
+            .into_iter()
+            .zip(ARROWS)
+            .find_map(|(direction, key)| pressed(key).then_some(direction))?;
+
+        // like egui, the items overlapping along an axis are aligned on it
+        let from_rect = items[from].1;
+        let offsets = || items.iter()
+            .enumerate()
+            .filter(move |(index, _)| *index != from)
+            .map(move |(index, (_, rect))| {
+                let offset = vec2(
+                    range_offset(from_rect.x_range(), rect.x_range()),
+                    range_offset(from_rect.y_range(), rect.y_range()),
+                );
+                (index, offset)
+            });
+
+        // the nearest item in the direction, favoring the ones right in it
+        let nearest = |min_cos: f32| offsets()
+            .filter_map(|(index, offset)| {
+                let cos = offset.normalized().dot(direction);
+                (cos > min_cos).then(|| (index, offset.length() / (cos * cos)))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index);
+
+        // first within 45° of the direction
+        let target = nearest(std::f32::consts::FRAC_1_SQRT_2 - f32::EPSILON);
+        if target.is_some() {
+            return target;
+        }
+
+        if self.wrap {
+            // the farthest item in the opposite direction, on the same line if possible
+            let across = |offset: Vec2| offset.dot(direction.rot90()).abs();
+            let target = offsets()
+                .filter(|(_, offset)| offset.dot(direction) < 0.0)
+                .min_by(|(_, a), (_, b)| across(*a).total_cmp(&across(*b)).then(a.dot(direction).total_cmp(&b.dot(direction))))
+                .map(|(index, _)| index);
+            if target.is_some() {
+                return target;
+            }
+        }
+
+        // then anywhere on the side of the direction
+        nearest(0.0)
+    }
+}
+
+const ARROWS: [Key; 4] = [Key::ArrowLeft, Key::ArrowRight, Key::ArrowUp, Key::ArrowDown];
+
+// the offset between the centers of the ranges, zero if they overlap by half of the smaller one
+fn range_offset(from: Rangef, to: Rangef) -> f32 {
+    if from.intersection(to).span() >= 0.5 * from.span().min(to.span()) {
+        0.0
+    } else {
+        to.center() - from.center()
+    }
+}
+
+/// The handle used to register the items of a [`RovingFocus`] group.
+pub struct RovingItems {
+    ctx: Context,
+    active: Option<Id>,
+    last_items: Vec<(Id, Rect)>,
+    items: Vec<(Id, Rect)>,
+    entering: bool,
+    leaving: bool,
+}
+
+impl RovingItems {
+    #[inline]
+    /// The number of items registered so far.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[inline]
+    /// If no item has been registered yet.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Register the response of a focusable widget as the next item of the group.
+    pub fn item(&mut self, response: &Response) {
+        self.items.push((response.id, response.rect));
+        // the item Tab leaves the group from keeps the focus until the next pass
+        if !response.has_focus() || self.leaving {
+            return;
+        }
+
+        match self.active {
+            // Tab entered the group at another item
+            Some(active) if self.entering
+                && active != response.id
+                && self.last_items.iter().any(|(id, _)| *id == active) => {
+                self.ctx.memory_mut(|memory| memory.request_focus(active));
+            },
+            _ => self.active = Some(response.id),
+        }
+    }
+
+    #[inline]
+    /// Add a widget to the ui as the next item of the group.
+    pub fn add(&mut self, ui: &mut Ui, widget: impl Widget) -> Response {
+        let response = ui.add(widget);
+        self.item(&response);
+        response
+    }
+}
@@ -0,0 +1,44 @@
+//! The safe area of the screen, clear of notches, rounded corners and OS bars
+//!
+//! egui does not know the safe area, so the app registers its insets,
+//! e.g. from `env(safe-area-inset-*)` on the web or from the window insets on mobile,
+//! and the contents are aligned within it with [`Bounds::SafeArea`](crate::Bounds::SafeArea).
+//!
+//! # Example
+//! ```
+//! # use egui_alignments::egui;
+//! use egui_alignments::{set_safe_area_insets, Bounds, WidgetAligner};
+//!
+//! # egui::__run_test_ui(|ui| {
+//! // once the insets are known, e.g. at startup and on rotation
+//! set_safe_area_insets(ui.ctx(), 24.0);
+//!
+//! WidgetAligner::center_bottom()
+//!     .bounds(Bounds::safe_area())
+//!     .show(ui, |ui| {
+//!         ui.button("Continue")
+//!     });
+//! # });
+//! ```
+
+use egui::{Context, Id, Margin, Rect};
+
+fn insets_id() -> Id {
+    Id::new("egui_alignments::safe_area_insets")
+}
+
+/// Register the insets of the safe area from the edges of the screen.
+pub fn set_safe_area_insets(ctx: &Context, insets: impl Into<Margin>) {
+    let insets = insets.into();
+    ctx.data_mut(|data| data.insert_temp(insets_id(), insets));
+}
+
+/// The insets of the safe area registered with [`set_safe_area_insets`], zero if none were.
+pub fn safe_area_insets(ctx: &Context) -> Margin {
+    ctx.data(|data| data.get_temp(insets_id())).unwrap_or_default()
+}
+
+/// The rect of the screen within the safe area.
+pub fn safe_area_rect(ctx: &Context) -> Rect {
+    ctx.screen_rect() - safe_area_insets(ctx)
+}
@@ -0,0 +1,143 @@
+use std::hash::Hash;
+
+use egui::{scroll_area::ScrollAreaOutput, Context, Id, Rect, ScrollArea, Ui};
+
+use crate::size_cache;
+
+/// Keeps the view of a vertical [`ScrollArea`] in place when the contents above it change size,
+/// e.g. when a container above the viewport finishes its sizing pass.
+///
+/// The containers of the crate shown in the scroll area are the anchor candidates.
+/// After each pass, the smallest container at the top of the viewport is remembered,
+/// and if it moved in the contents by the next pass, the scroll offset follows it.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, ScrollArea};
+/// use egui_alignments::{Column, ScrollAnchor};
+///
+/// # egui::__run_test_ui(|ui| {
+/// ScrollAnchor::new("feed").show(ui, ScrollArea::vertical(), |ui| {
+///     for i in 0..100 {
+///         Column::new(Align::Min).show(ui, |ui| {
+///             ui.label(format!("Post {}", i));
+///         });
+///     }
+/// });
+/// # });
+/// ```
+pub struct ScrollAnchor {
+    /// Used to memorize the anchor.
+    pub id: Id,
+}
+
+// the anchor container and its top in the contents of the scroll area
+#[derive(Clone, Copy)]
+struct Anchor {
+    id: Id,
+    top: f32,
+}
+
+// the containers shown in the scroll areas being shown, innermost last
+#[derive(Clone, Default)]
+struct Candidates(Vec<Vec<(Id, Rect)>>);
+
+fn candidates_id() -> Id {
+    Id::new("egui_alignments::scroll_anchor_candidates")
+}
+
+/// Register a container laid out in the given rect as an anchor candidate
+/// of the innermost [`ScrollAnchor`] being shown, if any.
+pub(crate) fn report(ctx: &Context, id: Id, rect: Rect) {
+    if !size_cache::flags(ctx).anchoring {
+        return;
+    }
+    ctx.data_mut(|data| {
+        if let Some(candidates) = data.get_temp_mut_or_default::<Candidates>(candidates_id()).0.last_mut() {
+            candidates.push((id, rect));
+        }
+    });
+}
+
+impl ScrollAnchor {
+    #[inline]
+    /// Create a new scroll anchor with the given id salt.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self { id: Id::new(id_salt) }
+    }
+
+    /// Show the scroll area, keeping the anchor container in place.
+    pub fn show<R>(
+        &self,
+        ui: &mut Ui,
+        scroll_area: ScrollArea,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> ScrollAreaOutput<R> {
+        let ctx = ui.ctx().clone();
+        // set once any scroll anchor was shown, to skip the memory lock otherwise
+        size_cache::set_flag(&ctx, |flags| &mut flags.anchoring, true);
+        ctx.data_mut(|data| data.get_temp_mut_or_default::<Candidates>(candidates_id()).0.push(Vec::new()));
+
+        // the top of the contents as laid out, which the positions of the anchors are relative to,
+        // as the offset of the output may already include the scrolling of this pass
+        let output = scroll_area.show(ui, |ui| {
+            let contents_top = ui.max_rect().top();
+            (add_contents(ui), contents_top)
+        });
+        let (inner, contents_top) = output.inner;
+        let mut output = ScrollAreaOutput {
+            inner,
+            id: output.id,
+            state: output.state,
+            content_size: output.content_size,
+            inner_rect: output.inner_rect,
+        };
+
+        let candidates = ctx
+            .data_mut(|data| data.get_temp_mut_or_default::<Candidates>(candidates_id()).0.pop())
+            .unwrap_or_default();
+        if ui.is_sizing_pass() {
+            return output;
+        }
+
+        let anchor_id = self.id.with("anchor");
+        let anchor: Option<Anchor> = ctx.data(|data| data.get_temp(anchor_id));
+        if let Some(anchor) = anchor {
+            let moved = candidates
+                .iter()
+                .find(|(id, _)| *id == anchor.id)
+                .map(|(_, rect)| rect.top() - contents_top - anchor.top)
+                .filter(|moved| *moved != 0.0);
+            if let Some(moved) = moved {
+                let max_offset = (output.content_size.y - output.inner_rect.height()).max(0.0);
+                let offset = (output.state.offset.y + moved).clamp(0.0, max_offset);
+                if offset != output.state.offset.y {
+                    output.state.offset.y = offset;
+                    output.state.store(&ctx, output.id);
+                    crate::request_discard(&ctx, "ScrollAnchor offset");
+                }
+            }
+        }
+
+        // the smallest container crossing the top of the viewport, or the first one below it
+        let viewport_top = output.inner_rect.top();
+        let new_anchor = candidates
+            .iter()
+            .filter(|(_, rect)| rect.bottom() > viewport_top)
+            .min_by(|(_, a), (_, b)| {
+                let key = |rect: &Rect| (rect.top().max(viewport_top), rect.height());
+                key(a).partial_cmp(&key(b)).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(id, rect)| Anchor {
+                id: *id,
+                top: rect.top() - contents_top,
+            });
+        ctx.data_mut(|data| match new_anchor {
+            Some(anchor) => data.insert_temp(anchor_id, anchor),
+            None => data.remove::<Anchor>(anchor_id),
+        });
+
+        output
+    }
+}
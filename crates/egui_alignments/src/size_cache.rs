@@ -0,0 +1,246 @@
+// The sizes memorized by the aligners and containers.
+//
+// All the sizes are kept in a single map in the context memory.
+// The map is read once at the first access of a pass into a thread-local shadow,
+// which serves all the reads and writes of the pass,
+// and is written back once at the end of the pass,
+// so hundreds of aligned widgets do not each lock the memory.
+//
+// Each size is memorized with the bounds it was measured in,
+// so the contents are measured again when the bounds change, e.g. when the window is resized.
+// A few sizes are kept per id, one per bounds, so toggling between responsive states,
+// e.g. portrait and landscape or a maximized and a restored window, does not measure them again.
+//
+// The shadow also keeps the flags of the features used in the context,
+// so the ones never used skip the memory lock.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use egui::{Context, Id, IdMap, Vec2};
+
+// a change of the bounds below this, in points, keeps the memorized size
+const BOUNDS_THRESHOLD: f32 = 1.0;
+
+// the number of sizes kept per id, measured in different bounds
+const MAX_ENTRIES: usize = 4;
+
+#[derive(Clone, Copy)]
+struct Entry {
+    size: Vec2,
+    bounds: Vec2,
+}
+
+// the sizes of an id, the one measured last first
+#[derive(Clone, Copy)]
+struct Entries {
+    recent: Entry,
+    older: [Option<Entry>; MAX_ENTRIES - 1],
+}
+
+/// The features used in a context, set once used.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Flags {
+    /// A stretch was added.
+    pub stretching: bool,
+
+    /// A scroll anchor was shown.
+    pub anchoring: bool,
+
+    /// The layout is being recorded.
+    pub recording: bool,
+
+    /// An on-screen keyboard was registered.
+    pub keyboard: bool,
+}
+
+#[derive(Clone, Default)]
+struct SizeMap {
+    sizes: IdMap<Entries>,
+    flags: Flags,
+}
+
+struct Shadow {
+    ctx: Context,
+    sizes: IdMap<Entries>,
+    flags: Flags,
+    dirty: bool,
+}
+
+thread_local! {
+    static SHADOW: RefCell<Option<Shadow>> = const { RefCell::new(None) };
+}
+
+fn map_id() -> Id {
+    Id::new("egui_alignments::size_cache")
+}
+
+fn flush(shadow: Shadow) {
+    if shadow.dirty {
+        let map = SizeMap { sizes: shadow.sizes, flags: shadow.flags };
+        shadow.ctx.data_mut(|data| data.insert_temp(map_id(), map));
+    }
+}
+
+fn with_shadow<R>(ctx: &Context, f: impl FnOnce(&mut Shadow) -> R) -> R {
+    SHADOW.with(|cell| {
+        let mut cell = cell.borrow_mut();
+
+        if cell.as_ref().is_some_and(|shadow| shadow.ctx != *ctx) {
+            // another context on the same thread, write the old one back first
+            flush(cell.take().unwrap());
+        }
+
+        let shadow = cell.get_or_insert_with(|| {
+            let (map, registered) = ctx.data_mut(|data| {
+                let map = data.get_temp::<SizeMap>(map_id()).unwrap_or_default();
+                let registered = data.get_temp::<bool>(map_id().with("registered")).unwrap_or(false);
+                data.insert_temp(map_id().with("registered"), true);
+                (map, registered)
+            });
+
+            if !registered {
+                ctx.on_end_pass("egui_alignments::size_cache", Arc::new(end_pass));
+            }
+
+            Shadow {
+                ctx: ctx.clone(),
+                sizes: map.sizes,
+                flags: map.flags,
+                dirty: false,
+            }
+        });
+
+        f(shadow)
+    })
+}
+
+fn end_pass(ctx: &Context) {
+    let shadow = SHADOW.with(|cell| {
+        let mut cell = cell.borrow_mut();
+        if cell.as_ref().is_some_and(|shadow| shadow.ctx == *ctx) {
+            cell.take()
+        } else {
+            None
+        }
+    });
+
+    if let Some(shadow) = shadow {
+        flush(shadow);
+    }
+}
+
+// a size which can be laid out: finite and not negative
+fn is_valid(size: Vec2) -> bool {
+    size.x.is_finite() && size.y.is_finite() && size.x >= 0.0 && size.y >= 0.0
+}
+
+// if the contents measured in the bounds of the entry may be laid out differently in the given bounds.
+// text is wrapped by width, so a change of the width is enough,
+// while a change of the height matters only if the size no longer fits.
+fn is_stale(entry: Entry, bounds: Vec2) -> bool {
+    // equal infinite bounds give NaN, which is no change
+    let changed = |old: f32, new: f32| (new - old).abs() > BOUNDS_THRESHOLD;
+    let overflows = |size: f32, new: f32| size > new + BOUNDS_THRESHOLD;
+    changed(entry.bounds.x, bounds.x) || (changed(entry.bounds.y, bounds.y) && overflows(entry.size.y, bounds.y))
+}
+
+// the sizes memorized with the id, forgotten if the last one is invalid
+fn get_entries(shadow: &mut Shadow, id: Id) -> Option<&mut Entries> {
+    if shadow.sizes.get(&id).is_some_and(|entries| !is_valid(entries.recent.size)) {
+        shadow.sizes.remove(&id);
+        shadow.dirty = true;
+    }
+    shadow.sizes.get_mut(&id)
+}
+
+/// Get the size memorized last with the id, whatever the bounds it was measured in.
+/// An invalid memorized size is forgotten, so the contents are measured again.
+pub(crate) fn get(ctx: &Context, id: Id) -> Option<Vec2> {
+    with_shadow(ctx, |shadow| get_entries(shadow, id).map(|entries| entries.recent.size))
+}
+
+/// Get the size memorized with the id in bounds close to the given ones,
+/// or `None` if there is none, in which case the contents should be measured again.
+/// The other sizes are kept, so the change can be reported and the bounds can come back.
+pub(crate) fn get_within(ctx: &Context, id: Id, bounds: Vec2) -> Option<Vec2> {
+    with_shadow(ctx, |shadow| {
+        let entries = get_entries(shadow, id)?;
+        if !is_stale(entries.recent, bounds) {
+            return Some(entries.recent.size);
+        }
+
+        // the bounds came back to ones the contents were measured in before
+        let older = entries.older.iter_mut()
+            .find(|entry| entry.is_some_and(|entry| is_valid(entry.size) && !is_stale(entry, bounds)));
+        if let Some(Some(entry)) = older {
+            std::mem::swap(entry, &mut entries.recent);
+            let size = entries.recent.size;
+            shadow.dirty = true;
+            return Some(size);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(?id, old = ?entries.recent.bounds, new = ?bounds, "bounds changed, measuring again");
+        None
+    })
+}
+
+/// Memorize the size with the id, with the bounds it was measured in.
+/// Invalid sizes, e.g. NaN or negative, are not memorized.
+pub(crate) fn insert(ctx: &Context, id: Id, size: Vec2, bounds: Vec2) {
+    debug_assert!(is_valid(size), "invalid size {size:?} measured for {id:?}");
+    if !is_valid(size) {
+        return;
+    }
+
+    with_shadow(ctx, |shadow| {
+        let entry = Entry { size, bounds };
+        let Some(entries) = get_entries(shadow, id) else {
+            shadow.sizes.insert(id, Entries { recent: entry, older: [None; MAX_ENTRIES - 1] });
+            shadow.dirty = true;
+            return;
+        };
+
+        let old = entries.recent;
+        if is_stale(old, bounds) {
+            // keep the size measured in the old bounds, in place of the oldest one
+            entries.older.rotate_right(1);
+            entries.older[0] = Some(old);
+        }
+        entries.recent = entry;
+
+        if old.size != size {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?id, old = ?old.size, new = ?size, "memorized size changed");
+            shadow.dirty = true;
+        } else if old.bounds != bounds {
+            shadow.dirty = true;
+        }
+    });
+}
+
+/// Forget the size memorized with the id.
+pub(crate) fn remove(ctx: &Context, id: Id) {
+    with_shadow(ctx, |shadow| {
+        if shadow.sizes.remove(&id).is_some() {
+            shadow.dirty = true;
+        }
+    });
+}
+
+/// Get the flags of the features used in the context.
+pub(crate) fn flags(ctx: &Context) -> Flags {
+    with_shadow(ctx, |shadow| shadow.flags)
+}
+
+/// Set or clear a flag of the features used in the context.
+pub(crate) fn set_flag(ctx: &Context, flag: impl FnOnce(&mut Flags) -> &mut bool, value: bool) {
+    with_shadow(ctx, |shadow| {
+        let flag = flag(&mut shadow.flags);
+        if *flag != value {
+            *flag = value;
+            shadow.dirty = true;
+        }
+    });
+}
@@ -0,0 +1,77 @@
+//! Sizes known before showing the contents
+
+use egui::{Image, Response, Style, Ui, Vec2, Widget};
+
+use crate::{Aligner, WidgetAligner};
+
+/// A widget which can report its size before it is shown,
+/// so the containers aligning it can skip the sizing pass.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{vec2, Align2, Button};
+/// use egui_alignments::{Fixed, SizeHint};
+///
+/// # egui::__run_test_ui(|ui| {
+/// // aligned in the first frame, without a sizing pass
+/// Fixed::new(Button::new("OK"), vec2(80.0, 24.0))
+///     .align_hinted(ui, Align2::RIGHT_BOTTOM);
+/// # });
+/// ```
+pub trait SizeHint {
+    /// The size the widget will take, if known up front.
+    fn intrinsic_size(&self, style: &Style) -> Option<Vec2>;
+
+    /// Show the widget at the position specified by the [`Aligner`],
+    /// using [`SizeHint::intrinsic_size`] instead of a sizing pass if known.
+    fn align_hinted(self, ui: &mut Ui, align: impl Aligner) -> Response
+    where
+        Self: Widget + Sized,
+    {
+        let mut aligner = WidgetAligner::from_align(align);
+        aligner.size_hint = self.intrinsic_size(ui.style());
+        aligner.show(ui, |ui| self.ui(ui)).inner
+    }
+}
+
+/// Images shown from a texture know their size,
+/// assuming they are not shrunk to fit the available space.
+impl SizeHint for Image<'_> {
+    fn intrinsic_size(&self, _style: &Style) -> Option<Vec2> {
+        let texture_size = self.size()?;
+        Some(self.calc_size(Vec2::INFINITY, Some(texture_size)))
+    }
+}
+
+/// A widget shown with a fixed size, like [`Ui::add_sized`].
+pub struct Fixed<W: Widget> {
+    /// The widget.
+    pub widget: W,
+
+    /// The size of the widget.
+    pub size: Vec2,
+}
+
+impl<W: Widget> Fixed<W> {
+    #[inline]
+    /// Show the widget with the given size.
+    pub fn new(widget: W, size: impl Into<Vec2>) -> Self {
+        Self {
+            widget,
+            size: size.into(),
+        }
+    }
+}
+
+impl<W: Widget> Widget for Fixed<W> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.add_sized(self.size, self.widget)
+    }
+}
+
+impl<W: Widget> SizeHint for Fixed<W> {
+    fn intrinsic_size(&self, _style: &Style) -> Option<Vec2> {
+        Some(self.size)
+    }
+}
@@ -0,0 +1,116 @@
+use egui::{vec2, Color32, Rect, Ui, Vec2};
+
+/// A placeholder painted by the containers in place of their contents during the sizing pass,
+/// so newly shown contents do not pop in from nothing.
+///
+/// It is a rounded rect as large as the minimum size of the container,
+/// as wide as the available width if there is no minimum width,
+/// and one line high if there is no minimum height.
+///
+/// The sizing pass of a container with a skeleton is presented rather than discarded,
+/// so the skeleton is seen for a frame and the contents are shown from the next one.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{Column, Skeleton};
+///
+/// # egui::__run_test_ui(|ui| {
+/// Column::new(Align::Min)
+///     .min_width(200.0)
+///     .skeleton(Skeleton::default())
+///     .show(ui, |ui| {
+///         ui.label("Loaded contents");
+///     });
+/// # });
+/// ```
+///
+/// The first frame presents the skeleton:
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, Color32, Shape};
+/// use egui_alignments::{Column, Skeleton};
+///
+/// let ctx = egui::Context::default();
+/// let output = ctx.run(Default::default(), |ctx| {
+///     egui::CentralPanel::default().show(ctx, |ui| {
+///         Column::new(Align::Min)
+///             .skeleton(Skeleton::default().fill(Color32::RED))
+///             .show(ui, |ui| {
+///                 ui.label("Loaded contents");
+///             });
+///     });
+/// });
+/// assert!(output.shapes.iter().any(|clipped| {
+///     matches!(&clipped.shape, Shape::Rect(rect) if rect.fill == Color32::RED)
+/// }));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Skeleton {
+    /// The fill of the placeholder.
+    /// If `None`, use the background color of the inactive widgets.
+    pub fill: Option<Color32>,
+
+    /// The corner radius of the placeholder.
+    pub corner_radius: f32,
+
+    /// The height of the placeholder if the container has no minimum height.
+    /// If `None`, use the interaction height of the style.
+    pub line_height: Option<f32>,
+}
+
+impl Default for Skeleton {
+    fn default() -> Self {
+        Self {
+            fill: None,
+            corner_radius: 4.0,
+            line_height: None,
+        }
+    }
+}
+
+impl Skeleton {
+    #[inline]
+    /// Set the fill of the placeholder.
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    #[inline]
+    /// Set the corner radius of the placeholder.
+    pub fn corner_radius(mut self, corner_radius: f32) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    #[inline]
+    /// Set the height of the placeholder used when the container has no minimum height.
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = Some(line_height);
+        self
+    }
+
+    /// The size of the placeholder of a container with the given minimum and maximum sizes.
+    pub fn size(&self, ui: &Ui, available_size: Vec2, min_size: Vec2, max_size: Vec2) -> Vec2 {
+        let width = if min_size.x > 0.0 { min_size.x } else { available_size.x };
+        let height = if min_size.y > 0.0 {
+            min_size.y
+        } else {
+            self.line_height.unwrap_or(ui.spacing().interact_size.y)
+        };
+        vec2(width, height).min(max_size)
+    }
+
+    /// Paint the placeholder in the given rect.
+    pub fn paint(&self, ui: &Ui, rect: Rect) {
+        if !rect.is_positive() {
+            return;
+        }
+
+        let fill = self.fill.unwrap_or(ui.visuals().widgets.inactive.bg_fill);
+        ui.painter().rect_filled(rect, self.corner_radius, fill);
+    }
+}
@@ -0,0 +1,115 @@
+use egui::{Context, Id, Ui, Vec2};
+
+use crate::size_cache;
+
+// the tag of the content uis of the containers, holding the id of the container
+pub(crate) const CONTAINER_ID_TAG: &str = "egui_alignments::container_id";
+
+// the space left by the contents and the total weight of the stretches, in the previous pass
+#[derive(Clone, Copy, Default, PartialEq)]
+struct Shares {
+    leftover: f32,
+    weight: f32,
+}
+
+// the total weight of the stretches and the space they added, in the current pass
+#[derive(Clone, Copy, Default)]
+struct Added {
+    weight: f32,
+    space: f32,
+}
+
+/// Add flexible spacing along the main direction of a [`crate::Row`], [`crate::Column`]
+/// or [`crate::GenericContainer`], taking a share of the space left by the contents
+/// proportional to its weight.
+///
+/// The container then fills its maximum size, or the available size, along its main direction.
+/// Several stretches share the leftover space by weight, e.g. `1:2:1`.
+///
+/// It must be added directly in the contents of the container, and does nothing elsewhere,
+/// nor in wrapping layouts.
+/// The leftover space is measured after the contents are laid out,
+/// so the pass is discarded when it changes.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align;
+/// use egui_alignments::{stretch, Row};
+///
+/// # egui::__run_test_ui(|ui| {
+/// // a toolbar with three groups, the middle gap twice the outer ones
+/// Row::new(Align::Center).show(ui, |ui| {
+///     let _ = ui.button("Back");
+///     stretch(ui, 1.0);
+///     ui.label("Title");
+///     stretch(ui, 2.0);
+///     let _ = ui.button("Share");
+///     stretch(ui, 1.0);
+///     let _ = ui.button("Menu");
+/// });
+/// # });
+/// ```
+pub fn stretch(ui: &mut Ui, weight: f32) {
+    // set once any stretch was added, to skip the memory lock otherwise
+    size_cache::set_flag(ui.ctx(), |flags| &mut flags.stretching, true);
+    let weight = weight.max(0.0);
+    let Some(id) = ui.stack().info.tags.get_downcast::<Id>(CONTAINER_ID_TAG).copied() else {
+        return;
+    };
+    if ui.is_sizing_pass() || ui.layout().main_wrap() {
+        // the sizing pass measures the contents without the stretches
+        return;
+    }
+
+    let shares: Shares = ui.ctx().data(|data| data.get_temp(id.with("stretch"))).unwrap_or_default();
+    let space = if shares.weight > 0.0 {
+        shares.leftover * weight / shares.weight
+    } else {
+        0.0
+    };
+    ui.ctx().data_mut(|data| {
+        let added = data.get_temp_mut_or_default::<Added>(id.with("stretch_added"));
+        added.weight += weight;
+        added.space += space;
+    });
+    ui.add_space(space);
+}
+
+/// Forget the stretches added in the previous pass of the container.
+pub(crate) fn begin(ctx: &Context, id: Id) {
+    if !size_cache::flags(ctx).stretching {
+        return;
+    }
+    ctx.data_mut(|data| data.remove::<Added>(id.with("stretch_added")));
+}
+
+/// Memorize the space left by the contents of the container,
+/// given the size of the contents and the size they may fill.
+pub(crate) fn end(ctx: &Context, id: Id, horizontal: bool, content_size: Vec2, max_size: Vec2) {
+    if !size_cache::flags(ctx).stretching {
+        return;
+    }
+    let Some(added) = ctx.data_mut(|data| data.remove_temp::<Added>(id.with("stretch_added"))) else {
+        return;
+    };
+
+    let (length, max_length) = if horizontal {
+        (content_size.x, max_size.x)
+    } else {
+        (content_size.y, max_size.y)
+    };
+    let leftover = if max_length.is_finite() {
+        (max_length - (length - added.space)).max(0.0)
+    } else {
+        0.0
+    };
+    let shares = Shares { leftover, weight: added.weight };
+
+    let shares_id = id.with("stretch");
+    let old_shares: Option<Shares> = ctx.data(|data| data.get_temp(shares_id));
+    if old_shares != Some(shares) {
+        ctx.data_mut(|data| data.insert_temp(shares_id, shares));
+        crate::request_discard(ctx, "stretch");
+    }
+}
@@ -0,0 +1,251 @@
+//! Aligned text helpers
+
+use std::sync::Arc;
+
+use egui::{Align, Align2, Color32, FontId, Galley, Id, Layout, Rect, Response, Sense, TextStyle, Ui, Vec2};
+
+use crate::{AlignedResponse, Bounds, WidgetAligner};
+
+/// A single line of text whose font size is shrunk, within a range,
+/// until the text fits in the width and height of a rect,
+/// and then aligned within the rect.
+///
+/// If the text does not fit even at the minimum size, it is clipped.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align2, Rect, TextStyle};
+/// use egui_alignments::FitText;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let rect = Rect::from_min_size(ui.next_widget_position(), egui::vec2(120.0, 40.0));
+/// FitText::new("1,234,567", TextStyle::Heading)
+///     .size_range(8.0, 32.0)
+///     .align(Align2::RIGHT_CENTER)
+///     .show(ui, rect);
+/// # });
+/// ```
+pub struct FitText {
+    /// The text to show.
+    pub text: String,
+
+    /// The text style providing the font family.
+    pub style: TextStyle,
+
+    /// The minimum font size.
+    pub min_size: f32,
+
+    /// The maximum font size.
+    /// If `None`, use the size of the text style.
+    pub max_size: Option<f32>,
+
+    /// The alignment of the text within the rect.
+    pub align: Align2,
+
+    /// The color of the text.
+    /// If `None`, use the text color of the ui.
+    pub color: Option<Color32>,
+}
+
+impl FitText {
+    #[inline]
+    /// Create a new centered fit text with the given text style.
+    pub fn new(text: impl Into<String>, style: TextStyle) -> Self {
+        Self {
+            text: text.into(),
+            style,
+            min_size: 6.0,
+            max_size: None,
+            align: Align2::CENTER_CENTER,
+            color: None,
+        }
+    }
+
+    #[inline]
+    /// Set the range of the font size.
+    pub fn size_range(mut self, min_size: f32, max_size: f32) -> Self {
+        self.min_size = min_size;
+        self.max_size = Some(max_size);
+        self
+    }
+
+    #[inline]
+    /// Set the alignment of the text within the rect.
+    pub fn align(mut self, align: Align2) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    /// Set the color of the text.
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl FitText {
+    /// Lay out the text at the largest font size in range which fits the given size.
+    pub fn layout(&self, ui: &Ui, size: Vec2) -> Arc<Galley> {
+        let font_id = self.style.resolve(ui.style());
+        let max_size = self.max_size.unwrap_or(font_id.size);
+        let min_size = self.min_size.min(max_size);
+
+        let layout = |font_size: f32| {
+            ui.fonts(|fonts| {
+                fonts.layout_no_wrap(
+                    self.text.clone(),
+                    FontId::new(font_size, font_id.family.clone()),
+                    Color32::PLACEHOLDER,
+                )
+            })
+        };
+
+        let mut font_size = max_size;
+        let mut galley = layout(font_size);
+        // the text size is nearly proportional to the font size, so guess first,
+        // then shrink step by step to make up for rounding
+        for _ in 0..8 {
+            let galley_size = galley.size();
+            if (galley_size.x <= size.x && galley_size.y <= size.y) || font_size <= min_size {
+                break;
+            }
+
+            let scale = (size / galley_size.max(Vec2::splat(f32::EPSILON))).min_elem();
+            font_size = (font_size * scale.min(0.95)).clamp(min_size, max_size);
+            galley = layout(font_size);
+        }
+
+        galley
+    }
+
+    /// Show the text in the given rect.
+    pub fn show(&self, ui: &mut Ui, max_rect: Rect) -> Response {
+        let response = ui.allocate_rect(max_rect, Sense::hover());
+        let galley = self.layout(ui, max_rect.size());
+        let rect = self.align.align_size_within_rect(galley.size(), max_rect);
+        let color = self.color.unwrap_or_else(|| ui.visuals().text_color());
+        ui.painter()
+            .with_clip_rect(max_rect.intersect(ui.clip_rect()))
+            .galley(rect.min, galley, color);
+        response
+    }
+}
+
+#[inline]
+/// Show a single line of text in the rect, shrinking the font size until it fits
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui::TextStyle;
+/// use egui_alignments::fit_text;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let rect = ui.available_rect_before_wrap();
+/// fit_text(ui, rect, "Quarterly revenue", TextStyle::Heading);
+/// # });
+/// ```
+pub fn fit_text(ui: &mut Ui, max_rect: Rect, text: impl Into<String>, style: TextStyle) -> Response {
+    FitText::new(text, style).show(ui, max_rect)
+}
+
+/// A block of lines, or of widgets, aligned as a whole,
+/// whose lines are each aligned within the width of the block,
+/// e.g. a block centered in the ui whose lines are right-aligned.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, Align2};
+/// use egui_alignments::MultiLineAligned;
+///
+/// # egui::__run_test_ui(|ui| {
+/// MultiLineAligned::new(Align2::CENTER_CENTER, Align::Max)
+///     .show(ui, |ui| {
+///         ui.label("Subtotal: 120.00");
+///         ui.label("Tax: 24.00");
+///         ui.strong("Total: 144.00");
+///     });
+/// # });
+/// ```
+pub struct MultiLineAligned {
+    /// Used to memorize the size of the block.
+    /// If None, the id will be generated automatically.
+    pub id: Option<Id>,
+
+    /// The alignment of the block within the bounds.
+    pub block_align: Align2,
+
+    /// The horizontal alignment of the lines within the block.
+    pub line_align: Align,
+
+    /// The bounds in which the block is aligned.
+    pub bounds: Bounds,
+}
+
+impl MultiLineAligned {
+    #[inline]
+    /// Create a new block aligned in the available space, with its lines aligned within it.
+    pub fn new(block_align: Align2, line_align: Align) -> Self {
+        Self {
+            id: None,
+            block_align,
+            line_align,
+            bounds: Bounds::available_rect(),
+        }
+    }
+
+    #[inline]
+    /// Set the id of the block.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    /// Set the bounds in which the block is aligned.
+    pub fn bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = bounds;
+        self
+    }
+}
+
+impl MultiLineAligned {
+    /// Show the lines added by the closure, one below the other.
+    pub fn show<R>(&self, ui: &mut Ui, add_lines: impl FnOnce(&mut Ui) -> R) -> AlignedResponse<R> {
+        let mut aligner = WidgetAligner::from_align(self.block_align)
+            .bounds(self.bounds)
+            .layout(Layout::top_down(self.line_align));
+        aligner.id = self.id;
+        aligner.show(ui, add_lines)
+    }
+
+    /// Show each line of the text as a label.
+    pub fn show_text(&self, ui: &mut Ui, text: &str) -> AlignedResponse<()> {
+        self.show(ui, |ui| {
+            for line in text.lines() {
+                ui.label(line);
+            }
+        })
+    }
+}
+
+#[inline]
+/// Show the lines of the text as a block aligned in the available space,
+/// each line aligned within the block
+///
+/// # Example
+/// ```rust
+/// # use egui_alignments::egui;
+/// use egui::{Align, Align2};
+/// use egui_alignments::multi_line_aligned;
+///
+/// # egui::__run_test_ui(|ui| {
+/// multi_line_aligned(ui, Align2::CENTER_CENTER, Align::Center, "Roses are red\nViolets are blue");
+/// # });
+/// ```
+pub fn multi_line_aligned(ui: &mut Ui, block_align: Align2, line_align: Align, text: &str) -> Response {
+    MultiLineAligned::new(block_align, line_align).show_text(ui, text).response
+}
@@ -0,0 +1,66 @@
+//! Transform math for aligned views
+//!
+//! # Example
+//! ```
+//! # use egui_alignments::egui;
+//! use egui::{vec2, Align2, Rect};
+//! use egui_alignments::{view_transform, ViewFit};
+//!
+//! let bounds = Rect::from_min_size((0.0, 0.0).into(), vec2(200.0, 100.0));
+//! let transform = view_transform(vec2(400.0, 100.0), bounds, ViewFit::Contain, Align2::CENTER_CENTER);
+//! assert_eq!(transform.scaling, 0.5);
+//! assert_eq!(transform * egui::Pos2::ZERO, (0.0, 25.0).into());
+//! ```
+
+use egui::{emath::TSTransform, Rect, Vec2};
+
+use crate::Aligner;
+
+/// Determines the scale of an aligned view of some content.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ViewFit {
+    /// Show the content at the given scale.
+    Scale(f32),
+
+    /// Scale the content so that its width fills the bounds.
+    FitWidth,
+
+    /// Scale the content so that its height fills the bounds.
+    FitHeight,
+
+    /// Scale the content so that it is fully visible.
+    Contain,
+
+    /// Scale the content so that it covers the bounds.
+    Cover,
+}
+
+impl ViewFit {
+    /// Get the scale of the content in the bounds.
+    pub fn scale(self, content_size: Vec2, bounds_size: Vec2) -> f32 {
+        let scale = bounds_size / content_size.max(Vec2::splat(f32::EPSILON));
+        match self {
+            ViewFit::Scale(scale) => scale,
+            ViewFit::FitWidth => scale.x,
+            ViewFit::FitHeight => scale.y,
+            ViewFit::Contain => scale.min_elem(),
+            ViewFit::Cover => scale.max_elem(),
+        }
+    }
+}
+
+/// Get the transform from content space, where the content starts at the origin,
+/// to the space of the bounds, so that the scaled content is placed by the aligner.
+pub fn view_transform(content_size: Vec2, bounds: Rect, fit: ViewFit, align: impl Aligner) -> TSTransform {
+    let scale = fit.scale(content_size, bounds.size());
+    let rect = align.align(content_size * scale, bounds);
+    TSTransform::new(rect.min.to_vec2(), scale)
+}
+
+/// Zoom the transform by `zoom` around the given fixed point in the transformed space.
+pub fn zoom_around(transform: TSTransform, zoom: f32, fixed_point: egui::Pos2) -> TSTransform {
+    TSTransform::from_translation(fixed_point.to_vec2())
+        * TSTransform::from_scaling(zoom)
+        * TSTransform::from_translation(-fixed_point.to_vec2())
+        * transform
+}
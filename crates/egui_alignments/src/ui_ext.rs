@@ -0,0 +1,62 @@
+//! Alignment methods on [`Ui`]
+
+use egui::{Align, InnerResponse, Ui};
+
+use crate::{Aligner, Column, Row, WidgetAligner};
+
+/// Alignment methods on [`Ui`], shorthands for the aligners and containers of the crate.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align, Align2};
+/// use egui_alignments::UiAlignExt;
+///
+/// # egui::__run_test_ui(|ui| {
+/// ui.row(Align::Center, |ui| {
+///     ui.label("Name");
+///     ui.text_edit_singleline(&mut String::new());
+/// });
+/// ui.centered(|ui| {
+///     ui.heading("Welcome");
+/// });
+/// ui.aligned(Align2::RIGHT_BOTTOM, |ui| {
+///     ui.button("Continue");
+/// });
+/// # });
+/// ```
+pub trait UiAlignExt {
+    /// Show the contents in a [`Row`] with the given vertical alignment.
+    fn row<R>(&mut self, valign: Align, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R>;
+
+    /// Show the contents in a [`Column`] with the given horizontal alignment.
+    fn column<R>(&mut self, halign: Align, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R>;
+
+    /// Show the contents at the center of the available space.
+    fn centered<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R>;
+
+    /// Show the contents aligned in the available space with the given [`Aligner`].
+    fn aligned<R>(&mut self, align: impl Aligner, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R>;
+}
+
+impl UiAlignExt for Ui {
+    #[inline]
+    fn row<R>(&mut self, valign: Align, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        Row::new(valign).show(self, add_contents).into()
+    }
+
+    #[inline]
+    fn column<R>(&mut self, halign: Align, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        Column::new(halign).show(self, add_contents).into()
+    }
+
+    #[inline]
+    fn centered<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        WidgetAligner::center().show(self, add_contents).into()
+    }
+
+    #[inline]
+    fn aligned<R>(&mut self, align: impl Aligner, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        WidgetAligner::from_align(align).show(self, add_contents).into()
+    }
+}
@@ -0,0 +1,60 @@
+//! Sizes relative to the screen
+//!
+//! Like the `vw` and `vh` units of CSS, the sizes are fractions of the screen rect,
+//! not of the local ui, so overlays and dialogs can scale with the window.
+//! Within a [`Page`](crate::Page), they are fractions of the page instead.
+//! The builders take plain sizes, so the units can be given to any minimum or maximum size.
+//!
+//! # Example
+//! ```
+//! # use egui_alignments::egui;
+//! use egui::{Align, Layout};
+//! use egui_alignments::{vh, vw, viewport_size, GenericContainer, Row};
+//!
+//! # egui::__run_test_ui(|ui| {
+//! Row::new(Align::Center)
+//!     .max_width(vw(ui, 0.5))
+//!     .min_height(vh(ui, 0.3))
+//!     .show(ui, |ui| {
+//!         ui.label("At most half as wide as the window");
+//!     });
+//!
+//! GenericContainer::new(Layout::top_down(Align::Center))
+//!     .max_size(viewport_size(ui, [0.8, 0.6]))
+//!     .show(ui, |ui| {
+//!         ui.label("At most 80% by 60% of the window");
+//!     });
+//! # });
+//! ```
+
+use egui::{Ui, Vec2};
+
+#[inline]
+/// The given fraction of the width of the screen.
+pub fn vw(ui: &Ui, fraction: f32) -> f32 {
+    crate::screen_or_page_rect(ui).width() * fraction
+}
+
+#[inline]
+/// The given fraction of the height of the screen.
+pub fn vh(ui: &Ui, fraction: f32) -> f32 {
+    crate::screen_or_page_rect(ui).height() * fraction
+}
+
+#[inline]
+/// The given fraction of the smaller side of the screen.
+pub fn vmin(ui: &Ui, fraction: f32) -> f32 {
+    crate::screen_or_page_rect(ui).size().min_elem() * fraction
+}
+
+#[inline]
+/// The given fraction of the larger side of the screen.
+pub fn vmax(ui: &Ui, fraction: f32) -> f32 {
+    crate::screen_or_page_rect(ui).size().max_elem() * fraction
+}
+
+#[inline]
+/// The given fractions of the width and the height of the screen.
+pub fn viewport_size(ui: &Ui, fraction: impl Into<Vec2>) -> Vec2 {
+    crate::screen_or_page_rect(ui).size() * fraction.into()
+}
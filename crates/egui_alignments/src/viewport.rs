@@ -0,0 +1,159 @@
+//! Alignment across the native windows of egui viewports
+//!
+//! The positions of the native windows are in monitor space, in points,
+//! as reported by the integration in [`egui::ViewportInfo`].
+//! They are unknown on integrations which do not report them, e.g. on the web,
+//! in which case the helpers return `None` or leave the builders unchanged.
+
+use std::hash::Hash;
+
+use egui::{
+    Align2, Area, Context, Id, InnerResponse, Margin, Pos2, Rect, Ui, Vec2, ViewportBuilder, ViewportCommand,
+    ViewportId,
+};
+
+use crate::{place_beside, Edge};
+
+/// Get the outer rect of the native window of a viewport, including its decorations if known.
+pub fn viewport_outer_rect(ctx: &Context, viewport_id: ViewportId) -> Option<Rect> {
+    ctx.input_for(viewport_id, |input| {
+        let info = input.viewport();
+        info.outer_rect.or(info.inner_rect)
+    })
+}
+
+/// Get the rect of the monitor of a viewport.
+///
+/// egui reports the size of the monitor but not its position,
+/// so the monitor is assumed to start at the origin, as the primary monitor usually does.
+/// Returns `None` if the native window is not within that rect, e.g. on another monitor,
+/// or if the size of the monitor or the position of the window is unknown.
+pub fn viewport_monitor_rect(ctx: &Context, viewport_id: ViewportId) -> Option<Rect> {
+    let monitor_size = ctx.input_for(viewport_id, |input| input.viewport().monitor_size)?;
+    let monitor_rect = Rect::from_min_size(Pos2::ZERO, monitor_size);
+    let window_rect = viewport_outer_rect(ctx, viewport_id)?;
+    monitor_rect.contains(window_rect.center()).then_some(monitor_rect)
+}
+
+/// Show the contents aligned within the screen of a viewport.
+///
+/// The contents are shown only in the passes of that viewport,
+/// so this can be called from code shared by several viewports.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{Align2, ViewportId};
+/// use egui_alignments::align_in_viewport;
+///
+/// # egui::__run_test_ui(|ui| {
+/// align_in_viewport(ui.ctx(), "status", ViewportId::ROOT, Align2::RIGHT_BOTTOM, |ui| {
+///     ui.label("Connected");
+/// });
+/// # });
+/// ```
+pub fn align_in_viewport<R>(
+    ctx: &Context,
+    id_salt: impl Hash,
+    viewport_id: ViewportId,
+    align: Align2,
+    add_contents: impl FnOnce(&mut Ui) -> R
+) -> Option<InnerResponse<R>> {
+    if ctx.viewport_id() != viewport_id {
+        return None;
+    }
+
+    let response = Area::new(Id::new(id_salt).with(viewport_id))
+        .anchor(align, Vec2::ZERO)
+        .show(ctx, add_contents);
+    Some(response)
+}
+
+/// Get the outer position of a child window of the given size
+/// aligned within the native window of the parent viewport,
+/// e.g. centered over it with [`Align2::CENTER_CENTER`].
+pub fn child_viewport_position(ctx: &Context, parent: ViewportId, size: Vec2, align: Align2) -> Option<Pos2> {
+    let parent_rect = viewport_outer_rect(ctx, parent)?;
+    Some(align.align_size_within_rect(size, parent_rect).min)
+}
+
+/// Get the outer position of a child window of the given size
+/// beside the native window of the parent viewport, `gap` away from it,
+/// on the given edge of the parent if it fits on the monitor, otherwise on another one.
+/// If the monitor is unknown, see [`viewport_monitor_rect`], the edge is kept.
+pub fn child_viewport_position_beside(
+    ctx: &Context,
+    parent: ViewportId,
+    size: Vec2,
+    edge: Edge,
+    gap: f32
+) -> Option<Pos2> {
+    let parent_rect = viewport_outer_rect(ctx, parent)?;
+    let monitor_rect = viewport_monitor_rect(ctx, parent).unwrap_or(Rect::EVERYTHING);
+    Some(place_beside(parent_rect, size, monitor_rect, gap, edge).min)
+}
+
+/// Set the position of a viewport builder so the window opens centered over the current viewport.
+///
+/// The size of the window is the inner size of the builder.
+/// The builder is unchanged if its inner size or the position of the current viewport is unknown.
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::{ViewportBuilder, ViewportId};
+/// use egui_alignments::centered_over_parent;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let ctx = ui.ctx();
+/// let builder = ViewportBuilder::default()
+///     .with_title("Preferences")
+///     .with_inner_size([400.0, 300.0]);
+/// let builder = centered_over_parent(ctx, builder);
+/// ctx.show_viewport_deferred(ViewportId::from_hash_of("preferences"), builder, |ctx, _class| {
+///     egui::CentralPanel::default().show(ctx, |ui| {
+///         ui.label("Preferences");
+///     });
+/// });
+/// # });
+/// ```
+pub fn centered_over_parent(ctx: &Context, builder: ViewportBuilder) -> ViewportBuilder {
+    let position = builder.inner_size.and_then(|size| {
+        child_viewport_position(ctx, ctx.viewport_id(), size, Align2::CENTER_CENTER)
+    });
+
+    match position {
+        Some(position) => builder.with_position(position),
+        None => builder,
+    }
+}
+
+/// Move the native window of the current viewport to be aligned on its monitor,
+/// `margin` away from the monitor edges it is aligned to.
+///
+/// egui reports the size of the monitor, not its work area,
+/// so leave room for the task bar or the dock with the margin.
+///
+/// Returns the new outer position of the window,
+/// or `None` if the size of the window or the monitor is unknown, see [`viewport_monitor_rect`].
+///
+/// # Example
+/// ```
+/// # use egui_alignments::egui;
+/// use egui::Align2;
+/// use egui_alignments::align_native_window;
+///
+/// # egui::__run_test_ui(|ui| {
+/// // a notification window in the top right corner
+/// align_native_window(ui.ctx(), Align2::RIGHT_TOP, 16.0);
+/// # });
+/// ```
+pub fn align_native_window(ctx: &Context, align: Align2, margin: impl Into<Margin>) -> Option<Pos2> {
+    let viewport_id = ctx.viewport_id();
+    let window_size = viewport_outer_rect(ctx, viewport_id)?.size();
+    let monitor_rect = viewport_monitor_rect(ctx, viewport_id)? - margin.into();
+
+    let position = align.align_size_within_rect(window_size, monitor_rect).min;
+    ctx.send_viewport_cmd(ViewportCommand::OuterPosition(position));
+    Some(position)
+}